@@ -1 +1,50 @@
-
+//! Transforming a point buffer one point at a time vs. in one batch.
+//!
+//! Run with `cargo bench --bench transform_bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use spatial_typestate::{spatial_frames, Point3, Quantity, Transform, UnitQuat, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn sample_transform() -> Transform<Body, World> {
+    let axis = Vector3::<Body>::new(0.3, -1.0, 0.7);
+    let q = UnitQuat::from_axis_angle(axis, Quantity::new(2.1)).unwrap();
+    Transform::from_quaternion_translation(q, -4.0, 0.5, 12.0)
+}
+
+fn apply_points(c: &mut Criterion) {
+    let t = sample_transform();
+    let mut group = c.benchmark_group("apply_points");
+
+    for len in [1_000_usize, 100_000, 1_000_000] {
+        let src: Vec<Point3<Body>> = (0..len)
+            .map(|i| {
+                let i = i as f64;
+                Point3::new(i, -0.5 * i, 0.25 * i)
+            })
+            .collect();
+        let mut dst = vec![Point3::<World>::new(0.0, 0.0, 0.0); len];
+        group.throughput(Throughput::Elements(len as u64));
+
+        group.bench_with_input(BenchmarkId::new("scalar", len), &src, |b, src| {
+            b.iter(|| {
+                for (p, out) in src.iter().zip(dst.iter_mut()) {
+                    *out = black_box(&t).apply_point(*p);
+                }
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch", len), &src, |b, src| {
+            b.iter(|| black_box(&t).apply_points(src, &mut dst).unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, apply_points);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+//! Axis-aligned bounding boxes tagged with a coordinate frame.
+//!
+//! An [`Aabb<F>`] is aligned with the axes of frame `F`, so it only makes
+//! sense for points expressed in that same frame.
+//!
+//! ```rust
+//! use spatial_typestate::{Aabb, Frame, Point3};
+//!
+//! struct World;
+//! impl Frame for World {}
+//!
+//! let arena = Aabb::new(Point3::<World>::new(0.0, 0.0, 0.0), Point3::new(10.0, 10.0, 5.0));
+//!
+//! assert!(arena.contains(Point3::new(1.0, 2.0, 3.0)));
+//!
+//! let p = arena.clamp(Point3::new(12.0, -1.0, 3.0));
+//! assert_eq!((p.x, p.y, p.z), (10.0, 0.0, 3.0));
+//! ```
+
+use crate::frame::Frame;
+use crate::point::Point3;
+
+/// An axis-aligned box in frame `F`, given by its minimum and maximum
+/// corners.
+#[derive(Debug, PartialEq)]
+pub struct Aabb<F: Frame> {
+    min: Point3<F>,
+    max: Point3<F>,
+}
+
+impl<F: Frame> Aabb<F> {
+    /// The box spanned by two opposite corners, in any order.
+    #[inline]
+    #[must_use]
+    pub fn new(a: Point3<F>, b: Point3<F>) -> Self {
+        Self {
+            min: Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    /// Corner with the smallest coordinates.
+    #[inline]
+    #[must_use]
+    pub const fn min(&self) -> Point3<F> {
+        self.min
+    }
+
+    /// Corner with the largest coordinates.
+    #[inline]
+    #[must_use]
+    pub const fn max(&self) -> Point3<F> {
+        self.max
+    }
+
+    /// Whether `p` lies inside the box or on its surface.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, p: Point3<F>) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x)
+            && (self.min.y..=self.max.y).contains(&p.y)
+            && (self.min.z..=self.max.z).contains(&p.z)
+    }
+
+    /// The point of the box closest to `p`.
+    ///
+    /// Points inside are returned unchanged; points outside are moved onto
+    /// the nearest face, edge or corner.
+    ///
+    /// Unlike [`f64::clamp`] this never panics. An axis whose bounds are
+    /// `NaN` (from two `NaN` corners passed to [`Aabb::new`]) is left
+    /// unclamped, and a `NaN` coordinate is moved to the lower bound.
+    #[inline]
+    #[must_use]
+    pub fn clamp(&self, p: Point3<F>) -> Point3<F> {
+        Point3::new(
+            p.x.max(self.min.x).min(self.max.x),
+            p.y.max(self.min.y).min(self.max.y),
+            p.z.max(self.min.z).min(self.max.z),
+        )
+    }
+}
+
+impl<F: Frame> Clone for Aabb<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame> Copy for Aabb<F> {}
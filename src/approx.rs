@@ -0,0 +1,146 @@
+//! [`approx`](https://docs.rs/approx) comparisons (feature `approx`).
+//!
+//! [`Point3`], [`Vector3`], [`UnitQuat`], [`Transform`] and [`Quantity`]
+//! implement `AbsDiffEq`, `RelativeEq` and `UlpsEq` component-wise, so tests
+//! can write `assert_relative_eq!(p, q)` instead of comparing coordinates one
+//! by one. As with `==` (whose derived impls these mirror, including the
+//! `PartialEq` bound on the markers that [`spatial_frames!`](crate::spatial_frames!)
+//! provides), both sides must have the same frame (or unit) type; comparing
+//! values from different frames does not compile. The tolerance
+//! (`Epsilon`) is the bare scalar type, e.g. `f64`.
+//!
+//! ```rust
+//! use approx_dep::assert_relative_eq;
+//! use spatial_typestate::{spatial_frames, Point3, Transform};
+//!
+//! spatial_frames! {
+//!     World,
+//!     Body,
+//! }
+//!
+//! let t: Transform<Body, World> = Transform::from_translation(0.1, 0.2, 0.0);
+//! let p = t.apply_point(Point3::new(0.2, 0.1, 0.0));
+//!
+//! assert_ne!(p, Point3::new(0.3, 0.3, 0.0)); // 0.2 + 0.1 != 0.3 in f64
+//! assert_relative_eq!(p, Point3::new(0.3, 0.3, 0.0));
+//! ```
+//!
+//! Quaternions compare component-wise, so `q` and `-q` are **not** equal
+//! even though they encode the same rotation; use
+//! [`UnitQuat::rotation_abs_diff_eq`] when only the rotation matters.
+
+use approx_dep::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::frame::Frame;
+use crate::point::Point3;
+use crate::quaternion::UnitQuat;
+use crate::scalar::Scalar;
+use crate::transform::Transform;
+use crate::units::{Quantity, Unit};
+use crate::vector::Vector3;
+
+/// Implement the three `approx` traits by comparing the scalars yielded by
+/// `$parts` (an iterator over `$this`'s components) pairwise.
+macro_rules! approx_componentwise {
+    (
+        impl[$($gen:tt)*] $ty:ty where [$($bounds:tt)*];
+        scalar $t:ty;
+        parts($this:ident) => $parts:expr;
+    ) => {
+        impl<$($gen)*> AbsDiffEq for $ty
+        where
+            $($bounds)*
+        {
+            type Epsilon = $t;
+
+            #[inline]
+            fn default_epsilon() -> $t {
+                <$t>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                let lhs = { let $this = self; $parts };
+                let rhs = { let $this = other; $parts };
+                lhs.zip(rhs).all(|(a, b)| a.abs_diff_eq(&b, epsilon))
+            }
+        }
+
+        impl<$($gen)*> RelativeEq for $ty
+        where
+            $($bounds)*
+        {
+            #[inline]
+            fn default_max_relative() -> $t {
+                <$t>::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                let lhs = { let $this = self; $parts };
+                let rhs = { let $this = other; $parts };
+                lhs.zip(rhs).all(|(a, b)| a.relative_eq(&b, epsilon, max_relative))
+            }
+        }
+
+        impl<$($gen)*> UlpsEq for $ty
+        where
+            $($bounds)*
+        {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                <$t>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                let lhs = { let $this = self; $parts };
+                let rhs = { let $this = other; $parts };
+                lhs.zip(rhs).all(|(a, b)| a.ulps_eq(&b, epsilon, max_ulps))
+            }
+        }
+    };
+}
+
+approx_componentwise! {
+    impl[F: Frame, T: Scalar] Point3<F, T>
+        where [F: PartialEq, T: RelativeEq<Epsilon = T> + UlpsEq<Epsilon = T>];
+    scalar T;
+    parts(p) => [p.x, p.y, p.z].into_iter();
+}
+
+approx_componentwise! {
+    impl[F: Frame, T: Scalar] Vector3<F, T>
+        where [F: PartialEq, T: RelativeEq<Epsilon = T> + UlpsEq<Epsilon = T>];
+    scalar T;
+    parts(v) => [v.x, v.y, v.z].into_iter();
+}
+
+approx_componentwise! {
+    impl[From: Frame, To: Frame, T: Scalar] Transform<From, To, T>
+        where [From: PartialEq, To: PartialEq, T: RelativeEq<Epsilon = T> + UlpsEq<Epsilon = T>];
+    scalar T;
+    parts(t) => t.matrix.iter().flatten().copied();
+}
+
+approx_componentwise! {
+    impl[F: Frame] UnitQuat<F> where [F: PartialEq];
+    scalar f64;
+    parts(q) => [q.x, q.y, q.z, q.w].into_iter();
+}
+
+approx_componentwise! {
+    impl[U: Unit] Quantity<U> where [];
+    scalar f64;
+    parts(q) => core::iter::once(q.get());
+}
+
+impl<F: Frame + PartialEq> UnitQuat<F> {
+    /// Whether `self` and `other` encode the same rotation to within
+    /// `epsilon` per component.
+    ///
+    /// Unlike the component-wise `AbsDiffEq` impl, this also accepts
+    /// `other ≈ -self`, since `q` and `-q` are the same rotation.
+    #[must_use]
+    pub fn rotation_abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let negated = Self::new_unchecked(-other.x, -other.y, -other.z, -other.w);
+        self.abs_diff_eq(other, epsilon) || self.abs_diff_eq(&negated, epsilon)
+    }
+}
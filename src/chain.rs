@@ -0,0 +1,114 @@
+//! Type-level chains of frames for multi-stage transform pipelines.
+//!
+//! A [`FrameChain`] names the frames a pipeline passes through, in order.
+//! Composing a chain from its individual hops is statically checked: each
+//! hop must start where the previous one ended, and the hops must visit
+//! exactly the frames listed in the chain.
+//!
+//! ```rust
+//! use spatial_typestate::{spatial_frames, FrameChain, Point3, Transform};
+//!
+//! spatial_frames! {
+//!     Sensor,
+//!     Body,
+//!     World,
+//! }
+//!
+//! let sensor_to_body: Transform<Sensor, Body> = Transform::from_translation(0.0, 0.0, 1.0);
+//! let body_to_world: Transform<Body, World> = Transform::from_translation(10.0, 0.0, 0.0);
+//!
+//! let sensor_to_world = <FrameChain![Sensor, Body, World]>::compose((sensor_to_body, body_to_world));
+//!
+//! let p = sensor_to_world.apply_point(Point3::new(0.0, 0.0, 0.0));
+//! assert_eq!((p.x, p.y, p.z), (10.0, 0.0, 1.0));
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::frame::Frame;
+use crate::transform::Transform;
+
+/// A type-level list of the frames a transform pipeline passes through.
+///
+/// `Frames` is a tuple of frame markers, e.g. `(Sensor, Body, World)`. The
+/// [`FrameChain!`](crate::FrameChain!) macro spells this more readably. The
+/// type is never instantiated; it only serves as documentation and as the
+/// anchor for [`FrameChain::compose`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct FrameChain<Frames>(PhantomData<Frames>);
+
+impl<Frames> FrameChain<Frames> {
+    /// Compose the hop transforms of this chain into a single transform from
+    /// its first frame to its last.
+    ///
+    /// `hops` is a tuple of transforms, one per hop, in pipeline order.
+    #[inline]
+    pub fn compose<H>(hops: H) -> Transform<H::First, H::Last>
+    where
+        H: ChainHops<Frames = Frames>,
+    {
+        hops.compose_hops()
+    }
+}
+
+impl<Frames> Clone for FrameChain<Frames> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Frames> Copy for FrameChain<Frames> {}
+
+/// A tuple of transforms whose frames line up end to end.
+///
+/// Implemented for tuples of one to six [`Transform`]s where each hop's `To`
+/// frame is the next hop's `From` frame. Tuples that do not line up simply
+/// have no implementation, which turns an out-of-order pipeline into a
+/// compile error.
+pub trait ChainHops {
+    /// The frames visited by the hops, as a tuple in order.
+    type Frames;
+    /// The frame the first hop starts from.
+    type First: Frame;
+    /// The frame the last hop ends in.
+    type Last: Frame;
+
+    /// Compose all hops into a single transform.
+    fn compose_hops(self) -> Transform<Self::First, Self::Last>;
+}
+
+macro_rules! impl_chain_hops {
+    (
+        ($first:ident $(, $frame:ident)+) => $last:ident;
+        $h0:ident: $a0:ident -> $b0:ident $(, $h:ident: $a:ident -> $b:ident)*
+    ) => {
+        impl<$first: Frame $(, $frame: Frame)+> ChainHops for (Transform<$a0, $b0>, $(Transform<$a, $b>,)*) {
+            type Frames = ($first, $($frame,)+);
+            type First = $first;
+            type Last = $last;
+
+            #[inline]
+            fn compose_hops(self) -> Transform<$first, $last> {
+                let ($h0, $($h,)*) = self;
+                $h0 $(.compose(&$h))*
+            }
+        }
+    };
+}
+
+impl_chain_hops! { (F0, F1) => F1; t0: F0 -> F1 }
+impl_chain_hops! { (F0, F1, F2) => F2; t0: F0 -> F1, t1: F1 -> F2 }
+impl_chain_hops! { (F0, F1, F2, F3) => F3; t0: F0 -> F1, t1: F1 -> F2, t2: F2 -> F3 }
+impl_chain_hops! {
+    (F0, F1, F2, F3, F4) => F4;
+    t0: F0 -> F1, t1: F1 -> F2, t2: F2 -> F3, t3: F3 -> F4
+}
+impl_chain_hops! {
+    (F0, F1, F2, F3, F4, F5) => F5;
+    t0: F0 -> F1, t1: F1 -> F2, t2: F2 -> F3, t3: F3 -> F4, t4: F4 -> F5
+}
+impl_chain_hops! {
+    (F0, F1, F2, F3, F4, F5, F6) => F6;
+    t0: F0 -> F1, t1: F1 -> F2, t2: F2 -> F3, t3: F3 -> F4, t4: F4 -> F5, t5: F5 -> F6
+}
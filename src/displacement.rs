@@ -0,0 +1,71 @@
+//! Frame-tagged displacements in meters.
+//!
+//! A [`Displacement<F>`] wraps a [`Vector3`] to record that its components are
+//! lengths in meters. Like a vector, it moves a [`Point3`] with `+` and `-`;
+//! the difference of two points is a plain [`Vector3`], which can be wrapped
+//! when its unit should be carried along.
+//!
+//! ```rust
+//! use spatial_typestate::{Displacement, Frame, Point3, Vector3};
+//!
+//! struct World;
+//! impl Frame for World {}
+//!
+//! let p = Point3::<World>::new(1.0, 2.0, 3.0);
+//! let step = Displacement(Vector3::new(0.5, 0.0, -1.0));
+//!
+//! let q = p + step;
+//! assert_eq!((q.x, q.y, q.z), (1.5, 2.0, 2.0));
+//!
+//! let back = Displacement(q - p);
+//! assert_eq!(back.length().get(), step.length().get());
+//! ```
+
+use core::ops::{Add, Sub};
+
+use crate::frame::Frame;
+use crate::point::Point3;
+use crate::units::{Meters, Quantity};
+use crate::vector::Vector3;
+
+/// A displacement expressed in frame `F`, with components in meters.
+#[derive(Debug, PartialEq)]
+pub struct Displacement<F: Frame>(pub Vector3<F>);
+
+impl<F: Frame> Displacement<F> {
+    /// Length of the displacement.
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> Quantity<Meters> {
+        self.0.norm_m()
+    }
+}
+
+impl<F: Frame> Clone for Displacement<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame> Copy for Displacement<F> {}
+
+/// Move a point by a displacement in the same frame.
+impl<F: Frame> Add<Displacement<F>> for Point3<F> {
+    type Output = Point3<F>;
+
+    #[inline]
+    fn add(self, rhs: Displacement<F>) -> Self::Output {
+        Point3::new(self.x + rhs.0.x, self.y + rhs.0.y, self.z + rhs.0.z)
+    }
+}
+
+/// Move a point back by a displacement in the same frame.
+impl<F: Frame> Sub<Displacement<F>> for Point3<F> {
+    type Output = Point3<F>;
+
+    #[inline]
+    fn sub(self, rhs: Displacement<F>) -> Self::Output {
+        Point3::new(self.x - rhs.0.x, self.y - rhs.0.y, self.z - rhs.0.z)
+    }
+}
@@ -0,0 +1,114 @@
+//! Transforms between frames that are only known at runtime.
+//!
+//! Everywhere else in this crate frames are types, and mixing them up is a
+//! compile error. When frames come from configuration or a message bus that
+//! is not possible; this module is the runtime fallback. [`DynTransform`]
+//! pairs a transform with the runtime ids of its two frames, and
+//! [`DynTransform::try_compose`] checks at runtime what
+//! [`Transform::compose`] checks at compile time.
+//!
+//! ```rust
+//! use spatial_typestate::dynamic::{DynFrameId, DynTransform};
+//! use spatial_typestate::{SpatialError, Transform};
+//!
+//! let (lidar, body, world) = (DynFrameId(1), DynFrameId(2), DynFrameId(3));
+//!
+//! let lidar_to_body = DynTransform::new(lidar, body, Transform::from_translation(0.0, 0.0, 1.0));
+//! let body_to_world = DynTransform::new(body, world, Transform::from_translation(5.0, 0.0, 0.0));
+//!
+//! let lidar_to_world = lidar_to_body.try_compose(&body_to_world).unwrap();
+//! assert_eq!((lidar_to_world.from(), lidar_to_world.to()), (lidar, world));
+//!
+//! // Composing in the wrong order is caught at runtime.
+//! assert_eq!(body_to_world.try_compose(&lidar_to_body), Err(SpatialError::FrameMismatch));
+//! ```
+
+use crate::errors::SpatialError;
+use crate::frame::Frame;
+use crate::transform::Transform;
+
+/// Frame marker standing in for "some frame, identified at runtime".
+///
+/// Values tagged with `DynFrame` carry no compile-time frame information;
+/// use them only alongside a [`DynFrameId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynFrame;
+
+impl Frame for DynFrame {
+    const NAME: &'static str = "DynFrame";
+}
+
+/// Runtime identifier of a frame.
+///
+/// How ids are assigned (e.g. an index into a list of frame names) is up to
+/// the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DynFrameId(pub u32);
+
+/// A transform between two runtime-identified frames.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynTransform {
+    from: DynFrameId,
+    to: DynFrameId,
+    transform: Transform<DynFrame, DynFrame>,
+}
+
+impl DynTransform {
+    /// Tag `transform` as mapping frame `from` into frame `to`.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        from: DynFrameId,
+        to: DynFrameId,
+        transform: Transform<DynFrame, DynFrame>,
+    ) -> Self {
+        Self {
+            from,
+            to,
+            transform,
+        }
+    }
+
+    /// The frame this transform maps from.
+    #[inline]
+    #[must_use]
+    pub const fn from(&self) -> DynFrameId {
+        self.from
+    }
+
+    /// The frame this transform maps into.
+    #[inline]
+    #[must_use]
+    pub const fn to(&self) -> DynFrameId {
+        self.to
+    }
+
+    /// The underlying transform.
+    #[inline]
+    #[must_use]
+    pub const fn transform(&self) -> &Transform<DynFrame, DynFrame> {
+        &self.transform
+    }
+
+    /// Chain this transform with `next`, which must continue from the frame
+    /// this one ends in.
+    ///
+    /// This is the runtime counterpart of [`Transform::compose`]: the result
+    /// first applies `self` and then `next`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::FrameMismatch`] if `next` does not start in
+    /// the frame `self` maps into.
+    pub fn try_compose(&self, next: &Self) -> Result<Self, SpatialError> {
+        if self.to != next.from {
+            return Err(SpatialError::FrameMismatch);
+        }
+
+        Ok(Self::new(
+            self.from,
+            next.to,
+            self.transform.compose(&next.transform),
+        ))
+    }
+}
@@ -17,4 +17,36 @@ pub enum SpatialError {
     /// A quaternion with zero (or effectively zero) norm was provided where
     /// a unit quaternion was required.
     ZeroNormQuaternion,
+
+    /// A vector with zero (or effectively zero) length was provided where a
+    /// direction was required.
+    ZeroNormVector,
+
+    /// The inputs were geometrically degenerate for the requested operation
+    /// (e.g. nearly collinear vectors passed to a cross product).
+    DegenerateInput,
+
+    /// A matrix that was required to be a rigid transform (orthonormal,
+    /// right-handed rotation block and bottom row `[0, 0, 0, 1]`) was not,
+    /// or was too far from one to be repaired.
+    NotRigid,
+
+    /// Two frames are not connected by any chain of known transforms (for
+    /// example, they belong to different trees of a
+    /// [`FrameResolver`](crate::resolver::FrameResolver)).
+    NoTransformPath,
+
+    /// A matrix that had to be inverted was singular (or so close to it
+    /// that the inverse is not finite).
+    NotInvertible,
+
+    /// Two runtime-identified frames that had to match did not (e.g. the
+    /// middle frames of a
+    /// [`DynTransform::try_compose`](crate::dynamic::DynTransform::try_compose)).
+    FrameMismatch,
+
+    /// Two buffers that had to have the same length did not (e.g. the input
+    /// and output slices of
+    /// [`Transform::apply_points`](crate::transform::Transform::apply_points)).
+    LengthMismatch,
 }
@@ -0,0 +1,206 @@
+//! Euler-angle conversions for [`UnitQuat`] with an explicit axis order.
+//!
+//! There is no single "the" Euler convention, and mixing them up is a
+//! classic source of attitude bugs, so every conversion takes an
+//! [`EulerOrder`]. All orders here are **intrinsic** Tait–Bryan sequences:
+//! `EulerOrder::ZYX` with angles `(a, b, c)` rotates by `a` about `z`, then by
+//! `b` about the *new* `y`, then by `c` about the *newest* `x`, i.e.
+//! `q = q_z(a) ⊗ q_y(b) ⊗ q_x(c)`. That is the aerospace yaw, pitch, roll
+//! convention. An extrinsic sequence (about the fixed axes) equals the
+//! intrinsic one with the axis order reversed, so extrinsic `XYZ` with
+//! angles `(c, b, a)` is intrinsic `ZYX` with `(a, b, c)`.
+//!
+//! [`Transform::from_euler`] builds the same rotation directly as a matrix,
+//! `R = R_z(a) R_y(b) R_x(c)` for `ZYX`.
+//!
+//! ```rust
+//! use spatial_typestate::euler::EulerOrder;
+//! use spatial_typestate::{Frame, Quantity, UnitQuat};
+//!
+//! struct World;
+//! impl Frame for World {}
+//!
+//! let (yaw, pitch, roll) = (Quantity::new(0.3), Quantity::new(-0.2), Quantity::new(1.1));
+//! let q = UnitQuat::<World>::from_euler(EulerOrder::ZYX, yaw, pitch, roll);
+//!
+//! let (a, b, c) = q.to_euler(EulerOrder::ZYX);
+//! assert!((a.get() - 0.3).abs() < 1e-12);
+//! assert!((b.get() + 0.2).abs() < 1e-12);
+//! assert!((c.get() - 1.1).abs() < 1e-12);
+//! ```
+
+use crate::frame::Frame;
+use crate::math;
+use crate::quaternion::{quat_mul, rotation3_from_quat, UnitQuat};
+use crate::transform::Transform;
+use crate::units::{Quantity, Radians};
+
+/// Axis sequence of an intrinsic Tait–Bryan rotation, named in the order
+/// the rotations are applied.
+///
+/// See the [module documentation](self) for the exact convention.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+    /// `x`, then `y`, then `z`.
+    XYZ,
+    /// `x`, then `z`, then `y`.
+    XZY,
+    /// `y`, then `x`, then `z`.
+    YXZ,
+    /// `y`, then `z`, then `x`.
+    YZX,
+    /// `z`, then `x`, then `y`.
+    ZXY,
+    /// `z`, then `y`, then `x`: yaw, pitch, roll.
+    ZYX,
+}
+
+impl EulerOrder {
+    /// Axis indices `(i, j, k)` in application order.
+    const fn axes(self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+        }
+    }
+
+    /// `+1` for cyclic sequences (`XYZ`, `YZX`, `ZXY`), `-1` otherwise.
+    const fn parity(self) -> f64 {
+        match self {
+            Self::XYZ | Self::YZX | Self::ZXY => 1.0,
+            Self::XZY | Self::YXZ | Self::ZYX => -1.0,
+        }
+    }
+}
+
+impl<F: Frame> UnitQuat<F> {
+    /// Rotation by the intrinsic Euler angles `(a, b, c)` about the axes of
+    /// `order`, applied in that order.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn from_euler(
+        order: EulerOrder,
+        a: Quantity<Radians>,
+        b: Quantity<Radians>,
+        c: Quantity<Radians>,
+    ) -> Self {
+        let [i, j, k] = order.axes();
+        let q = quat_mul(
+            quat_mul(axis_quat(i, a.get()), axis_quat(j, b.get())),
+            axis_quat(k, c.get()),
+        );
+
+        Self::from_xyzw(q)
+    }
+
+    /// Intrinsic Euler angles `(a, b, c)` of this rotation for `order`, the
+    /// inverse of [`UnitQuat::from_euler`].
+    ///
+    /// `a` and `c` lie in `[-π, π]` and the middle angle `b` in
+    /// `[-π/2, π/2]`.
+    ///
+    /// # Gimbal lock
+    ///
+    /// When `b` is `±π/2` the first and last axes line up and only a
+    /// combination of `a` and `c` is determined. This method then reports
+    /// `c = 0` and folds the whole rotation about that axis into `a`, so the
+    /// angles still reproduce the same rotation but will not match the ones
+    /// the quaternion was built from. Near the lock the split between `a` and
+    /// `c` is numerically ill-conditioned.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn to_euler(
+        &self,
+        order: EulerOrder,
+    ) -> (Quantity<Radians>, Quantity<Radians>, Quantity<Radians>) {
+        const LOCK_TOL: f64 = 1e-9;
+
+        let r = rotation3_from_quat(self.to_xyzw());
+        let [i, j, k] = order.axes();
+        let s = order.parity();
+
+        let sin_b = (s * r[i][k]).clamp(-1.0, 1.0);
+        let b = math::asin(sin_b);
+
+        let (a, c) = if sin_b.abs() >= 1.0 - LOCK_TOL {
+            (math::atan2(s * r[k][j], r[j][j]), 0.0)
+        } else {
+            (
+                math::atan2(-s * r[j][k], r[k][k]),
+                math::atan2(-s * r[i][j], r[i][i]),
+            )
+        };
+
+        (Quantity::new(a), Quantity::new(b), Quantity::new(c))
+    }
+}
+
+impl<From: Frame, To: Frame> Transform<From, To> {
+    /// Pure rotation by the intrinsic Euler angles `(a, b, c)` about the axes
+    /// of `order`, with zero translation.
+    ///
+    /// The rotation block is the product of the three elementary rotation
+    /// matrices in `order`, so this is the same transform as
+    /// [`Transform::from_quaternion_translation`] of
+    /// [`UnitQuat::from_euler`] with the same arguments, built without the
+    /// quaternion detour.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn from_euler(
+        order: EulerOrder,
+        a: Quantity<Radians>,
+        b: Quantity<Radians>,
+        c: Quantity<Radians>,
+    ) -> Self {
+        let [i, j, k] = order.axes();
+        let r = mat3_mul(
+            mat3_mul(axis_rotation3(i, a.get()), axis_rotation3(j, b.get())),
+            axis_rotation3(k, c.get()),
+        );
+
+        Self::from_matrix([
+            [r[0][0], r[0][1], r[0][2], 0.0],
+            [r[1][0], r[1][1], r[1][2], 0.0],
+            [r[2][0], r[2][1], r[2][2], 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+/// Quaternion of a rotation by `angle` about coordinate axis `axis`.
+fn axis_quat(axis: usize, angle: f64) -> [f64; 4] {
+    let (sin_half, cos_half) = math::sin_cos(0.5 * angle);
+    let mut q = [0.0, 0.0, 0.0, cos_half];
+    q[axis] = sin_half;
+    q
+}
+
+/// Right-handed rotation matrix by `angle` about coordinate axis `axis`.
+fn axis_rotation3(axis: usize, angle: f64) -> [[f64; 3]; 3] {
+    let (sin, cos) = math::sin_cos(angle);
+    let (j, k) = ((axis + 1) % 3, (axis + 2) % 3);
+
+    let mut r = [[0.0; 3]; 3];
+    r[axis][axis] = 1.0;
+    r[j][j] = cos;
+    r[j][k] = -sin;
+    r[k][j] = sin;
+    r[k][k] = cos;
+    r
+}
+
+/// Matrix product `a × b`.
+fn mat3_mul(a: [[f64; 3]; 3], b: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut m = [[0.0; 3]; 3];
+    for (row, m_row) in m.iter_mut().enumerate() {
+        for (col, v) in m_row.iter_mut().enumerate() {
+            *v = a[row][0] * b[0][col] + a[row][1] * b[1][col] + a[row][2] * b[2][col];
+        }
+    }
+    m
+}
@@ -15,9 +15,252 @@
 //! impl Frame for Sensor {}
 //! ```
 
+use core::fmt;
+use core::marker::PhantomData;
+
 /// Marker trait for a coordinate frame.
 ///
 /// Typical usage is to define zero-sized types representing frames in your
-/// domain and implement `Frame` for them. The trait is intentionally empty:
-/// all semantics are carried at the type level.
-pub trait Frame: 'static {}
+/// domain and implement `Frame` for them. Every item of the trait has a
+/// default, so an empty `impl` is enough; the associated constants are
+/// descriptive metadata and do not change how values are transformed.
+///
+/// Frame markers must be zero-sized. Building a frame-tagged value (point,
+/// vector, quaternion, transform) with a marker that carries data fails to
+/// compile.
+///
+/// A marker does not need to implement any other trait. Frame-tagged types
+/// implement `Clone` and `Copy` by hand rather than deriving them, since a
+/// derive would require `F: Copy`; a plain `struct World;` therefore gives
+/// `Copy` points, vectors and transforms.
+pub trait Frame: 'static {
+    /// Human-readable name of the frame, shown in `Debug` output.
+    ///
+    /// [`spatial_frames!`](crate::spatial_frames!) sets this to the marker's
+    /// identifier. Left empty, the marker's type name is shown instead.
+    const NAME: &'static str = "";
+
+    /// Whether the frame's axes form a right- or left-handed system.
+    ///
+    /// Defaults to [`Handedness::Right`], the convention this crate's
+    /// rotations and cross products assume.
+    const HANDEDNESS: Handedness = Handedness::Right;
+
+    /// Which axis points up, as far as the frame has an "up".
+    ///
+    /// Defaults to [`Axis::Z`]. A north-east-down frame, for example, would
+    /// set [`Axis::NegZ`].
+    const UP_AXIS: Axis = Axis::Z;
+}
+
+/// A frame that can be identified at runtime by a string key.
+///
+/// Runtime containers such as [`FrameGraph`](crate::FrameGraph) register
+/// frames by this id. [`spatial_frames!`](crate::spatial_frames!) implements
+/// it for every frame it declares, using the module path and identifier of
+/// the marker (e.g. `"my_robot::frames::Lidar"`), so ids from different
+/// modules do not collide.
+///
+/// ```rust
+/// use spatial_typestate::{Frame, FrameId};
+///
+/// struct Imu;
+///
+/// impl Frame for Imu {}
+/// impl FrameId for Imu {
+///     const ID: &'static str = "imu";
+/// }
+/// ```
+pub trait FrameId: Frame {
+    /// Runtime identifier of the frame. Frames stored in the same container
+    /// must have distinct ids.
+    const ID: &'static str;
+}
+
+/// Orientation of a frame's axes: whether `x × y` is `+z` or `-z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Handedness {
+    /// `x × y = z`, as in ROS, most CAD tools and this crate's math.
+    Right,
+    /// `x × y = -z`, as in e.g. Unity and DirectX.
+    Left,
+}
+
+/// A signed coordinate axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// `+x`.
+    X,
+    /// `+y`.
+    Y,
+    /// `+z`.
+    Z,
+    /// `-x`.
+    NegX,
+    /// `-y`.
+    NegY,
+    /// `-z`.
+    NegZ,
+}
+
+/// A frame's axis conventions, read from [`Frame::HANDEDNESS`] and
+/// [`Frame::UP_AXIS`] so they can be passed around and compared at runtime.
+///
+/// ```rust
+/// use spatial_typestate::{spatial_frames, Axis, FrameConvention, Handedness};
+///
+/// spatial_frames! {
+///     Ros,
+///     #[frame(handedness = Left, up = Y)]
+///     Unity,
+/// }
+///
+/// let unity = FrameConvention::of::<Unity>();
+/// assert_eq!(unity.handedness, Handedness::Left);
+/// assert_eq!(unity.up_axis, Axis::Y);
+/// assert_ne!(unity, FrameConvention::of::<Ros>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameConvention {
+    /// See [`Frame::HANDEDNESS`].
+    pub handedness: Handedness,
+    /// See [`Frame::UP_AXIS`].
+    pub up_axis: Axis,
+}
+
+impl FrameConvention {
+    /// The conventions declared by frame `F`.
+    #[inline]
+    #[must_use]
+    pub const fn of<F: Frame>() -> Self {
+        Self {
+            handedness: F::HANDEDNESS,
+            up_axis: F::UP_AXIS,
+        }
+    }
+}
+
+/// Opt-in declaration that `Self` and `G` name the same physical frame.
+///
+/// Implementing `RelabelSafe<G>` for `F` allows values tagged with `F` to be
+/// re-tagged as `G` without a transform (see e.g.
+/// [`Point3::relabel`](crate::point::Point3::relabel)). This is meant for
+/// refactors and aliases where two marker types genuinely denote the same
+/// frame; every such pair is a visible, greppable `impl` rather than an
+/// arbitrary cast. The declaration is one-directional: implement it both
+/// ways if relabeling should work in both directions.
+///
+/// ```rust
+/// use spatial_typestate::{Frame, Point3, RelabelSafe};
+///
+/// struct LegacyNed;
+/// struct Ned;
+/// impl Frame for LegacyNed {}
+/// impl Frame for Ned {}
+///
+/// impl RelabelSafe<Ned> for LegacyNed {}
+///
+/// let old: Point3<LegacyNed> = Point3::new(1.0, 2.0, 3.0);
+/// let new: Point3<Ned> = old.relabel();
+/// ```
+pub trait RelabelSafe<G: Frame>: Frame {}
+
+/// A fixed, ordered set of frames, known at compile time.
+///
+/// Implemented for tuples of one to eight frames. It carries only metadata
+/// (how many frames and what they are called), which is handy for code that
+/// loops over a known set of frames, e.g. to emit one transform per sensor.
+/// [`spatial_frames!`](crate::spatial_frames!) can name such a set for you.
+///
+/// ```rust
+/// use spatial_typestate::{spatial_frames, FrameSet};
+///
+/// spatial_frames! {
+///     pub type Sensors = (Lidar, Camera, Imu);
+/// }
+///
+/// assert_eq!(Sensors::LEN, 3);
+/// assert_eq!(Sensors::NAMES, ["Lidar", "Camera", "Imu"]);
+/// ```
+pub trait FrameSet {
+    /// Number of frames in the set.
+    const LEN: usize;
+
+    /// [`Frame::NAME`] of each frame, in order.
+    const NAMES: &'static [&'static str];
+}
+
+macro_rules! impl_frame_set {
+    ( $( $frame:ident ),+ ) => {
+        impl<$( $frame: Frame ),+> FrameSet for ( $( $frame, )+ ) {
+            const LEN: usize = Self::NAMES.len();
+            const NAMES: &'static [&'static str] = &[ $( $frame::NAME ),+ ];
+        }
+    };
+}
+
+impl_frame_set!(F0);
+impl_frame_set!(F0, F1);
+impl_frame_set!(F0, F1, F2);
+impl_frame_set!(F0, F1, F2, F3);
+impl_frame_set!(F0, F1, F2, F3, F4);
+impl_frame_set!(F0, F1, F2, F3, F4, F5);
+impl_frame_set!(F0, F1, F2, F3, F4, F5, F6);
+impl_frame_set!(F0, F1, F2, F3, F4, F5, F6, F7);
+
+/// `Debug` stand-in for a frame tag: prints the frame's name, unquoted.
+pub(crate) struct FrameName<F: Frame>(PhantomData<F>);
+
+impl<F: Frame> FrameName<F> {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<F: Frame> fmt::Debug for FrameName<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if F::NAME.is_empty() {
+            write_short_type_name(f, core::any::type_name::<F>())
+        } else {
+            f.write_str(F::NAME)
+        }
+    }
+}
+
+/// Write a type name with module paths stripped, e.g. `Product<Meters,
+/// Meters>` for `spatial_typestate::units::Product<spatial_typestate::...>`.
+pub(crate) fn write_short_type_name(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+    let mut rest = name;
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+            .unwrap_or(rest.len());
+        let (path, tail) = rest.split_at(end);
+        f.write_str(path.rsplit("::").next().unwrap_or(path))?;
+
+        let sep = tail
+            .find(|c: char| c.is_alphanumeric() || c == '_' || c == ':')
+            .unwrap_or(tail.len());
+        f.write_str(&tail[..sep])?;
+        rest = &tail[sep..];
+    }
+
+    Ok(())
+}
+
+/// Compile-time check that the frame marker `F` is zero-sized.
+///
+/// Frame tags are meant to be free: a `Point3<F>` should cost exactly three
+/// `f64`s. Every frame-tagged constructor calls this, so a marker type that
+/// accidentally grows a field is rejected as soon as a value tagged with it
+/// is built, instead of silently inflating every such value.
+#[inline]
+pub(crate) const fn assert_zero_sized<F: Frame>() {
+    const {
+        assert!(
+            core::mem::size_of::<F>() == 0,
+            "frame marker types must be zero-sized"
+        );
+    }
+}
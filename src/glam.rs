@@ -0,0 +1,193 @@
+//! Interop with [`glam`](https://docs.rs/glam) (feature `glam`).
+//!
+//! As with the [`nalgebra`](crate::nalgebra) interop, the conversions drop the
+//! frame tag: `glam` values are frame-agnostic numbers.
+//!
+//! `glam` pairs each single-precision type with a `D`-prefixed
+//! double-precision one, and the conversions follow the scalar type of this
+//! crate's side (see [`crate::scalar`]), so none of them round:
+//!
+//! | this crate                   | `glam`               |
+//! |------------------------------|----------------------|
+//! | [`Point3<F, f32>`]           | `Vec3`               |
+//! | [`Vector3<F, f32>`]          | `Vec3`               |
+//! | [`Transform<From, To, f32>`] | `Mat4`, `Affine3A`   |
+//! | [`UnitQuat<F, f32>`]         | `Quat`               |
+//! | [`Point3<F>`]                | `DVec3`              |
+//! | [`Vector3<F>`]               | `DVec3`              |
+//! | [`Transform<From, To>`]      | `DMat4`, `DAffine3`  |
+//! | [`UnitQuat<F>`]              | `DQuat`, `Quat`      |
+//!
+//! The one conversion across precisions is from an `f64` [`UnitQuat<F>`]
+//! into a `Quat`, kept for callers that hold `f64` orientations. It rounds
+//! each component to `f32` (about seven significant digits, i.e. rotations
+//! are off by up to roughly `1e-7` rad). `glam` quaternions are not
+//! guaranteed to be normalized, so converting them back is a [`TryFrom`]
+//! that renormalizes, which also absorbs that rounding.
+//!
+//! `glam` matrices are column-major: `Mat4::to_cols_array_2d()[col][row]` is
+//! `Transform::matrix[row][col]`.
+
+use glam_dep::{Affine3A, DAffine3, DMat4, DQuat, DVec3, Mat4, Quat, Vec3};
+
+use crate::errors::SpatialError;
+use crate::frame::Frame;
+use crate::point::Point3;
+use crate::quaternion::UnitQuat;
+use crate::scalar::Scalar;
+use crate::transform::Transform;
+use crate::vector::Vector3;
+
+/// Conversions between one scalar type and its `glam` vector, matrix,
+/// affine and quaternion counterparts.
+macro_rules! glam_conversions {
+    ($t:ty, $vec:ty, $mat:ty, $affine:ty, $quat:ty) => {
+        impl<F: Frame> From<Point3<F, $t>> for $vec {
+            #[inline]
+            fn from(p: Point3<F, $t>) -> Self {
+                <$vec>::new(p.x, p.y, p.z)
+            }
+        }
+
+        impl<F: Frame> From<$vec> for Point3<F, $t> {
+            #[inline]
+            fn from(p: $vec) -> Self {
+                Self::new(p.x, p.y, p.z)
+            }
+        }
+
+        impl<F: Frame> From<Vector3<F, $t>> for $vec {
+            #[inline]
+            fn from(v: Vector3<F, $t>) -> Self {
+                <$vec>::new(v.x, v.y, v.z)
+            }
+        }
+
+        impl<F: Frame> From<$vec> for Vector3<F, $t> {
+            #[inline]
+            fn from(v: $vec) -> Self {
+                Self::new(v.x, v.y, v.z)
+            }
+        }
+
+        impl<From: Frame, To: Frame> core::convert::From<Transform<From, To, $t>> for $mat {
+            #[inline]
+            fn from(transform: Transform<From, To, $t>) -> Self {
+                let m = &transform.matrix;
+                let mut cols = [[0.0; 4]; 4];
+                for (col, c) in cols.iter_mut().enumerate() {
+                    for (row, v) in c.iter_mut().enumerate() {
+                        *v = m[row][col];
+                    }
+                }
+
+                <$mat>::from_cols_array_2d(&cols)
+            }
+        }
+
+        /// Like [`Transform::from_matrix`], this does not check that the
+        /// matrix is rigid.
+        impl<From: Frame, To: Frame> core::convert::From<$mat> for Transform<From, To, $t> {
+            #[inline]
+            fn from(matrix: $mat) -> Self {
+                let cols = matrix.to_cols_array_2d();
+                let mut m = [[0.0; 4]; 4];
+                for (row, r) in m.iter_mut().enumerate() {
+                    for (col, v) in r.iter_mut().enumerate() {
+                        *v = cols[col][row];
+                    }
+                }
+
+                Self::from_matrix(m)
+            }
+        }
+
+        /// Fails with [`SpatialError::DegenerateInput`] unless the bottom row
+        /// is exactly `[0, 0, 0, 1]`, since an affine transform cannot hold a
+        /// projective component.
+        impl<From: Frame, To: Frame> TryFrom<Transform<From, To, $t>> for $affine {
+            type Error = SpatialError;
+
+            #[inline]
+            fn try_from(transform: Transform<From, To, $t>) -> Result<Self, Self::Error> {
+                let m = &transform.matrix;
+                if !has_affine_bottom_row(m) {
+                    return Err(SpatialError::DegenerateInput);
+                }
+
+                let mut cols = [[0.0; 3]; 4];
+                for (col, c) in cols.iter_mut().enumerate() {
+                    for (row, v) in c.iter_mut().enumerate() {
+                        *v = m[row][col];
+                    }
+                }
+
+                Ok(<$affine>::from_cols_array_2d(&cols))
+            }
+        }
+
+        impl<From: Frame, To: Frame> core::convert::From<$affine> for Transform<From, To, $t> {
+            #[inline]
+            fn from(affine: $affine) -> Self {
+                let cols = affine.to_cols_array_2d();
+                let mut m = Self::identity().matrix;
+                for (row, r) in m.iter_mut().take(3).enumerate() {
+                    for (col, v) in r.iter_mut().enumerate() {
+                        *v = cols[col][row];
+                    }
+                }
+
+                Self::from_matrix(m)
+            }
+        }
+
+        impl<F: Frame> From<UnitQuat<F, $t>> for $quat {
+            #[inline]
+            fn from(quat: UnitQuat<F, $t>) -> Self {
+                let [x, y, z, w] = quat.to_xyzw();
+                <$quat>::from_xyzw(x, y, z, w)
+            }
+        }
+
+        /// Renormalizes; fails like [`UnitQuat::try_from_components`].
+        impl<F: Frame> TryFrom<$quat> for UnitQuat<F, $t> {
+            type Error = SpatialError;
+
+            #[inline]
+            fn try_from(quat: $quat) -> Result<Self, Self::Error> {
+                let [x, y, z, w] = quat.to_array();
+                Self::try_from_components(x, y, z, w)
+            }
+        }
+    };
+}
+
+glam_conversions!(f32, Vec3, Mat4, Affine3A, Quat);
+glam_conversions!(f64, DVec3, DMat4, DAffine3, DQuat);
+
+/// Rounds each component to `f32`; see the [module docs](self).
+impl<F: Frame> From<UnitQuat<F>> for Quat {
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(quat: UnitQuat<F>) -> Self {
+        let [x, y, z, w] = quat.to_xyzw();
+        Quat::from_xyzw(x as f32, y as f32, z as f32, w as f32)
+    }
+}
+
+/// Widens to `f64` and renormalizes; fails like
+/// [`UnitQuat::try_from_components`].
+impl<F: Frame> TryFrom<Quat> for UnitQuat<F> {
+    type Error = SpatialError;
+
+    #[inline]
+    fn try_from(quat: Quat) -> Result<Self, Self::Error> {
+        let [x, y, z, w] = quat.to_array();
+        Self::try_from_components(x.into(), y.into(), z.into(), w.into())
+    }
+}
+
+/// Whether the bottom row of `m` is exactly `[0, 0, 0, 1]`.
+fn has_affine_bottom_row<T: Scalar>(m: &[[T; 4]; 4]) -> bool {
+    m[3] == [T::ZERO, T::ZERO, T::ZERO, T::ONE]
+}
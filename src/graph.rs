@@ -0,0 +1,90 @@
+//! An owned, runtime-built frame tree (feature `std`).
+//!
+//! [`FrameGraph`] is a ready-made frame tree: register each frame's
+//! transform into its parent once, then ask for the transform between any
+//! two connected frames and get it back fully typed. Lookups walk the tree
+//! like [`transform_between`]: they compose edges up to the common root and
+//! invert them on the way down.
+//!
+//! ```rust
+//! use spatial_typestate::{spatial_frames, FrameGraph, Point3, Transform};
+//!
+//! spatial_frames! {
+//!     World,
+//!     Body,
+//!     Lidar,
+//! }
+//!
+//! let mut graph = FrameGraph::new();
+//! graph.insert(&Transform::<Body, World>::from_translation(10.0, 0.0, 0.0));
+//! graph.insert(&Transform::<Lidar, Body>::from_translation(0.0, 0.0, 1.5));
+//!
+//! let lidar_to_world = graph.transform::<Lidar, World>().unwrap();
+//! let p = lidar_to_world.apply_point(Point3::new(0.0, 0.0, 0.0));
+//! assert_eq!((p.x, p.z), (10.0, 1.5));
+//! ```
+//!
+//! Edges are keyed by each frame's runtime [`FrameId::ID`], which
+//! [`spatial_frames!`](crate::spatial_frames!) provides; frames declared by
+//! hand need a [`FrameId`] impl to take part.
+//!
+//! [`transform_between`]: crate::resolver::transform_between
+
+use std::collections::HashMap;
+
+use crate::errors::SpatialError;
+use crate::frame::FrameId;
+use crate::resolver::transform_through_root;
+use crate::transform::Transform;
+
+/// A tree of frames connected by rigid transforms, built at runtime.
+///
+/// Each frame has at most one parent, set by [`FrameGraph::insert`]. Which
+/// frame of an edge is the child only matters for this bookkeeping; lookups
+/// work in either direction.
+#[derive(Debug, Clone, Default)]
+pub struct FrameGraph {
+    parents: HashMap<&'static str, (&'static str, [[f64; 4]; 4])>,
+}
+
+impl FrameGraph {
+    /// An empty graph.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `child_to_parent` as the edge from `Child` to its parent
+    /// `Parent`.
+    ///
+    /// A frame has only one parent, so registering another edge out of the
+    /// same `Child` replaces the previous one. The transform must be rigid,
+    /// since lookups invert edges with [`Transform::inverse`].
+    pub fn insert<Child: FrameId, Parent: FrameId>(
+        &mut self,
+        child_to_parent: &Transform<Child, Parent>,
+    ) {
+        self.parents
+            .insert(Child::ID, (Parent::ID, child_to_parent.matrix));
+    }
+
+    /// Whether `F` has a registered parent.
+    #[must_use]
+    pub fn has_parent<F: FrameId>(&self) -> bool {
+        self.parents.contains_key(F::ID)
+    }
+
+    /// The transform from `From` to `To`, composed from the registered edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NoTransformPath`] if the two frames are not
+    /// connected, or if the edges form a chain longer than
+    /// [`MAX_TREE_DEPTH`](crate::resolver::MAX_TREE_DEPTH) (which includes
+    /// cycles).
+    pub fn transform<From: FrameId, To: FrameId>(
+        &self,
+    ) -> Result<Transform<From, To>, SpatialError> {
+        transform_through_root(From::ID, To::ID, |child| self.parents.get(child).copied())
+    }
+}
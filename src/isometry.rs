@@ -0,0 +1,192 @@
+//! Compact rigid transforms stored as rotation + translation.
+//!
+//! [`IsometryTransform<From, To>`] is the memory-lean sibling of
+//! [`Transform<From, To>`]: instead of a full 4×4 matrix (128 bytes) it keeps
+//! a unit quaternion and a translation (56 bytes). Because it can only
+//! represent rigid motions, it never needs validating and its inverse is
+//! always exact.
+//!
+//! ```rust
+//! use spatial_typestate::{Frame, IsometryTransform, Point3, Transform};
+//!
+//! struct World;
+//! struct Body;
+//! impl Frame for World {}
+//! impl Frame for Body {}
+//!
+//! let t: Transform<Body, World> = Transform::from_translation(1.0, 2.0, 3.0);
+//! let iso = IsometryTransform::from_transform(&t);
+//!
+//! let p = iso.apply_point(Point3::new(0.0, 0.0, 0.0));
+//! assert_eq!((p.x, p.y, p.z), (1.0, 2.0, 3.0));
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::frame::Frame;
+use crate::point::Point3;
+use crate::quaternion::{
+    quat_from_rotation3, quat_mul, quat_rotate, rotation3_from_quat, UnitQuat,
+};
+use crate::transform::Transform;
+use crate::vector::Vector3;
+
+/// A rigid transform from frame `From` to frame `To`, stored as a rotation
+/// and a translation.
+///
+/// A point `p` in `From` maps to `rotation · p + translation` in `To`. Both
+/// parts are expressed in `To`: `rotation` is the orientation of the `From`
+/// axes as seen from `To`, and `translation` is the position of the `From`
+/// origin in `To`.
+#[derive(Debug, PartialEq)]
+pub struct IsometryTransform<From: Frame, To: Frame> {
+    rotation: UnitQuat<To>,
+    translation: Vector3<To>,
+    _from: PhantomData<From>,
+}
+
+impl<From: Frame, To: Frame> IsometryTransform<From, To> {
+    /// The identity transform (no rotation, no translation).
+    #[inline]
+    #[must_use]
+    pub const fn identity() -> Self {
+        Self::from_parts(UnitQuat::identity(), Vector3::new(0.0, 0.0, 0.0))
+    }
+
+    /// Construct from a rotation and a translation, both expressed in `To`.
+    #[inline]
+    #[must_use]
+    pub const fn from_parts(rotation: UnitQuat<To>, translation: Vector3<To>) -> Self {
+        Self {
+            rotation,
+            translation,
+            _from: PhantomData,
+        }
+    }
+
+    /// Convert from the matrix form.
+    ///
+    /// The rotation block of `t` is assumed to be orthonormal; use
+    /// [`Transform::sanitize`] first if that is not guaranteed.
+    #[must_use]
+    pub fn from_transform(t: &Transform<From, To>) -> Self {
+        let m = &t.matrix;
+        let q = quat_from_rotation3([
+            [m[0][0], m[0][1], m[0][2]],
+            [m[1][0], m[1][1], m[1][2]],
+            [m[2][0], m[2][1], m[2][2]],
+        ]);
+
+        Self::from_parts(
+            UnitQuat::from_xyzw(q),
+            Vector3::new(m[0][3], m[1][3], m[2][3]),
+        )
+    }
+
+    /// Convert to the matrix form.
+    #[must_use]
+    pub fn to_transform(&self) -> Transform<From, To> {
+        let r = rotation3_from_quat(self.rotation.to_xyzw());
+        let t = &self.translation;
+
+        Transform::from_matrix([
+            [r[0][0], r[0][1], r[0][2], t.x],
+            [r[1][0], r[1][1], r[1][2], t.y],
+            [r[2][0], r[2][1], r[2][2], t.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// The rotation part, expressed in `To`.
+    #[inline]
+    #[must_use]
+    pub const fn rotation(&self) -> &UnitQuat<To> {
+        &self.rotation
+    }
+
+    /// The translation part (the `From` origin in `To`).
+    #[inline]
+    #[must_use]
+    pub const fn translation(&self) -> &Vector3<To> {
+        &self.translation
+    }
+
+    /// Apply this transform to a point in the `From` frame, producing a point
+    /// in the `To` frame.
+    #[inline]
+    #[must_use]
+    pub fn apply_point(&self, p: Point3<From>) -> Point3<To> {
+        let r = quat_rotate(self.rotation.to_xyzw(), [p.x, p.y, p.z]);
+        let t = &self.translation;
+
+        Point3::new(r[0] + t.x, r[1] + t.y, r[2] + t.z)
+    }
+
+    /// Chain this transform with one that continues from `To` to `Next`.
+    ///
+    /// The result first applies `self` and then `next`.
+    #[inline]
+    #[must_use]
+    pub fn compose<Next: Frame>(
+        &self,
+        next: &IsometryTransform<To, Next>,
+    ) -> IsometryTransform<From, Next> {
+        let q_next = next.rotation.to_xyzw();
+        let q = quat_mul(q_next, self.rotation.to_xyzw());
+
+        let t = &self.translation;
+        let rt = quat_rotate(q_next, [t.x, t.y, t.z]);
+        let tn = &next.translation;
+
+        IsometryTransform::from_parts(
+            UnitQuat::from_xyzw(q),
+            Vector3::new(rt[0] + tn.x, rt[1] + tn.y, rt[2] + tn.z),
+        )
+    }
+
+    /// Invert this transform, producing the mapping from `To` back to `From`.
+    ///
+    /// This is always exact up to rounding, since the stored form can only
+    /// represent rigid motions.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> IsometryTransform<To, From> {
+        let q = self.rotation.to_xyzw();
+        let q_inv = [-q[0], -q[1], -q[2], q[3]];
+
+        let t = &self.translation;
+        let rt = quat_rotate(q_inv, [t.x, t.y, t.z]);
+
+        IsometryTransform::from_parts(
+            UnitQuat::from_xyzw(q_inv),
+            Vector3::new(-rt[0], -rt[1], -rt[2]),
+        )
+    }
+}
+
+impl<From: Frame, To: Frame> Clone for IsometryTransform<From, To> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<From: Frame, To: Frame> Copy for IsometryTransform<From, To> {}
+
+impl<From: Frame, To: Frame> core::convert::From<Transform<From, To>>
+    for IsometryTransform<From, To>
+{
+    #[inline]
+    fn from(t: Transform<From, To>) -> Self {
+        Self::from_transform(&t)
+    }
+}
+
+impl<From: Frame, To: Frame> core::convert::From<IsometryTransform<From, To>>
+    for Transform<From, To>
+{
+    #[inline]
+    fn from(iso: IsometryTransform<From, To>) -> Self {
+        iso.to_transform()
+    }
+}
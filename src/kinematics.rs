@@ -0,0 +1,71 @@
+//! Frame-tagged velocities.
+//!
+//! [`Velocity3`] and [`AngularVelocity3`] wrap a [`Vector3`] to record what
+//! the components mean physically: meters per second and radians per second
+//! respectively. Both are expressed in frame `F`.
+//!
+//! ```rust
+//! use spatial_typestate::{AngularVelocity3, Frame, Vector3, Velocity3};
+//!
+//! struct World;
+//! impl Frame for World {}
+//!
+//! let v = Velocity3(Vector3::<World>::new(1.0, 0.0, 0.0));
+//! let w = AngularVelocity3(Vector3::<World>::new(0.0, 0.0, 0.1));
+//! assert_eq!(v.0.x, 1.0);
+//! assert_eq!(w.0.z, 0.1);
+//! ```
+
+use crate::frame::Frame;
+use crate::units::{Meters, Quantity, Quotient, Radians, Seconds};
+use crate::vector::Vector3;
+
+/// Linear velocity expressed in frame `F`, with components in meters per
+/// second.
+#[derive(Debug, PartialEq)]
+pub struct Velocity3<F: Frame>(pub Vector3<F>);
+
+/// Angular velocity expressed in frame `F`, with components in radians per
+/// second.
+///
+/// The direction is the instantaneous rotation axis (right-hand rule) and the
+/// magnitude is the rotation rate.
+#[derive(Debug, PartialEq)]
+pub struct AngularVelocity3<F: Frame>(pub Vector3<F>);
+
+impl<F: Frame> Velocity3<F> {
+    /// Speed (magnitude of the velocity) in meters per second.
+    #[inline]
+    #[must_use]
+    pub fn speed(self) -> Quantity<Quotient<Meters, Seconds>> {
+        Quantity::new(self.0.norm())
+    }
+}
+
+impl<F: Frame> AngularVelocity3<F> {
+    /// Rotation rate (magnitude of the angular velocity) in radians per
+    /// second.
+    #[inline]
+    #[must_use]
+    pub fn rate(self) -> Quantity<Quotient<Radians, Seconds>> {
+        Quantity::new(self.0.norm())
+    }
+}
+
+impl<F: Frame> Clone for Velocity3<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame> Copy for Velocity3<F> {}
+
+impl<F: Frame> Clone for AngularVelocity3<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame> Copy for AngularVelocity3<F> {}
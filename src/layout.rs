@@ -0,0 +1,54 @@
+//! The crate's only `unsafe` code: layout-based reference casts.
+//!
+//! Everything here relies on a `#[repr(transparent)]` or `#[repr(C)]`
+//! guarantee documented on the target type. Keep this module minimal; every
+//! function must state its safety argument next to the `unsafe` block.
+
+#![allow(unsafe_code)]
+
+use crate::frame::Frame;
+use crate::point::Point3;
+use crate::scalar::Scalar;
+use crate::transform::Transform;
+use crate::vector::Vector3;
+
+/// Reinterpret a borrowed 4×4 matrix as a borrowed, frame-tagged transform.
+#[inline]
+pub(crate) fn transform_from_matrix_ref<From: Frame, To: Frame>(
+    matrix: &[[f64; 4]; 4],
+) -> &Transform<From, To> {
+    let ptr: *const [[f64; 4]; 4] = matrix;
+
+    // SAFETY: `Transform` is `#[repr(transparent)]` over its `matrix` field;
+    // its only other fields are `PhantomData` markers, which are zero-sized
+    // with alignment 1. `Transform<From, To>` therefore has exactly the size,
+    // alignment and ABI of `[[f64; 4]; 4]`, every bit pattern valid for the
+    // array is valid for the transform, and the returned reference inherits
+    // the lifetime and (shared) mutability of the input.
+    unsafe { &*ptr.cast::<Transform<From, To>>() }
+}
+
+/// View a point's coordinates as a borrowed `[x, y, z]` array.
+#[inline]
+pub(crate) fn point_as_array<F: Frame, T: Scalar>(p: &Point3<F, T>) -> &[T; 3] {
+    let ptr: *const Point3<F, T> = p;
+
+    // SAFETY: `Point3` is `#[repr(C)]` with fields `x`, `y`, `z` of type `T`
+    // followed by a `PhantomData` marker (zero-sized, alignment 1). The three
+    // coordinates therefore sit at offsets 0, `size_of::<T>()` and
+    // `2 * size_of::<T>()` with no padding, which is exactly the layout of
+    // `[T; 3]`, and the struct is at least as aligned as the array. The
+    // returned reference inherits the input's lifetime and shared mutability.
+    unsafe { &*ptr.cast::<[T; 3]>() }
+}
+
+/// View a vector's components as a borrowed `[x, y, z]` array.
+#[inline]
+pub(crate) fn vector_as_array<F: Frame, T: Scalar>(v: &Vector3<F, T>) -> &[T; 3] {
+    let ptr: *const Vector3<F, T> = v;
+
+    // SAFETY: same argument as `point_as_array`: `Vector3` is `#[repr(C)]`
+    // with three `T` fields followed by a zero-sized `PhantomData`, matching
+    // the layout of `[T; 3]`.
+    unsafe { &*ptr.cast::<[T; 3]>() }
+}
@@ -49,13 +49,26 @@
 //!
 //! # Feature flags
 //!
-//! - `std` (default): enables integration with the Rust standard library.
+//! - `std` (default): enables integration with the Rust standard library,
+//!   including the `HashMap`-backed [`FrameGraph`].
 //! - `no_std`: builds without `std`. Disable default features and enable this:
 //!   ```toml
 //!   spatial-typestate = { version = "0.1", default-features = false, features = ["no_std"] }
 //!   ```
-//! - `nalgebra`: (planned) use `nalgebra` as an underlying math backend.
-//! - `glam`: (planned) use `glam` as an underlying math backend.
+//!   Square roots and trigonometry then come from [`libm`](https://docs.rs/libm)
+//!   instead of the `std` float methods.
+//! - `nalgebra`: `From`/`Into` conversions to and from `nalgebra` points,
+//!   vectors, unit quaternions, matrices and isometries (see
+//!   [`nalgebra`](crate::nalgebra)).
+//! - `glam`: `From`/`Into` conversions to and from `glam` vectors,
+//!   quaternions, matrices and affine transforms, in both `f32` and `f64`
+//!   (see [`glam`](crate::glam)).
+//! - `approx`: `AbsDiffEq`/`RelativeEq`/`UlpsEq` for points, vectors,
+//!   quaternions, transforms and quantities (see [`approx`](crate::approx)).
+//! - `serde`: `Serialize`/`Deserialize` for points, vectors, quaternions,
+//!   transforms and quantities (frame markers are not serialized), plus
+//!   [`serde::unit_symbol`](crate::serde::unit_symbol) for quantities tagged
+//!   with their unit symbol.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(unsafe_code)]
@@ -67,20 +80,65 @@
     clippy::pedantic
 )]
 
+pub mod aabb;
+#[cfg(feature = "approx")]
+pub mod approx;
+pub mod chain;
+pub mod displacement;
+pub mod dynamic;
 pub mod errors;
+pub mod euler;
 pub mod frame;
+#[cfg(feature = "glam")]
+pub mod glam;
+#[cfg(feature = "std")]
+pub mod graph;
+pub mod isometry;
+pub mod kinematics;
+mod layout;
 pub mod macros;
+mod math;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+pub mod plane;
 pub mod point;
+pub mod pose;
+pub mod prelude;
 pub mod quaternion;
+pub mod ray;
+pub mod resolver;
+pub mod rotation;
+pub mod scalar;
+pub mod segment;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod stamped;
 pub mod transform;
 pub mod units;
 pub mod vector;
 
 // Re-export primary types for a clean public API.
+pub use crate::aabb::Aabb;
+pub use crate::chain::FrameChain;
+pub use crate::displacement::Displacement;
 pub use crate::errors::SpatialError;
-pub use crate::frame::Frame;
+pub use crate::frame::{Axis, Frame, FrameConvention, FrameId, FrameSet, Handedness, RelabelSafe};
+#[cfg(feature = "std")]
+pub use crate::graph::FrameGraph;
+pub use crate::isometry::IsometryTransform;
+pub use crate::kinematics::{AngularVelocity3, Velocity3};
+pub use crate::plane::Plane;
 pub use crate::point::Point3;
+pub use crate::pose::{Pose, PoseIn};
 pub use crate::quaternion::UnitQuat;
+pub use crate::ray::Ray;
+pub use crate::rotation::Rotation3;
+pub use crate::scalar::Scalar;
+pub use crate::segment::Segment;
+pub use crate::stamped::Stamped;
 pub use crate::transform::Transform;
-pub use crate::units::{Degrees, Meters, Quantity, Radians, Unit};
+pub use crate::units::{
+    Celsius, ConvertTo, Degrees, Dimensionless, Feet, Kelvin, Kilometers, Meters, NauticalMiles,
+    Product, Quantity, Quotient, Radians, Seconds, Unit, UnitSymbol,
+};
 pub use crate::vector::Vector3;
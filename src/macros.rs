@@ -17,14 +17,38 @@
 //!
 //! ```ignore
 //! pub struct World;
-//! impl spatial_typestate::Frame for World {}
+//! impl spatial_typestate::Frame for World {
+//!     const NAME: &'static str = "World";
+//! }
+//! impl spatial_typestate::FrameId for World {
+//!     const ID: &'static str = concat!(module_path!(), "::World");
+//! }
 //! // etc...
 //! ```
 
 /// Define one or more zero-sized frame types and implement [`Frame`] for them.
 ///
-/// Each identifier becomes a `pub struct` with `Debug`, `Clone`, `Copy`,
-/// `PartialEq`, and `Eq` derives, plus an implementation of [`crate::Frame`].
+/// Each identifier becomes a unit struct with `Debug`, `Clone`, `Copy`,
+/// `PartialEq`, and `Eq` derives, plus an implementation of [`crate::Frame`]
+/// whose [`NAME`](crate::Frame::NAME) is the identifier and of
+/// [`crate::FrameId`] whose [`ID`](crate::FrameId::ID) is its module path
+/// and identifier.
+///
+/// Doc comments and other attributes written before a frame are forwarded to
+/// its struct, so frames can satisfy `missing_docs`. A frame is `pub` unless
+/// a visibility is written before its name: `pub(crate) Internal` or
+/// `pub(self) Scratch` restricts it as for any other item.
+///
+/// A frame can also be preceded by `#[frame(...)]` to set its axis
+/// conventions: `handedness = Right | Left` sets
+/// [`HANDEDNESS`](crate::Frame::HANDEDNESS) and
+/// `up = X | Y | Z | NegX | NegY | NegZ` sets
+/// [`UP_AXIS`](crate::Frame::UP_AXIS). Anything left out keeps the trait's
+/// default.
+///
+/// Writing the list as a type alias additionally names the group as a
+/// [`FrameSet`](crate::frame::FrameSet), exposing its length and frame
+/// names. Attributes and visibility before `type` apply to the alias.
 ///
 /// # Examples
 ///
@@ -36,15 +60,163 @@
 ///     Body,
 ///     Sensor,
 /// }
+///
+/// spatial_frames! {
+///     pub type Sensors = (Lidar, Camera);
+/// }
+///
+/// spatial_frames! {
+///     /// North-east-down navigation frame.
+///     #[frame(up = NegZ)]
+///     Ned,
+///     /// Scene frame of an imported Unity asset.
+///     #[frame(handedness = Left, up = Y)]
+///     pub(crate) Unity,
+/// }
 /// ```
 #[macro_export]
 macro_rules! spatial_frames {
+    // Plain lists of names, the common case, expand without recursing so
+    // that long lists stay clear of the macro recursion limit.
+    (
+        $( #[$set_meta:meta] )*
+        $vis:vis type $set:ident = ( $( $name:ident ),+ $(,)? );
+    ) => {
+        $crate::spatial_frames! { $( $name ),+ }
+
+        $( #[$set_meta] )*
+        $vis type $set = ( $( $name, )+ );
+    };
     ( $( $name:ident ),+ $(,)? ) => {
         $(
             #[derive(Debug, Clone, Copy, PartialEq, Eq)]
             pub struct $name;
 
-            impl $crate::Frame for $name {}
+            impl $crate::Frame for $name {
+                const NAME: &'static str = stringify!($name);
+            }
+
+            impl $crate::FrameId for $name {
+                const ID: &'static str = concat!(module_path!(), "::", stringify!($name));
+            }
         )+
     };
+
+    (
+        $( #[$set_meta:meta] )*
+        $vis:vis type $set:ident = ( $( $frames:tt )* );
+    ) => {
+        $crate::spatial_frames!(
+            @munch [$( #[$set_meta] )* $vis $set] [] [] [] $( $frames )*
+        );
+    };
+
+    // Lists with attributes or visibilities are consumed one attribute or
+    // frame at a time, collecting the names for the optional `FrameSet`
+    // alias. These `@` rules are an implementation detail.
+    (
+        @munch $set:tt $names:tt $attrs:tt [ $( $settings:tt )* ]
+        #[frame( $( $key:ident = $value:ident ),* $(,)? )] $( $rest:tt )*
+    ) => {
+        $crate::spatial_frames!(
+            @munch $set $names $attrs [ $( $settings )* $( $key = $value, )* ] $( $rest )*
+        );
+    };
+    (
+        @munch $set:tt $names:tt [ $( $attrs:tt )* ] $settings:tt
+        #[$meta:meta] $( $rest:tt )*
+    ) => {
+        $crate::spatial_frames!(
+            @munch $set $names [ $( $attrs )* #[$meta] ] $settings $( $rest )*
+        );
+    };
+    (
+        @munch $set:tt [ $( $names:ident )* ] $attrs:tt $settings:tt
+        pub ( $( $restriction:tt )* ) $name:ident $( , $( $rest:tt )* )?
+    ) => {
+        $crate::spatial_frames!(@frame [pub( $( $restriction )* )] $attrs $settings $name);
+        $crate::spatial_frames!(@munch $set [ $( $names )* $name ] [] [] $( $( $rest )* )?);
+    };
+    (
+        @munch $set:tt [ $( $names:ident )* ] $attrs:tt $settings:tt
+        pub $name:ident $( , $( $rest:tt )* )?
+    ) => {
+        $crate::spatial_frames!(@frame [pub] $attrs $settings $name);
+        $crate::spatial_frames!(@munch $set [ $( $names )* $name ] [] [] $( $( $rest )* )?);
+    };
+    (
+        @munch $set:tt [ $( $names:ident )* ] $attrs:tt $settings:tt
+        $name:ident $( , $( $rest:tt )* )?
+    ) => {
+        $crate::spatial_frames!(@frame [pub] $attrs $settings $name);
+        $crate::spatial_frames!(@munch $set [ $( $names )* $name ] [] [] $( $( $rest )* )?);
+    };
+    (@munch [] $names:tt [] []) => {};
+    (@munch [$( #[$set_meta:meta] )* $vis:vis $set:ident] [ $( $names:ident )* ] [] []) => {
+        $( #[$set_meta] )*
+        $vis type $set = ( $( $names, )* );
+    };
+    (@munch $( $rest:tt )*) => {
+        ::core::compile_error!(
+            "expected a frame name, optionally preceded by attributes and a visibility"
+        );
+    };
+    (
+        @frame [ $( $vis:tt )* ] [ $( $attrs:tt )* ]
+        [ $( $key:ident = $value:ident, )* ] $name:ident
+    ) => {
+        $( $attrs )*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        $( $vis )* struct $name;
+
+        impl $crate::Frame for $name {
+            const NAME: &'static str = stringify!($name);
+            $( $crate::spatial_frames!(@setting $key = $value); )*
+        }
+
+        impl $crate::FrameId for $name {
+            const ID: &'static str = concat!(module_path!(), "::", stringify!($name));
+        }
+    };
+    (@setting handedness = $value:ident) => {
+        const HANDEDNESS: $crate::Handedness = $crate::Handedness::$value;
+    };
+    (@setting up = $value:ident) => {
+        const UP_AXIS: $crate::Axis = $crate::Axis::$value;
+    };
+
+    ( $( $frames:tt )* ) => {
+        $crate::spatial_frames!(@munch [] [] [] [] $( $frames )*);
+    };
+}
+
+/// Spell a [`FrameChain`](crate::chain::FrameChain) type from a list of
+/// frames, in pipeline order.
+///
+/// `FrameChain![Sensor, Body, World]` is shorthand for
+/// `FrameChain<(Sensor, Body, World)>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use spatial_typestate::{spatial_frames, FrameChain, Transform};
+///
+/// spatial_frames! {
+///     Sensor,
+///     Body,
+///     World,
+/// }
+///
+/// type SensorPipeline = FrameChain![Sensor, Body, World];
+///
+/// let t: Transform<Sensor, World> = SensorPipeline::compose((
+///     Transform::<Sensor, Body>::identity(),
+///     Transform::<Body, World>::identity(),
+/// ));
+/// ```
+#[macro_export]
+macro_rules! FrameChain {
+    ( $( $frame:ty ),+ $(,)? ) => {
+        $crate::chain::FrameChain<( $( $frame, )+ )>
+    };
 }
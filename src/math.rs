@@ -0,0 +1,167 @@
+//! Floating-point functions that `core` does not provide.
+//!
+//! With the `std` feature these forward to the inherent `f64`/`f32` methods;
+//! without it (the `no_std` feature) they forward to [`libm`]. Call these
+//! instead of e.g. `x.sqrt()` anywhere in the crate so that both builds
+//! compile.
+//!
+//! [`libm`]: https://docs.rs/libm
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("spatial-typestate needs either the `std` or the `no_std` feature for float math");
+
+/// Square root.
+#[inline]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.sqrt()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sqrt(x)
+    }
+}
+
+/// Single-precision square root.
+#[inline]
+pub(crate) fn sqrtf(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    {
+        x.sqrt()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sqrtf(x)
+    }
+}
+
+/// Sine.
+#[inline]
+pub(crate) fn sin(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.sin()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sin(x)
+    }
+}
+
+/// Cosine.
+#[inline]
+pub(crate) fn cos(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.cos()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::cos(x)
+    }
+}
+
+/// Sine and cosine, as `(sin x, cos x)`.
+#[inline]
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    #[cfg(feature = "std")]
+    {
+        x.sin_cos()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sincos(x)
+    }
+}
+
+/// Single-precision sine and cosine together.
+#[inline]
+pub(crate) fn sin_cosf(x: f32) -> (f32, f32) {
+    #[cfg(feature = "std")]
+    {
+        x.sin_cos()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sincosf(x)
+    }
+}
+
+/// Tangent.
+#[inline]
+pub(crate) fn tan(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.tan()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::tan(x)
+    }
+}
+
+/// Arcsine, in `[-π/2, π/2]`.
+#[inline]
+pub(crate) fn asin(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.asin()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::asin(x)
+    }
+}
+
+/// Arccosine, in `[0, π]`.
+#[inline]
+pub(crate) fn acos(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.acos()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::acos(x)
+    }
+}
+
+/// Arctangent, in `[-π/2, π/2]`.
+#[inline]
+pub(crate) fn atan(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.atan()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::atan(x)
+    }
+}
+
+/// Four-quadrant arctangent of `y / x`, in `[-π, π]`.
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        y.atan2(x)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::atan2(y, x)
+    }
+}
+
+/// Round half away from zero.
+#[inline]
+pub(crate) fn round(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.round()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::round(x)
+    }
+}
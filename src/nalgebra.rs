@@ -0,0 +1,159 @@
+//! Interop with [`nalgebra`](https://docs.rs/nalgebra) (feature `nalgebra`).
+//!
+//! The conversions drop the frame tag: the resulting `nalgebra` values are
+//! plain, frame-agnostic numbers. Use them at the boundary with external
+//! solvers and convert results back into frame-tagged types of this crate,
+//! where the target frames are named again by the receiving type.
+//!
+//! | this crate              | `nalgebra`                       |
+//! |-------------------------|----------------------------------|
+//! | [`Point3<F>`]           | `Point3<f64>`                    |
+//! | [`Vector3<F>`]          | `Vector3<f64>`                   |
+//! | [`UnitQuat<F>`]         | `UnitQuaternion<f64>`            |
+//! | [`Transform<From, To>`] | `Matrix4<f64>`, `Isometry3<f64>` |
+//!
+//! A [`Transform`] can hold any 4×4 matrix, so converting it into an
+//! `Isometry3` is a [`TryFrom`] that rejects non-rigid transforms.
+//!
+//! The feature also makes [`Transform::try_inverse`] invert non-rigid
+//! matrices with `nalgebra`'s LU decomposition.
+
+use nalgebra_dep::{Isometry3, Matrix4, Quaternion, Translation3, UnitQuaternion, Vector4};
+
+use crate::errors::SpatialError;
+use crate::frame::Frame;
+use crate::point::Point3;
+use crate::quaternion::UnitQuat;
+use crate::transform::Transform;
+use crate::vector::Vector3;
+
+impl<F: Frame> Point3<F> {
+    /// Homogeneous column `[x, y, z, 1]` for use with 4×4 matrices.
+    ///
+    /// The `w = 1` component makes translations apply to the point.
+    #[inline]
+    #[must_use]
+    pub fn as_na_point(&self) -> Vector4<f64> {
+        Vector4::new(self.x, self.y, self.z, 1.0)
+    }
+}
+
+impl<F: Frame> Vector3<F> {
+    /// Homogeneous column `[x, y, z, 0]` for use with 4×4 matrices.
+    ///
+    /// The `w = 0` component makes translations leave the vector untouched,
+    /// as they should for directions and displacements.
+    #[inline]
+    #[must_use]
+    pub fn as_na_vector(&self) -> Vector4<f64> {
+        Vector4::new(self.x, self.y, self.z, 0.0)
+    }
+}
+
+impl<F: Frame> From<Point3<F>> for nalgebra_dep::Point3<f64> {
+    #[inline]
+    fn from(p: Point3<F>) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl<F: Frame> From<nalgebra_dep::Point3<f64>> for Point3<F> {
+    #[inline]
+    fn from(p: nalgebra_dep::Point3<f64>) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl<F: Frame> From<Vector3<F>> for nalgebra_dep::Vector3<f64> {
+    #[inline]
+    fn from(v: Vector3<F>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl<F: Frame> From<nalgebra_dep::Vector3<f64>> for Vector3<F> {
+    #[inline]
+    fn from(v: nalgebra_dep::Vector3<f64>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl<F: Frame> From<UnitQuat<F>> for UnitQuaternion<f64> {
+    #[inline]
+    fn from(quat: UnitQuat<F>) -> Self {
+        let [x, y, z, w] = quat.to_xyzw();
+        // Both sides maintain the unit-norm invariant.
+        UnitQuaternion::new_unchecked(Quaternion::new(w, x, y, z))
+    }
+}
+
+impl<F: Frame> From<UnitQuaternion<f64>> for UnitQuat<F> {
+    #[inline]
+    fn from(q: UnitQuaternion<f64>) -> Self {
+        Self::from_xyzw([q.i, q.j, q.k, q.w])
+    }
+}
+
+/// Entry `(row, col)` of the result is `transform.matrix[row][col]`.
+impl<From: Frame, To: Frame> core::convert::From<Transform<From, To>> for Matrix4<f64> {
+    #[inline]
+    fn from(transform: Transform<From, To>) -> Self {
+        Matrix4::from_fn(|row, col| transform.matrix[row][col])
+    }
+}
+
+/// Like [`Transform::from_matrix`], this does not check that the matrix is
+/// rigid.
+impl<From: Frame, To: Frame> core::convert::From<Matrix4<f64>> for Transform<From, To> {
+    #[inline]
+    fn from(matrix: Matrix4<f64>) -> Self {
+        let mut m = [[0.0_f64; 4]; 4];
+        for (row, m_row) in m.iter_mut().enumerate() {
+            for (col, v) in m_row.iter_mut().enumerate() {
+                *v = matrix[(row, col)];
+            }
+        }
+
+        Self::from_matrix(m)
+    }
+}
+
+impl<From: Frame, To: Frame> core::convert::From<Isometry3<f64>> for Transform<From, To> {
+    #[inline]
+    fn from(iso: Isometry3<f64>) -> Self {
+        let t = iso.translation.vector;
+        Self::from_quaternion_translation(iso.rotation.into(), t.x, t.y, t.z)
+    }
+}
+
+/// Fails exactly when [`Transform::to_quaternion_translation`] does.
+impl<From: Frame, To: Frame> TryFrom<Transform<From, To>> for Isometry3<f64> {
+    type Error = SpatialError;
+
+    #[inline]
+    fn try_from(transform: Transform<From, To>) -> Result<Self, Self::Error> {
+        let (rotation, [tx, ty, tz]) = transform.to_quaternion_translation()?;
+        Ok(Isometry3::from_parts(
+            Translation3::new(tx, ty, tz),
+            rotation.into(),
+        ))
+    }
+}
+
+/// General 4×4 inverse through `nalgebra`'s LU decomposition with partial
+/// pivoting, for the non-rigid branch of [`Transform::try_inverse`].
+pub(crate) fn lu_inverse(m: &[[f64; 4]; 4]) -> Result<[[f64; 4]; 4], SpatialError> {
+    let inv = Matrix4::from_fn(|row, col| m[row][col])
+        .lu()
+        .try_inverse()
+        .ok_or(SpatialError::NotInvertible)?;
+
+    let mut out = [[0.0_f64; 4]; 4];
+    for (row, out_row) in out.iter_mut().enumerate() {
+        for (col, v) in out_row.iter_mut().enumerate() {
+            *v = inv[(row, col)];
+        }
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,109 @@
+//! Planes tagged with a coordinate frame.
+//!
+//! A [`Plane<F>`] is stored in Hessian normal form: a unit normal `n` and an
+//! offset `d` such that the plane is the set of points `p` (in frame `F`)
+//! with `n · p = d`. The offset is the signed distance of the plane from the
+//! origin of `F`, measured along the normal.
+//!
+//! ```rust
+//! use spatial_typestate::{Frame, Plane, Point3, Vector3};
+//!
+//! struct World;
+//! impl Frame for World {}
+//!
+//! // The horizontal plane 2 m above the origin.
+//! let floor = Plane::try_from_point_normal(
+//!     Point3::<World>::new(0.0, 0.0, 2.0),
+//!     Vector3::new(0.0, 0.0, 3.0),
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(floor.offset(), 2.0);
+//! assert_eq!(floor.signed_distance(Point3::new(5.0, 5.0, 3.5)), 1.5);
+//! ```
+
+use crate::errors::SpatialError;
+use crate::frame::Frame;
+use crate::point::Point3;
+use crate::vector::Vector3;
+
+/// A plane in frame `F`, as a unit normal and an offset from the origin.
+#[derive(Debug, PartialEq)]
+pub struct Plane<F: Frame> {
+    normal: Vector3<F>,
+    offset: f64,
+}
+
+impl<F: Frame> Plane<F> {
+    /// The plane through `point` perpendicular to `normal`.
+    ///
+    /// The normal need not be unit length; it is normalized first. Its
+    /// direction decides which side counts as positive in
+    /// [`Plane::signed_distance`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any input is not finite, and
+    /// [`SpatialError::ZeroNormVector`] if the normal is too short to have a
+    /// direction.
+    pub fn try_from_point_normal(
+        point: Point3<F>,
+        normal: Vector3<F>,
+    ) -> Result<Self, SpatialError> {
+        if !point.x.is_finite() || !point.y.is_finite() || !point.z.is_finite() {
+            return Err(SpatialError::NonFinite);
+        }
+
+        let normal = normal.try_normalize()?;
+        Ok(Self::from_unit_normal(normal, point))
+    }
+
+    /// Unit normal of the plane.
+    #[inline]
+    #[must_use]
+    pub const fn normal(&self) -> Vector3<F> {
+        self.normal
+    }
+
+    /// Signed distance of the plane from the origin, along the normal.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Signed distance from the plane to `p`: positive on the side the
+    /// normal points to.
+    #[inline]
+    #[must_use]
+    pub fn signed_distance(&self, p: Point3<F>) -> f64 {
+        self.normal.x * p.x + self.normal.y * p.y + self.normal.z * p.z - self.offset
+    }
+
+    /// The point of the plane closest to the origin.
+    #[inline]
+    #[must_use]
+    pub fn closest_point_to_origin(&self) -> Point3<F> {
+        Point3::new(
+            self.offset * self.normal.x,
+            self.offset * self.normal.y,
+            self.offset * self.normal.z,
+        )
+    }
+
+    /// Plane through `point` with an already normalized `normal`.
+    #[inline]
+    pub(crate) fn from_unit_normal(normal: Vector3<F>, point: Point3<F>) -> Self {
+        let offset = normal.x * point.x + normal.y * point.y + normal.z * point.z;
+        Self { normal, offset }
+    }
+}
+
+impl<F: Frame> Clone for Plane<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame> Copy for Plane<F> {}
@@ -11,33 +11,65 @@
 //!
 //! let p: Point3<World> = Point3::new(1.0, 2.0, 3.0);
 //! ```
+//!
+//! Points follow affine-space rules: `point - point` is the [`Vector3`]
+//! between them, `point ± vector` is another point, and `point + point` is
+//! meaningless and does not compile.
+//!
+//! ```rust
+//! use spatial_typestate::{Frame, Point3, Vector3};
+//!
+//! struct World;
+//! impl Frame for World {}
+//!
+//! let p = Point3::<World>::new(1.0, 2.0, 3.0);
+//! let q = p + Vector3::new(0.5, 0.0, -1.0);
+//! assert_eq!((q.x, q.y, q.z), (1.5, 2.0, 2.0));
+//!
+//! let back: Vector3<World> = q - p;
+//! assert_eq!((back.x, back.y, back.z), (0.5, 0.0, -1.0));
+//! ```
 
+use core::fmt;
 use core::marker::PhantomData;
+use core::ops::{Add, Sub};
 
-use crate::frame::Frame;
+use crate::errors::SpatialError;
+use crate::frame::{assert_zero_sized, Frame, FrameName, RelabelSafe};
+use crate::math;
+use crate::scalar::Scalar;
+use crate::units::{Meters, Product, Quantity};
+use crate::vector::Vector3;
 
 /// A 3D point tagged with a coordinate frame `F`.
 ///
-/// The coordinates (`x`, `y`, `z`) are stored as `f64` and are interpreted
-/// according to your domain conventions (e.g. meters, right-handed).
+/// The coordinates (`x`, `y`, `z`) are stored as `T` (`f64` unless
+/// specified, see [`crate::scalar`]) and are interpreted according to your
+/// domain conventions (e.g. meters, right-handed).
 ///
 /// By encoding the frame in the type parameter `F`, the compiler enforces that
 /// you cannot accidentally mix points from different frames.
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct Point3<F: Frame> {
+///
+/// The struct is `#[repr(C)]` with the coordinates first (the frame marker is
+/// zero-sized), which is what makes [`Point3::as_array`] sound.
+#[derive(PartialEq)]
+#[repr(C)]
+pub struct Point3<F: Frame, T: Scalar = f64> {
     /// X coordinate in frame `F`.
-    pub x: f64,
+    pub x: T,
     /// Y coordinate in frame `F`.
-    pub y: f64,
+    pub y: T,
     /// Z coordinate in frame `F`.
-    pub z: f64,
+    pub z: T,
     _frame: PhantomData<F>,
 }
 
-impl<F: Frame> Point3<F> {
+impl<F: Frame, T: Scalar> Point3<F, T> {
     /// Construct a new point in the frame `F`.
     #[inline]
-    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+    #[must_use]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        assert_zero_sized::<F>();
         Self {
             x,
             y,
@@ -45,4 +77,189 @@ impl<F: Frame> Point3<F> {
             _frame: PhantomData,
         }
     }
+
+    /// Construct from `[x, y, z]` coordinates.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(xyz: [T; 3]) -> Self {
+        Self::new(xyz[0], xyz[1], xyz[2])
+    }
+
+    /// The coordinates as `[x, y, z]`.
+    #[inline]
+    #[must_use]
+    pub const fn into_array(self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Borrow the coordinates as `[x, y, z]` without copying, e.g. to hand
+    /// them to a graphics API or FFI buffer.
+    #[inline]
+    #[must_use]
+    pub fn as_array(&self) -> &[T; 3] {
+        crate::layout::point_as_array(self)
+    }
+
+    /// Re-tag this point as belonging to frame `G`, keeping its coordinates.
+    ///
+    /// Only available when `F` and `G` have been declared to be the same
+    /// frame via [`RelabelSafe`]. Use a transform for genuinely different
+    /// frames.
+    #[inline]
+    #[must_use]
+    pub fn relabel<G: Frame>(self) -> Point3<G, T>
+    where
+        F: RelabelSafe<G>,
+    {
+        Point3::new(self.x, self.y, self.z)
+    }
+
+    /// Linear interpolation: `self` at `t = 0`, `other` at `t = 1`.
+    ///
+    /// Computed as `self · (1 − t) + other · t` component-wise, so both
+    /// endpoints are reproduced exactly. `t` is not clamped; values outside
+    /// `[0, 1]` extrapolate along the line through the two points.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        let s = T::ONE - t;
+        Self::new(
+            self.x * s + other.x * t,
+            self.y * s + other.y * t,
+            self.z * s + other.z * t,
+        )
+    }
+
+    /// The point halfway between `self` and `other`, i.e.
+    /// [`lerp`](Point3::lerp) at `t = 0.5`.
+    #[inline]
+    #[must_use]
+    pub fn midpoint(self, other: Self) -> Self {
+        self.lerp(other, T::ONE / (T::ONE + T::ONE))
+    }
+}
+
+impl<F: Frame> Point3<F> {
+    /// Euclidean distance to `other`, in meters.
+    #[inline]
+    #[must_use]
+    pub fn distance(self, other: Self) -> Quantity<Meters> {
+        Quantity::new(math::sqrt(self.distance_squared(other).get()))
+    }
+
+    /// Squared Euclidean distance to `other`, in square meters.
+    ///
+    /// Cheaper than [`Point3::distance`] (no square root) and order-preserving,
+    /// so prefer it for nearest-neighbour comparisons.
+    #[inline]
+    #[must_use]
+    pub fn distance_squared(self, other: Self) -> Quantity<Product<Meters, Meters>> {
+        let (dx, dy, dz) = (self.x - other.x, self.y - other.y, self.z - other.z);
+        Quantity::new(dx * dx + dy * dy + dz * dz)
+    }
+
+    /// Weighted average of several points, e.g. a center of mass.
+    ///
+    /// Each point is scaled by its weight and the sum divided by the total
+    /// weight, so the result is pulled toward heavier points. Weights must
+    /// be non-negative; they need not sum to one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any coordinate or weight is
+    /// not finite, and [`SpatialError::DegenerateInput`] if a weight is
+    /// negative or the weights sum to zero (including when `points` is
+    /// empty).
+    pub fn weighted_centroid(points: &[(Self, f64)]) -> Result<Self, SpatialError> {
+        let mut total = 0.0_f64;
+        let mut sum = [0.0_f64; 3];
+
+        for (p, weight) in points {
+            let weight = *weight;
+            if !weight.is_finite() || !p.x.is_finite() || !p.y.is_finite() || !p.z.is_finite() {
+                return Err(SpatialError::NonFinite);
+            }
+            if weight < 0.0 {
+                return Err(SpatialError::DegenerateInput);
+            }
+
+            sum[0] += weight * p.x;
+            sum[1] += weight * p.y;
+            sum[2] += weight * p.z;
+            total += weight;
+        }
+
+        if !total.is_finite() {
+            return Err(SpatialError::NonFinite);
+        }
+        if total <= 0.0 {
+            return Err(SpatialError::DegenerateInput);
+        }
+
+        Ok(Self::new(sum[0] / total, sum[1] / total, sum[2] / total))
+    }
+}
+
+impl<F: Frame, T: Scalar> Clone for Point3<F, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame, T: Scalar> Copy for Point3<F, T> {}
+
+impl<F: Frame, T: Scalar> From<[T; 3]> for Point3<F, T> {
+    #[inline]
+    fn from(xyz: [T; 3]) -> Self {
+        Self::from_array(xyz)
+    }
+}
+
+impl<F: Frame, T: Scalar> From<Point3<F, T>> for [T; 3] {
+    #[inline]
+    fn from(v: Point3<F, T>) -> Self {
+        v.into_array()
+    }
+}
+
+/// Move a point by a vector in the same frame.
+impl<F: Frame, T: Scalar> Add<Vector3<F, T>> for Point3<F, T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Vector3<F, T>) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+/// Move a point back by a vector in the same frame.
+impl<F: Frame, T: Scalar> Sub<Vector3<F, T>> for Point3<F, T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Vector3<F, T>) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// The vector from `rhs` to `self`, both in the same frame.
+impl<F: Frame, T: Scalar> Sub for Point3<F, T> {
+    type Output = Vector3<F, T>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<F: Frame, T: Scalar> fmt::Debug for Point3<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Point3")
+            .field("frame", &FrameName::<F>::new())
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .finish()
+    }
 }
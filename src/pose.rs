@@ -0,0 +1,337 @@
+//! Poses of one frame expressed in another.
+//!
+//! A [`Pose<Parent, Child>`] describes where frame `Child` sits inside frame
+//! `Parent`. Numerically this is the same 4×4 matrix as the
+//! [`Transform<Child, Parent>`] that maps `Child` coordinates into `Parent`
+//! coordinates, which is exactly why the two are so easy to confuse:
+//!
+//! - "the pose of `Body` in `World`" is a `Pose<World, Body>`;
+//! - it maps body-frame points into the world frame, i.e. it *is* a
+//!   `Transform<Body, World>`.
+//!
+//! `Pose` spells the direction out in its method names so call sites never
+//! have to remember which way the underlying transform points.
+//!
+//! A pose can also be built from, and split back into, the position of the
+//! `Child` origin and the orientation of `Child`, which is how poses usually
+//! arrive from sensors and messages. Poses chain like transforms: the pose of
+//! `B` in `A` times the pose of `C` in `B` is the pose of `C` in `A`.
+//!
+//! When only the frame the pose is expressed in matters, [`PoseIn<F>`] stores
+//! a position and an orientation in `F` directly. It is a separate type
+//! because `Pose` already names the two-frame form; its
+//! [`to_transform`](PoseIn::to_transform) picks the child frame at the point
+//! of use.
+//!
+//! ```rust
+//! use spatial_typestate::{Frame, Point3, Pose, Transform};
+//!
+//! struct World;
+//! struct Body;
+//! impl Frame for World {}
+//! impl Frame for Body {}
+//!
+//! // The body sits 10 m along the world x-axis.
+//! let body_in_world: Pose<World, Body> =
+//!     Pose::from_transform(Transform::from_translation(10.0, 0.0, 0.0));
+//!
+//! // The body origin is at x = 10 in the world...
+//! let origin_world = body_in_world.transform_point_into_parent(Point3::new(0.0, 0.0, 0.0));
+//! assert_eq!(origin_world.x, 10.0);
+//!
+//! // ...and the world origin is at x = -10 as seen from the body.
+//! let origin_body = body_in_world.transform_point_into_child(Point3::new(0.0, 0.0, 0.0));
+//! assert_eq!(origin_body.x, -10.0);
+//! ```
+
+use core::ops::Mul;
+
+use crate::errors::SpatialError;
+use crate::frame::Frame;
+use crate::point::Point3;
+use crate::quaternion::UnitQuat;
+use crate::transform::Transform;
+
+/// The pose of frame `Child` expressed in frame `Parent`.
+///
+/// This is a thin newtype over the [`Transform<Child, Parent>`] that maps
+/// `Child` coordinates into `Parent` coordinates. The underlying transform is
+/// assumed to be rigid, so mapping in the opposite direction uses
+/// [`Transform::inverse`].
+#[derive(Debug, PartialEq)]
+pub struct Pose<Parent: Frame, Child: Frame> {
+    child_to_parent: Transform<Child, Parent>,
+}
+
+impl<Parent: Frame, Child: Frame> Pose<Parent, Child> {
+    /// The pose of a child frame that coincides with its parent.
+    #[inline]
+    #[must_use]
+    pub const fn identity() -> Self {
+        Self {
+            child_to_parent: Transform::identity(),
+        }
+    }
+
+    /// Wrap the transform mapping `Child` coordinates into `Parent`
+    /// coordinates as the pose of `Child` in `Parent`.
+    #[inline]
+    #[must_use]
+    pub const fn from_transform(child_to_parent: Transform<Child, Parent>) -> Self {
+        Self { child_to_parent }
+    }
+
+    /// The pose with the `Child` origin at `position` and oriented by
+    /// `orientation`: a `Child` point `p` sits at `orientation · p + position`
+    /// in `Parent`.
+    ///
+    /// The orientation is tagged with `Child` because it acts on `Child`
+    /// coordinates, as in [`Transform::from_quaternion_translation`].
+    #[inline]
+    #[must_use]
+    pub fn from_parts(position: Point3<Parent>, orientation: UnitQuat<Child>) -> Self {
+        Self::from_transform(Transform::from_quaternion_translation(
+            orientation,
+            position.x,
+            position.y,
+            position.z,
+        ))
+    }
+
+    /// The `Child` origin, expressed in `Parent`.
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> Point3<Parent> {
+        self.child_to_parent.origin()
+    }
+
+    /// Split into the position and orientation accepted by
+    /// [`Pose::from_parts`].
+    ///
+    /// Since `q` and `-q` encode the same rotation, the sign of the returned
+    /// quaternion is arbitrary.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`Transform::to_quaternion_translation`] if the underlying
+    /// transform is not finite or not rigid.
+    pub fn to_parts(&self) -> Result<(Point3<Parent>, UnitQuat<Child>), SpatialError> {
+        let (orientation, [x, y, z]) = self.child_to_parent.to_quaternion_translation()?;
+        Ok((Point3::new(x, y, z), orientation))
+    }
+
+    /// Chain with the pose of `Grandchild` in `Child`, giving the pose of
+    /// `Grandchild` in `Parent`.
+    #[inline]
+    #[must_use]
+    pub fn compose<Grandchild: Frame>(
+        &self,
+        child_pose: &Pose<Child, Grandchild>,
+    ) -> Pose<Parent, Grandchild> {
+        Pose::from_transform(child_pose.child_to_parent.compose(&self.child_to_parent))
+    }
+
+    /// Borrow the underlying `Child` → `Parent` transform.
+    #[inline]
+    #[must_use]
+    pub const fn as_transform(&self) -> &Transform<Child, Parent> {
+        &self.child_to_parent
+    }
+
+    /// Unwrap into the underlying `Child` → `Parent` transform.
+    #[inline]
+    #[must_use]
+    pub const fn into_transform(self) -> Transform<Child, Parent> {
+        self.child_to_parent
+    }
+
+    /// Express a point given in the `Child` frame in the `Parent` frame.
+    #[inline]
+    #[must_use]
+    pub fn transform_point_into_parent(&self, p: Point3<Child>) -> Point3<Parent> {
+        self.child_to_parent.apply_point(p)
+    }
+
+    /// Express a point given in the `Parent` frame in the `Child` frame.
+    #[inline]
+    #[must_use]
+    pub fn transform_point_into_child(&self, p: Point3<Parent>) -> Point3<Child> {
+        self.child_to_parent.inverse().apply_point(p)
+    }
+}
+
+impl<Parent: Frame, Child: Frame> Clone for Pose<Parent, Child> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Parent: Frame, Child: Frame> Copy for Pose<Parent, Child> {}
+
+impl<Parent: Frame, Child: Frame> From<Transform<Child, Parent>> for Pose<Parent, Child> {
+    #[inline]
+    fn from(child_to_parent: Transform<Child, Parent>) -> Self {
+        Self::from_transform(child_to_parent)
+    }
+}
+
+impl<Parent: Frame, Child: Frame> From<Pose<Parent, Child>> for Transform<Child, Parent> {
+    #[inline]
+    fn from(pose: Pose<Parent, Child>) -> Self {
+        pose.into_transform()
+    }
+}
+
+/// `a * b` is [`a.compose(&b)`](Pose::compose): the pose of `B` in `A` times
+/// the pose of `C` in `B` is the pose of `C` in `A`.
+///
+/// Note that this reads in the opposite order to [`Transform`]'s `*`, because
+/// a pose points from parent to child while a transform points from child to
+/// parent.
+impl<Parent: Frame, Child: Frame, Grandchild: Frame> Mul<Pose<Child, Grandchild>>
+    for Pose<Parent, Child>
+{
+    type Output = Pose<Parent, Grandchild>;
+
+    #[inline]
+    fn mul(self, rhs: Pose<Child, Grandchild>) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+
+/// A position and an orientation, both expressed in frame `F`.
+///
+/// This is the single-frame form of [`Pose`]: it stores the two parts
+/// directly, as they usually arrive from sensors and messages, without
+/// naming the frame they place. Use [`PoseIn::to_transform`] to pick that
+/// frame when the pose has to act on points, or [`Pose::from_parts`] when
+/// both frames are known up front.
+///
+/// A point `p` in the placed frame sits at `orientation · p + position` in
+/// `F`.
+///
+/// ```rust
+/// use spatial_typestate::units::{Quantity, Radians};
+/// use spatial_typestate::{Frame, Point3, PoseIn, Transform, UnitQuat, Vector3};
+///
+/// struct World;
+/// struct Body;
+/// impl Frame for World {}
+/// impl Frame for Body {}
+///
+/// let yaw = UnitQuat::<World>::from_axis_angle(
+///     Vector3::new(0.0, 0.0, 1.0),
+///     Quantity::<Radians>::new(core::f64::consts::FRAC_PI_2),
+/// )
+/// .unwrap();
+/// let pose = PoseIn::new(Point3::<World>::new(1.0, 0.0, 0.0), yaw);
+///
+/// let body_to_world: Transform<Body, World> = pose.to_transform();
+/// let p = body_to_world.apply_point(Point3::new(1.0, 0.0, 0.0));
+/// assert!((p.x - 1.0).abs() < 1e-12 && (p.y - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct PoseIn<F: Frame> {
+    /// Origin of the placed frame, expressed in `F`.
+    pub position: Point3<F>,
+    /// Orientation of the placed frame, expressed in `F`.
+    pub orientation: UnitQuat<F>,
+}
+
+impl<F: Frame> PoseIn<F> {
+    /// Bundle a position and an orientation.
+    #[inline]
+    #[must_use]
+    pub const fn new(position: Point3<F>, orientation: UnitQuat<F>) -> Self {
+        Self {
+            position,
+            orientation,
+        }
+    }
+
+    /// The pose at the origin of `F` with the identity orientation.
+    #[inline]
+    #[must_use]
+    pub const fn identity() -> Self {
+        Self::new(Point3::new(0.0, 0.0, 0.0), UnitQuat::identity())
+    }
+
+    /// The transform that maps `Local` coordinates into `F`: rotate by
+    /// `orientation`, then translate by `position`.
+    #[inline]
+    #[must_use]
+    pub fn to_transform<Local: Frame>(&self) -> Transform<Local, F> {
+        Transform::from_quaternion_translation(
+            UnitQuat::from_xyzw(self.orientation.to_xyzw()),
+            self.position.x,
+            self.position.y,
+            self.position.z,
+        )
+    }
+
+    /// Split a `Local` → `F` transform into a position and orientation.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`Transform::to_quaternion_translation`] if the transform
+    /// is not finite or not rigid.
+    pub fn from_transform<Local: Frame>(
+        transform: &Transform<Local, F>,
+    ) -> Result<Self, SpatialError> {
+        let (orientation, [x, y, z]) = transform.to_quaternion_translation()?;
+        Ok(Self::new(
+            Point3::new(x, y, z),
+            UnitQuat::from_xyzw(orientation.to_xyzw()),
+        ))
+    }
+
+    /// Apply `local`, a pose given relative to `self`, on top of `self`.
+    ///
+    /// This matches composing the transforms: `a.compose(&b).to_transform()`
+    /// maps points like `a.to_transform()` after `b.to_transform()`.
+    #[inline]
+    #[must_use]
+    pub fn compose(&self, local: &Self) -> Self {
+        let offset = self.orientation.rotate_point(local.position);
+        Self::new(
+            Point3::new(
+                self.position.x + offset.x,
+                self.position.y + offset.y,
+                self.position.z + offset.z,
+            ),
+            self.orientation * local.orientation,
+        )
+    }
+
+    /// Rotate `p` by `orientation`, then translate it by `position`.
+    #[inline]
+    #[must_use]
+    pub fn transform_point(&self, p: Point3<F>) -> Point3<F> {
+        let r = self.orientation.rotate_point(p);
+        Point3::new(
+            r.x + self.position.x,
+            r.y + self.position.y,
+            r.z + self.position.z,
+        )
+    }
+}
+
+impl<F: Frame> Clone for PoseIn<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame> Copy for PoseIn<F> {}
+
+/// `a * b` is [`a.compose(&b)`](PoseIn::compose).
+impl<F: Frame> Mul for PoseIn<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
@@ -0,0 +1,24 @@
+//! Convenience re-exports of the most commonly used items.
+//!
+//! ```rust
+//! use spatial_typestate::prelude::*;
+//!
+//! spatial_frames! {
+//!     World,
+//!     Body,
+//! }
+//!
+//! let p_body: Point3<Body> = Point3::new(1.0, 0.0, 0.0);
+//! let t_body_world: Transform<Body, World> = Transform::identity();
+//! let p_world = t_body_world.apply_point(p_body);
+//! ```
+
+pub use crate::errors::SpatialError;
+pub use crate::frame::{Frame, RelabelSafe};
+pub use crate::point::Point3;
+pub use crate::pose::Pose;
+pub use crate::quaternion::UnitQuat;
+pub use crate::spatial_frames;
+pub use crate::transform::Transform;
+pub use crate::units::{Degrees, Meters, Quantity, Radians, Seconds, Unit};
+pub use crate::vector::Vector3;
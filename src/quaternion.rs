@@ -7,40 +7,52 @@
 //! The type provides a checked constructor [`UnitQuat::try_from_components`]
 //! that normalizes the quaternion and rejects non-finite or zero-norm inputs.
 
+use core::fmt;
 use core::marker::PhantomData;
+use core::ops::Mul;
 
 use crate::errors::SpatialError;
-use crate::frame::Frame;
+use crate::frame::{assert_zero_sized, Frame, FrameName};
+use crate::math;
+use crate::point::Point3;
+use crate::scalar::Scalar;
+use crate::transform::Transform;
+use crate::units::{Quantity, Radians};
+use crate::vector::Vector3;
 
 /// A unit quaternion associated with a coordinate frame `F`.
 ///
 /// The quaternion is stored in `(x, y, z, w)` form, with the invariant that
-/// `x^2 + y^2 + z^2 + w^2 == 1` (within numerical tolerance).
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct UnitQuat<F: Frame> {
+/// `x^2 + y^2 + z^2 + w^2 == 1` (within numerical tolerance). Components are
+/// `f64` unless another [`Scalar`] is given as `T`.
+#[derive(PartialEq)]
+pub struct UnitQuat<F: Frame, T: Scalar = f64> {
     /// X component of the quaternion.
-    pub x: f64,
+    pub x: T,
     /// Y component of the quaternion.
-    pub y: f64,
+    pub y: T,
     /// Z component of the quaternion.
-    pub z: f64,
+    pub z: T,
     /// W component (scalar part) of the quaternion.
-    pub w: f64,
+    pub w: T,
     _frame: PhantomData<F>,
 }
 
-impl<F: Frame> UnitQuat<F> {
+impl<F: Frame, T: Scalar> UnitQuat<F, T> {
     /// Construct a unit quaternion from raw components, normalizing them.
     ///
-    /// Returns an error if any component is non-finite or if the norm is
-    /// too close to zero to be normalized safely.
-    pub fn try_from_components(x: f64, y: f64, z: f64, w: f64) -> Result<Self, SpatialError> {
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any component is non-finite,
+    /// and [`SpatialError::ZeroNormQuaternion`] if the norm is zero.
+    pub fn try_from_components(x: T, y: T, z: T, w: T) -> Result<Self, SpatialError> {
+        assert_zero_sized::<F>();
         if !x.is_finite() || !y.is_finite() || !z.is_finite() || !w.is_finite() {
             return Err(SpatialError::NonFinite);
         }
 
         let norm_sq = x * x + y * y + z * z + w * w;
-        if norm_sq == 0.0 {
+        if norm_sq == T::ZERO {
             return Err(SpatialError::ZeroNormQuaternion);
         }
 
@@ -62,7 +74,9 @@ impl<F: Frame> UnitQuat<F> {
     /// quaternion. This constructor does **not** check or normalize the input
     /// and is intended for advanced use cases where the invariant is already
     /// established externally.
-    pub const fn new_unchecked(x: f64, y: f64, z: f64, w: f64) -> Self {
+    #[must_use]
+    pub const fn new_unchecked(x: T, y: T, z: T, w: T) -> Self {
+        assert_zero_sized::<F>();
         Self {
             x,
             y,
@@ -74,13 +88,464 @@ impl<F: Frame> UnitQuat<F> {
 
     /// The identity rotation quaternion.
     #[inline]
+    #[must_use]
     pub const fn identity() -> Self {
+        assert_zero_sized::<F>();
         Self {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-            w: 1.0,
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+            w: T::ONE,
             _frame: PhantomData,
         }
     }
+
+    /// Rotation by `angle` about `axis` (right-hand rule), built as
+    /// `(sin(θ/2) · axis, cos(θ/2))`.
+    ///
+    /// The axis need not be unit length; it is normalized first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if the axis or angle is not
+    /// finite, and [`SpatialError::ZeroNormVector`] if the axis is too short
+    /// to have a direction.
+    pub fn from_axis_angle(
+        axis: Vector3<F, T>,
+        angle: Quantity<Radians, T>,
+    ) -> Result<Self, SpatialError> {
+        let theta = angle.get();
+        if !theta.is_finite() {
+            return Err(SpatialError::NonFinite);
+        }
+
+        let axis = axis.try_normalize()?;
+        let (sin_half, cos_half) = (theta / (T::ONE + T::ONE)).sin_cos();
+        Ok(Self::new_unchecked(
+            sin_half * axis.x,
+            sin_half * axis.y,
+            sin_half * axis.z,
+            cos_half,
+        ))
+    }
+
+    /// Rotate a vector of frame `F`: `q v q⁻¹`.
+    ///
+    /// Evaluated in the expanded form `v + 2w (u × v) + 2 u × (u × v)` (with
+    /// `u` the vector part), so no rotation matrix is built.
+    #[inline]
+    #[must_use]
+    pub fn rotate_vector(&self, v: Vector3<F, T>) -> Vector3<F, T> {
+        let [x, y, z] = quat_rotate(self.to_xyzw(), [v.x, v.y, v.z]);
+        Vector3::new(x, y, z)
+    }
+
+    /// The pure rotation transform whose rotation block is this quaternion,
+    /// with zero translation.
+    ///
+    /// The quaternion acts on `F` coordinates, so `F` is the source frame.
+    /// The target frame `To` is a free type parameter, usually inferred from
+    /// the binding: the same orientation can be read as a rotation within `F`
+    /// (`To = F`) or as the orientation of `F` in some other frame. This is
+    /// [`Transform::from_quaternion_translation`] with a zero translation.
+    #[inline]
+    #[must_use]
+    pub fn to_transform<To: Frame>(&self) -> Transform<F, To, T> {
+        Transform::from_quaternion_translation(*self, T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    /// Rotate a point about the origin of frame `F`.
+    ///
+    /// The point is treated as a position vector from the origin, so this
+    /// is a rotation **about the origin**, not about an arbitrary pivot; see
+    /// [`UnitQuat::rotate_point_about`] for that.
+    #[inline]
+    #[must_use]
+    pub fn rotate_point(&self, p: Point3<F, T>) -> Point3<F, T> {
+        let [x, y, z] = quat_rotate(self.to_xyzw(), [p.x, p.y, p.z]);
+        Point3::new(x, y, z)
+    }
+
+    /// Components as an `(x, y, z, w)` array.
+    #[inline]
+    pub(crate) const fn to_xyzw(self) -> [T; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Rebuild from `(x, y, z, w)` components already known to be unit
+    /// length.
+    #[inline]
+    pub(crate) const fn from_xyzw(q: [T; 4]) -> Self {
+        Self::new_unchecked(q[0], q[1], q[2], q[3])
+    }
+}
+
+impl<F: Frame> UnitQuat<F> {
+    /// The conjugate `(-x, -y, -z, w)`, which for a unit quaternion is the
+    /// inverse rotation.
+    #[inline]
+    #[must_use]
+    pub const fn conjugate(&self) -> Self {
+        Self::new_unchecked(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Axis and angle of this rotation, the inverse of
+    /// [`UnitQuat::from_axis_angle`].
+    ///
+    /// The axis is unit length and the angle lies in `[0, π]`: since `q` and
+    /// `-q` encode the same rotation, a rotation by more than half a turn is
+    /// reported as the shorter rotation about the opposite axis. For the
+    /// identity (or a rotation too small to have a well-defined axis) the
+    /// result is `x̂` with angle `0`.
+    #[must_use]
+    pub fn to_axis_angle(&self) -> (Vector3<F>, Quantity<Radians>) {
+        let sign = if self.w < 0.0 { -1.0 } else { 1.0 };
+        let (x, y, z, w) = (sign * self.x, sign * self.y, sign * self.z, sign * self.w);
+
+        let sin_half = math::sqrt(x * x + y * y + z * z);
+        if sin_half <= f64::EPSILON {
+            return (Vector3::new(1.0, 0.0, 0.0), Quantity::new(0.0));
+        }
+
+        (
+            Vector3::new(x / sin_half, y / sin_half, z / sin_half),
+            Quantity::new(2.0 * math::atan2(sin_half, w)),
+        )
+    }
+
+    /// Rotation described by a rotation vector: its direction is the axis
+    /// and its length the angle in radians.
+    ///
+    /// This is the usual attitude parameterization in filters. The zero
+    /// vector yields the identity, and small vectors are handled without
+    /// dividing by their length.
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_vector(rv: Vector3<F>) -> Self {
+        Self::from_xyzw(quat_exp3([rv.x, rv.y, rv.z]))
+    }
+
+    /// Rotate a point about `pivot` rather than the frame origin.
+    ///
+    /// Equivalent to moving `pivot` to the origin, rotating, and moving it
+    /// back: `pivot + q (p - pivot)`.
+    #[inline]
+    #[must_use]
+    pub fn rotate_point_about(&self, p: Point3<F>, pivot: Point3<F>) -> Point3<F> {
+        let [x, y, z] = quat_rotate(
+            self.to_xyzw(),
+            [p.x - pivot.x, p.y - pivot.y, p.z - pivot.z],
+        );
+        Point3::new(pivot.x + x, pivot.y + y, pivot.z + z)
+    }
+
+    /// Dot product of the quaternion's vector part with `dir`.
+    ///
+    /// The vector part is `sin(θ/2) · axis`, so this is the projection of the
+    /// rotation axis onto `dir` scaled by `sin(θ/2)`; it is not normalized.
+    /// Its sign tells which way the rotation turns about `dir`.
+    #[inline]
+    #[must_use]
+    pub fn axis_dot(&self, dir: Vector3<F>) -> f64 {
+        self.x * dir.x + self.y * dir.y + self.z * dir.z
+    }
+
+    /// The rotation taking `reference` to `self`: `reference⁻¹ ⊗ self`.
+    ///
+    /// Both orientations must live in the same frame `F`. Composing the
+    /// result back onto the reference, `reference ⊗ delta`, recovers `self`.
+    #[inline]
+    #[must_use]
+    pub fn relative_to(&self, reference: &Self) -> Self {
+        Self::from_xyzw(quat_mul(reference.conjugate().to_xyzw(), self.to_xyzw()))
+    }
+
+    /// The angular distance to `other`, in `[0, π]`: `2·acos(|q₁ · q₂|)`.
+    ///
+    /// Taking the absolute value of the dot product accounts for the double
+    /// cover, so `q` and `−q` are zero apart. The dot product is clamped to
+    /// `1` first, since rounding can push it slightly past and `acos` would
+    /// return NaN.
+    #[inline]
+    #[must_use]
+    pub fn angle_to(&self, other: &Self) -> Quantity<Radians> {
+        let a = self.to_xyzw();
+        let b = other.to_xyzw();
+        let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+        Quantity::new(2.0 * math::acos(dot.abs().min(1.0)))
+    }
+
+    /// Spherical linear interpolation from `self` (`t = 0`) to `other`
+    /// (`t = 1`) at constant angular velocity.
+    ///
+    /// Always follows the shorter arc: if the two quaternions lie in
+    /// opposite hemispheres, `other` is negated first (it encodes the same
+    /// rotation). When they are nearly parallel the sine of the angle between
+    /// them vanishes, so a normalized linear interpolation is used instead.
+    ///
+    /// `t` is not clamped; values outside `[0, 1]` extrapolate along the
+    /// same arc.
+    #[must_use]
+    pub fn slerp(&self, other: &Self, t: f64) -> Self {
+        const NLERP_THRESHOLD: f64 = 0.9995;
+
+        let a = self.to_xyzw();
+        let mut b = other.to_xyzw();
+        let mut cos_theta = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+        if cos_theta < 0.0 {
+            b = b.map(|v| -v);
+            cos_theta = -cos_theta;
+        }
+
+        let (wa, wb) = if cos_theta > NLERP_THRESHOLD {
+            (1.0 - t, t)
+        } else {
+            let theta = math::acos(cos_theta);
+            let sin_theta = math::sin(theta);
+            (
+                math::sin((1.0 - t) * theta) / sin_theta,
+                math::sin(t * theta) / sin_theta,
+            )
+        };
+
+        let q = [0, 1, 2, 3].map(|i| wa * a[i] + wb * b[i]);
+        let norm = math::sqrt(q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]);
+        Self::from_xyzw(q.map(|v| v / norm))
+    }
+
+    /// Propagate this orientation by a constant angular velocity `omega`
+    /// (radians per second, expressed in frame `F`) over `dt` seconds.
+    ///
+    /// The update is the exact exponential map for constant `omega`:
+    /// `q' = exp(omega · dt) ⊗ q`, i.e. the increment is applied on the left,
+    /// in frame `F`.
+    #[inline]
+    #[must_use]
+    pub fn integrate(&self, omega: Vector3<F>, dt: f64) -> Self {
+        let step = quat_exp3([omega.x * dt, omega.y * dt, omega.z * dt]);
+        Self::from_xyzw(quat_mul(step, self.to_xyzw()))
+    }
+
+    /// Like [`UnitQuat::integrate`], additionally returning the Jacobian of
+    /// the result with respect to `omega`.
+    ///
+    /// Errors on the result are parameterized in the tangent space of SO(3)
+    /// by a **left** (frame-`F`) rotation vector `δθ`, so that a perturbed
+    /// orientation is `exp(δθ) ⊗ q'`. This is the usual error-state
+    /// convention for EKF attitude filters with angular velocity expressed
+    /// in `F`. The returned matrix `J` satisfies `δθ ≈ J δω` and equals
+    /// `dt · J_l(omega · dt)`, where `J_l` is the left Jacobian of SO(3).
+    /// Feed it into the covariance propagation as `P' = J Q Jᵀ + …` for the
+    /// angular-velocity noise `Q`.
+    #[must_use]
+    pub fn integrate_with_jacobian(&self, omega: Vector3<F>, dt: f64) -> (Self, [[f64; 3]; 3]) {
+        let phi = [omega.x * dt, omega.y * dt, omega.z * dt];
+        let theta_sq = phi[0] * phi[0] + phi[1] * phi[1] + phi[2] * phi[2];
+        let theta = math::sqrt(theta_sq);
+
+        // J_l(φ) = I + a [φ]ₓ + b [φ]ₓ², with series expansions near zero.
+        let (a, b) = if theta < 1e-6 {
+            (0.5 - theta_sq / 24.0, 1.0 / 6.0 - theta_sq / 120.0)
+        } else {
+            let (sin, cos) = math::sin_cos(theta);
+            ((1.0 - cos) / theta_sq, (theta - sin) / (theta_sq * theta))
+        };
+
+        let skew = [
+            [0.0, -phi[2], phi[1]],
+            [phi[2], 0.0, -phi[0]],
+            [-phi[1], phi[0], 0.0],
+        ];
+        let mut jac = [[0.0_f64; 3]; 3];
+        for (row, jac_row) in jac.iter_mut().enumerate() {
+            for (col, v) in jac_row.iter_mut().enumerate() {
+                let identity = if row == col { 1.0 } else { 0.0 };
+                let skew_sq = skew[row][0] * skew[0][col]
+                    + skew[row][1] * skew[1][col]
+                    + skew[row][2] * skew[2][col];
+                *v = dt * (identity + a * skew[row][col] + b * skew_sq);
+            }
+        }
+
+        (self.integrate(omega, dt), jac)
+    }
+}
+
+/// Hamilton product `self ⊗ rhs`: the rotation that applies `rhs` first and
+/// then `self`, matching the product of the corresponding rotation matrices.
+///
+/// Both operands must live in the same frame `F`. The product is renormalized
+/// so that long chains of compositions do not drift off the unit sphere.
+impl<F: Frame, T: Scalar> Mul for UnitQuat<F, T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_xyzw(quat_mul(self.to_xyzw(), rhs.to_xyzw()))
+    }
+}
+
+impl<F: Frame, T: Scalar> Clone for UnitQuat<F, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame, T: Scalar> Copy for UnitQuat<F, T> {}
+
+impl<F: Frame, T: Scalar> fmt::Debug for UnitQuat<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnitQuat")
+            .field("frame", &FrameName::<F>::new())
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .field("w", &self.w)
+            .finish()
+    }
+}
+
+/// Convert an `(x, y, z, w)` unit quaternion to a rotation matrix.
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn rotation3_from_quat<T: Scalar>(q: [T; 4]) -> [[T; 3]; 3] {
+    let [x, y, z, w] = q;
+    let two = T::ONE + T::ONE;
+    [
+        [
+            T::ONE - two * (y * y + z * z),
+            two * (x * y - z * w),
+            two * (x * z + y * w),
+        ],
+        [
+            two * (x * y + z * w),
+            T::ONE - two * (x * x + z * z),
+            two * (y * z - x * w),
+        ],
+        [
+            two * (x * z - y * w),
+            two * (y * z + x * w),
+            T::ONE - two * (x * x + y * y),
+        ],
+    ]
+}
+
+/// Convert a rotation matrix to an `(x, y, z, w)` unit quaternion using
+/// Shepperd's method, which picks the numerically largest component to
+/// divide by.
+pub(crate) fn quat_from_rotation3(r: [[f64; 3]; 3]) -> [f64; 4] {
+    let trace = r[0][0] + r[1][1] + r[2][2];
+
+    if trace > 0.0 {
+        let s = math::sqrt(trace + 1.0) * 2.0;
+        [
+            (r[2][1] - r[1][2]) / s,
+            (r[0][2] - r[2][0]) / s,
+            (r[1][0] - r[0][1]) / s,
+            0.25 * s,
+        ]
+    } else if r[0][0] > r[1][1] && r[0][0] > r[2][2] {
+        let s = math::sqrt(1.0 + r[0][0] - r[1][1] - r[2][2]) * 2.0;
+        [
+            0.25 * s,
+            (r[0][1] + r[1][0]) / s,
+            (r[0][2] + r[2][0]) / s,
+            (r[2][1] - r[1][2]) / s,
+        ]
+    } else if r[1][1] > r[2][2] {
+        let s = math::sqrt(1.0 + r[1][1] - r[0][0] - r[2][2]) * 2.0;
+        [
+            (r[0][1] + r[1][0]) / s,
+            0.25 * s,
+            (r[1][2] + r[2][1]) / s,
+            (r[0][2] - r[2][0]) / s,
+        ]
+    } else {
+        let s = math::sqrt(1.0 + r[2][2] - r[0][0] - r[1][1]) * 2.0;
+        [
+            (r[0][2] + r[2][0]) / s,
+            (r[1][2] + r[2][1]) / s,
+            0.25 * s,
+            (r[1][0] - r[0][1]) / s,
+        ]
+    }
+}
+
+/// Logarithm of an `(x, y, z, w)` unit quaternion as a rotation vector
+/// (axis scaled by angle), taking the shorter of the two equivalent
+/// rotations.
+pub(crate) fn quat_log3(q: [f64; 4]) -> [f64; 3] {
+    let sign = if q[3] < 0.0 { -1.0 } else { 1.0 };
+    let v = [sign * q[0], sign * q[1], sign * q[2]];
+    let w = sign * q[3];
+
+    let sin_half = math::sqrt(v[0] * v[0] + v[1] * v[1] + v[2] * v[2]);
+    if sin_half < 1e-12 {
+        // θ ≈ 2 sin(θ/2) for small angles.
+        return [2.0 * v[0], 2.0 * v[1], 2.0 * v[2]];
+    }
+
+    let scale = 2.0 * math::atan2(sin_half, w) / sin_half;
+    [scale * v[0], scale * v[1], scale * v[2]]
+}
+
+/// Exponential of a rotation vector (axis scaled by angle) as an
+/// `(x, y, z, w)` unit quaternion.
+pub(crate) fn quat_exp3(rv: [f64; 3]) -> [f64; 4] {
+    let theta = math::sqrt(rv[0] * rv[0] + rv[1] * rv[1] + rv[2] * rv[2]);
+    if theta < 1e-12 {
+        // sin(θ/2)/θ ≈ 1/2 for small angles; renormalize to stay on the unit
+        // sphere.
+        let q = [0.5 * rv[0], 0.5 * rv[1], 0.5 * rv[2], 1.0];
+        let norm = math::sqrt(q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + 1.0);
+        return q.map(|c| c / norm);
+    }
+
+    let (sin_half, cos_half) = math::sin_cos(0.5 * theta);
+    let k = sin_half / theta;
+    [k * rv[0], k * rv[1], k * rv[2], cos_half]
+}
+
+/// Hamilton product `a ⊗ b` of two `(x, y, z, w)` quaternions, renormalized
+/// to keep repeated composition from drifting off the unit sphere.
+pub(crate) fn quat_mul<T: Scalar>(a: [T; 4], b: [T; 4]) -> [T; 4] {
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+
+    let q = [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ];
+    let norm = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+
+    [q[0] / norm, q[1] / norm, q[2] / norm, q[3] / norm]
+}
+
+/// Rotate `v` by the `(x, y, z, w)` unit quaternion `q`, i.e. `q v q⁻¹`.
+///
+/// Uses the expanded form `v + 2w (u × v) + 2 u × (u × v)` with `u` the
+/// vector part, which avoids building a rotation matrix.
+pub(crate) fn quat_rotate<T: Scalar>(q: [T; 4], v: [T; 3]) -> [T; 3] {
+    let u = [q[0], q[1], q[2]];
+    let w = q[3];
+
+    let uv = [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ];
+    let uuv = [
+        u[1] * uv[2] - u[2] * uv[1],
+        u[2] * uv[0] - u[0] * uv[2],
+        u[0] * uv[1] - u[1] * uv[0],
+    ];
+
+    let two = T::ONE + T::ONE;
+    [
+        v[0] + two * (w * uv[0] + uuv[0]),
+        v[1] + two * (w * uv[1] + uuv[1]),
+        v[2] + two * (w * uv[2] + uuv[2]),
+    ]
 }
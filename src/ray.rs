@@ -0,0 +1,94 @@
+//! Rays tagged with a coordinate frame.
+//!
+//! A [`Ray<F>`] starts at an origin in frame `F` and extends forever along a
+//! unit direction, which is what raycasting against sensor data needs. The
+//! direction is normalized on construction, so the ray parameter `t` in
+//! [`Ray::at`] is a distance along the ray in the units of `F`.
+//!
+//! ```rust
+//! use spatial_typestate::{Frame, Point3, Ray, Vector3};
+//!
+//! struct Lidar;
+//! impl Frame for Lidar {}
+//!
+//! let beam = Ray::try_new(Point3::<Lidar>::new(0.0, 0.0, 1.0), Vector3::new(0.0, 2.0, 0.0))
+//!     .unwrap();
+//!
+//! let hit = beam.at(5.0);
+//! assert_eq!((hit.x, hit.y, hit.z), (0.0, 5.0, 1.0));
+//! ```
+
+use crate::errors::SpatialError;
+use crate::frame::Frame;
+use crate::point::Point3;
+use crate::vector::Vector3;
+
+/// A half-line in frame `F`: an origin and a unit direction.
+#[derive(Debug, PartialEq)]
+pub struct Ray<F: Frame> {
+    origin: Point3<F>,
+    direction: Vector3<F>,
+}
+
+impl<F: Frame> Ray<F> {
+    /// The ray from `origin` along `direction`.
+    ///
+    /// The direction need not be unit length; it is normalized first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any input is not finite, and
+    /// [`SpatialError::ZeroNormVector`] if the direction is too short to
+    /// normalize.
+    pub fn try_new(origin: Point3<F>, direction: Vector3<F>) -> Result<Self, SpatialError> {
+        if !origin.x.is_finite() || !origin.y.is_finite() || !origin.z.is_finite() {
+            return Err(SpatialError::NonFinite);
+        }
+
+        let direction = direction.try_normalize()?;
+        Ok(Self::from_unit_direction(origin, direction))
+    }
+
+    /// Build from a direction that is already unit length.
+    #[inline]
+    pub(crate) const fn from_unit_direction(origin: Point3<F>, direction: Vector3<F>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Where the ray starts.
+    #[inline]
+    #[must_use]
+    pub const fn origin(&self) -> Point3<F> {
+        self.origin
+    }
+
+    /// Unit direction of the ray.
+    #[inline]
+    #[must_use]
+    pub const fn direction(&self) -> Vector3<F> {
+        self.direction
+    }
+
+    /// The point `origin + t · direction`, i.e. `t` along the ray.
+    ///
+    /// Negative `t` gives points behind the origin, on the line through the
+    /// ray.
+    #[inline]
+    #[must_use]
+    pub fn at(&self, t: f64) -> Point3<F> {
+        Point3::new(
+            self.origin.x + t * self.direction.x,
+            self.origin.y + t * self.direction.y,
+            self.origin.z + t * self.direction.z,
+        )
+    }
+}
+
+impl<F: Frame> Clone for Ray<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame> Copy for Ray<F> {}
@@ -0,0 +1,135 @@
+//! Typed transform lookups backed by a runtime frame tree.
+//!
+//! Some systems only learn how their frames relate at runtime (from a robot
+//! description, a calibration file, a message bus, ...). A [`FrameResolver`]
+//! exposes such a tree; [`transform_between`] then walks it and hands back a
+//! statically-typed [`Transform<From, To>`], so code downstream of the lookup
+//! keeps full compile-time frame checking.
+//!
+//! Frames are identified at runtime by their marker's [`TypeId`].
+//!
+//! ```rust
+//! use core::any::TypeId;
+//!
+//! use spatial_typestate::resolver::{transform_between, FrameResolver};
+//! use spatial_typestate::{spatial_frames, Point3, Transform};
+//!
+//! spatial_frames! {
+//!     World,
+//!     Body,
+//! }
+//!
+//! struct Tree;
+//!
+//! impl FrameResolver for Tree {
+//!     fn parent(&self, child: TypeId) -> Option<(TypeId, [[f64; 4]; 4])> {
+//!         (child == TypeId::of::<Body>()).then(|| {
+//!             let body_to_world: Transform<Body, World> = Transform::from_translation(1.0, 0.0, 0.0);
+//!             (TypeId::of::<World>(), body_to_world.matrix)
+//!         })
+//!     }
+//! }
+//!
+//! let t = transform_between::<Body, World, _>(&Tree).unwrap();
+//! assert_eq!(t.apply_point(Point3::new(0.0, 0.0, 0.0)).x, 1.0);
+//! ```
+
+use core::any::TypeId;
+
+use crate::errors::SpatialError;
+use crate::frame::Frame;
+use crate::transform::Transform;
+
+/// Longest parent chain [`transform_between`] will follow before giving up.
+///
+/// This bounds the walk on malformed resolvers whose parent links form a
+/// cycle.
+pub const MAX_TREE_DEPTH: usize = 64;
+
+/// A runtime tree of frames connected by rigid transforms.
+///
+/// Each frame has at most one parent. Frames without a parent are roots;
+/// two frames can only be related if they share the same root.
+pub trait FrameResolver {
+    /// The parent of `child` together with the matrix mapping `child`
+    /// coordinates into the parent's, or `None` if `child` is a root or
+    /// unknown to this resolver.
+    ///
+    /// The matrix must be a rigid transform, since walking the tree
+    /// downwards uses [`Transform::inverse`].
+    fn parent(&self, child: TypeId) -> Option<(TypeId, [[f64; 4]; 4])>;
+}
+
+/// Look up the transform from `From` to `To` in a runtime frame tree.
+///
+/// Both frames are walked up to their root; the result maps `From` into the
+/// root and then back down into `To`. A direct parent edge is just the
+/// one-hop case of this.
+///
+/// # Errors
+///
+/// Returns [`SpatialError::NoTransformPath`] if the two frames do not share
+/// a root, or if either parent chain is longer than [`MAX_TREE_DEPTH`].
+pub fn transform_between<From, To, R>(resolver: &R) -> Result<Transform<From, To>, SpatialError>
+where
+    From: Frame,
+    To: Frame,
+    R: FrameResolver + ?Sized,
+{
+    transform_through_root(TypeId::of::<From>(), TypeId::of::<To>(), |child| {
+        resolver.parent(child)
+    })
+}
+
+/// [`transform_between`] over any kind of frame key: walk `from` and `to`
+/// up to their root through `parent` and compose the two chains.
+pub(crate) fn transform_through_root<From, To, K>(
+    from: K,
+    to: K,
+    parent: impl Fn(K) -> Option<(K, [[f64; 4]; 4])>,
+) -> Result<Transform<From, To>, SpatialError>
+where
+    From: Frame,
+    To: Frame,
+    K: Copy + PartialEq,
+{
+    let (from_root, from_to_root) = walk_to_root::<From, K>(from, &parent)?;
+    let (to_root, to_to_root) = walk_to_root::<To, K>(to, &parent)?;
+    if from_root != to_root {
+        return Err(SpatialError::NoTransformPath);
+    }
+
+    Ok(from_to_root.compose(&to_to_root.inverse()))
+}
+
+/// Stand-in for whichever frame is the root of a resolver's tree.
+#[derive(Debug)]
+enum Root {}
+
+impl Frame for Root {}
+
+/// Follow parent links from `start` (the key of `F`) to its root, composing
+/// the edges on the way.
+fn walk_to_root<F, K>(
+    start: K,
+    parent: &impl Fn(K) -> Option<(K, [[f64; 4]; 4])>,
+) -> Result<(K, Transform<F, Root>), SpatialError>
+where
+    F: Frame,
+    K: Copy,
+{
+    let mut current = start;
+    let mut to_root = Transform::<F, Root>::identity();
+
+    for _ in 0..=MAX_TREE_DEPTH {
+        match parent(current) {
+            None => return Ok((current, to_root)),
+            Some((next, matrix)) => {
+                to_root = to_root.compose(&Transform::<Root, Root>::from_matrix(matrix));
+                current = next;
+            }
+        }
+    }
+
+    Err(SpatialError::NoTransformPath)
+}
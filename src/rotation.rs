@@ -0,0 +1,136 @@
+//! Frame-tagged 3×3 rotation matrices.
+//!
+//! A [`Rotation3<From, To>`] is the rotation-only counterpart of
+//! [`Transform<From, To>`](crate::transform::Transform): it maps `From`
+//! vectors into `To` without a translation column or a homogeneous row.
+//!
+//! # Relation to [`UnitQuat`]
+//!
+//! A [`UnitQuat<F>`] rotates vectors *within* frame `F`, so it corresponds to
+//! a [`Rotation3<F, F>`] and the conversions are only offered for that case:
+//! [`Rotation3::from_quat`] and [`Rotation3::to_quat`]. Both go through the
+//! same closed forms as the rest of the crate (Shepperd's method for
+//! matrix → quaternion), and applying either representation to a vector
+//! gives the same result. To read the rotation as the orientation of one
+//! frame in another, relabel the vectors it acts on explicitly (see
+//! [`RelabelSafe`](crate::frame::RelabelSafe)).
+//!
+//! ```rust
+//! use spatial_typestate::{Frame, Quantity, Rotation3, UnitQuat, Vector3};
+//!
+//! struct World;
+//! impl Frame for World {}
+//!
+//! let q = UnitQuat::<World>::from_axis_angle(
+//!     Vector3::new(0.0, 0.0, 1.0),
+//!     Quantity::new(core::f64::consts::FRAC_PI_2),
+//! )
+//! .unwrap();
+//! let r = Rotation3::from_quat(q);
+//!
+//! let v = r.apply_vector(Vector3::new(1.0, 0.0, 0.0));
+//! assert!((v.y - 1.0).abs() < 1e-12);
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::frame::{assert_zero_sized, Frame, FrameName};
+use crate::quaternion::{quat_from_rotation3, rotation3_from_quat, UnitQuat};
+use crate::vector::Vector3;
+
+/// A proper rotation mapping `From` vectors into `To` vectors.
+///
+/// The matrix is orthonormal with determinant `+1`; the only constructors
+/// are those that preserve this.
+#[derive(PartialEq)]
+pub struct Rotation3<From: Frame, To: Frame> {
+    matrix: [[f64; 3]; 3],
+    _from: PhantomData<From>,
+    _to: PhantomData<To>,
+}
+
+impl<From: Frame, To: Frame> Rotation3<From, To> {
+    /// The rotation that leaves every vector unchanged.
+    #[inline]
+    #[must_use]
+    pub const fn identity() -> Self {
+        Self::from_matrix_unchecked([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// Row-major rotation matrix.
+    #[inline]
+    #[must_use]
+    pub const fn matrix(&self) -> [[f64; 3]; 3] {
+        self.matrix
+    }
+
+    /// Rotate a `From`-frame vector into `To`.
+    #[inline]
+    #[must_use]
+    pub fn apply_vector(&self, v: Vector3<From>) -> Vector3<To> {
+        let m = &self.matrix;
+        Vector3::new(
+            m[0][0] * v.x + m[0][1] * v.y + m[0][2] * v.z,
+            m[1][0] * v.x + m[1][1] * v.y + m[1][2] * v.z,
+            m[2][0] * v.x + m[2][1] * v.y + m[2][2] * v.z,
+        )
+    }
+
+    /// The inverse rotation, i.e. the transpose.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Rotation3<To, From> {
+        let m = &self.matrix;
+        Rotation3::from_matrix_unchecked([0, 1, 2].map(|row| [m[0][row], m[1][row], m[2][row]]))
+    }
+
+    #[inline]
+    const fn from_matrix_unchecked(matrix: [[f64; 3]; 3]) -> Self {
+        assert_zero_sized::<From>();
+        assert_zero_sized::<To>();
+        Self {
+            matrix,
+            _from: PhantomData,
+            _to: PhantomData,
+        }
+    }
+}
+
+impl<F: Frame> Rotation3<F, F> {
+    /// The rotation matrix of a within-frame quaternion.
+    #[inline]
+    #[must_use]
+    pub fn from_quat(q: UnitQuat<F>) -> Self {
+        Self::from_matrix_unchecked(rotation3_from_quat(q.to_xyzw()))
+    }
+
+    /// The quaternion of this rotation, via Shepperd's method.
+    ///
+    /// `q` and `-q` encode the same rotation, so a round trip through
+    /// [`Rotation3::from_quat`] may flip the sign of every component.
+    #[inline]
+    #[must_use]
+    pub fn to_quat(&self) -> UnitQuat<F> {
+        UnitQuat::from_xyzw(quat_from_rotation3(self.matrix))
+    }
+}
+
+impl<From: Frame, To: Frame> Clone for Rotation3<From, To> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<From: Frame, To: Frame> Copy for Rotation3<From, To> {}
+
+impl<From: Frame, To: Frame> fmt::Debug for Rotation3<From, To> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rotation3")
+            .field("from", &FrameName::<From>::new())
+            .field("to", &FrameName::<To>::new())
+            .field("matrix", &self.matrix)
+            .finish()
+    }
+}
@@ -0,0 +1,124 @@
+//! Floating-point scalar types usable as coordinates.
+//!
+//! [`crate::point::Point3`], [`crate::vector::Vector3`] and
+//! [`crate::transform::Transform`] take a trailing scalar type parameter that
+//! defaults to `f64`, so `Point3<World>` still means a double-precision point.
+//! Spell out `f32` to trade precision for memory and bandwidth, e.g. for large
+//! point clouds or GPU-bound buffers:
+//!
+//! ```rust
+//! use spatial_typestate::{Frame, Point3, Transform};
+//!
+//! struct World;
+//! struct Body;
+//! impl Frame for World {}
+//! impl Frame for Body {}
+//!
+//! let t: Transform<Body, World, f32> = Transform::from_translation(1.0, 0.0, 0.0);
+//! let p: Point3<World, f32> = t.apply_point(Point3::new(0.5, 0.0, 0.0));
+//! assert_eq!(p.x, 1.5_f32);
+//! ```
+//!
+//! [`crate::quaternion::UnitQuat`] and [`crate::units::Quantity`] take the
+//! same parameter, so an `f32` rotation can be built from an axis and an
+//! `f32` angle and turned into a transform:
+//!
+//! ```rust
+//! use spatial_typestate::{Frame, Point3, Quantity, Radians, Transform, UnitQuat, Vector3};
+//!
+//! struct Body;
+//! impl Frame for Body {}
+//!
+//! let angle: Quantity<Radians, f32> = Quantity::new(core::f32::consts::FRAC_PI_2);
+//! let q = UnitQuat::from_axis_angle(Vector3::<Body, f32>::new(0.0, 0.0, 1.0), angle).unwrap();
+//! let t: Transform<Body, Body, f32> = q.to_transform();
+//! let p = t.apply_point(Point3::new(1.0, 0.0, 0.0));
+//! assert!((p.y - 1.0).abs() < 1e-6);
+//! ```
+//!
+//! The generic part of the API is construction, composition and
+//! application: points and vectors, quaternion construction and rotation,
+//! quantity construction and arithmetic. Unit conversions, trigonometry on
+//! quantities, interpolation and checked matrix operations are defined for
+//! the `f64` default. The `glam` interop converts both precisions, while
+//! the `nalgebra` interop is `f64` only.
+
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A floating-point type that can store coordinates and matrix entries.
+///
+/// Implemented for `f32` and `f64`.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + 'static
+{
+    /// Additive identity.
+    const ZERO: Self;
+    /// Multiplicative identity.
+    const ONE: Self;
+    /// Machine epsilon, the threshold below which lengths count as zero.
+    const EPSILON: Self;
+
+    /// Square root; `NaN` for negative inputs.
+    #[must_use]
+    fn sqrt(self) -> Self;
+
+    /// Sine and cosine of an angle in radians.
+    #[must_use]
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Whether the value is neither infinite nor `NaN`.
+    #[must_use]
+    fn is_finite(self) -> bool;
+}
+
+impl Scalar for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const EPSILON: Self = f32::EPSILON;
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        crate::math::sqrtf(self)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        crate::math::sin_cosf(self)
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+}
+
+impl Scalar for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const EPSILON: Self = f64::EPSILON;
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        crate::math::sqrt(self)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        crate::math::sin_cos(self)
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+}
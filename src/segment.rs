@@ -0,0 +1,101 @@
+//! Line segments tagged with a coordinate frame.
+//!
+//! A [`Segment<F>`] connects two points of frame `F`. Closest-point queries
+//! are clamped to the segment, so points beyond either end resolve to that
+//! endpoint.
+//!
+//! ```rust
+//! use spatial_typestate::{Frame, Point3, Segment};
+//!
+//! struct World;
+//! impl Frame for World {}
+//!
+//! let path = Segment::new(Point3::<World>::new(0.0, 0.0, 0.0), Point3::new(10.0, 0.0, 0.0));
+//!
+//! let foot = path.closest_point(&Point3::new(4.0, 3.0, 0.0));
+//! assert_eq!((foot.x, foot.y, foot.z), (4.0, 0.0, 0.0));
+//! assert_eq!(path.distance_to(&Point3::new(4.0, 3.0, 0.0)), 3.0);
+//! ```
+
+use crate::frame::Frame;
+use crate::math;
+use crate::point::Point3;
+
+/// The straight segment between two points in frame `F`.
+#[derive(Debug, PartialEq)]
+pub struct Segment<F: Frame> {
+    start: Point3<F>,
+    end: Point3<F>,
+}
+
+impl<F: Frame> Segment<F> {
+    /// The segment from `start` to `end`.
+    ///
+    /// The endpoints may coincide; the segment then behaves like a single
+    /// point.
+    #[inline]
+    #[must_use]
+    pub const fn new(start: Point3<F>, end: Point3<F>) -> Self {
+        Self { start, end }
+    }
+
+    /// First endpoint.
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> Point3<F> {
+        self.start
+    }
+
+    /// Second endpoint.
+    #[inline]
+    #[must_use]
+    pub const fn end(&self) -> Point3<F> {
+        self.end
+    }
+
+    /// The point of the segment closest to `p`.
+    ///
+    /// This is the foot of the perpendicular from `p` when that lies between
+    /// the endpoints, and the nearer endpoint otherwise.
+    #[must_use]
+    pub fn closest_point(&self, p: &Point3<F>) -> Point3<F> {
+        let d = [
+            self.end.x - self.start.x,
+            self.end.y - self.start.y,
+            self.end.z - self.start.z,
+        ];
+        let len_sq = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+        if len_sq == 0.0 {
+            return self.start;
+        }
+
+        let along =
+            (p.x - self.start.x) * d[0] + (p.y - self.start.y) * d[1] + (p.z - self.start.z) * d[2];
+        let t = (along / len_sq).clamp(0.0, 1.0);
+
+        Point3::new(
+            self.start.x + t * d[0],
+            self.start.y + t * d[1],
+            self.start.z + t * d[2],
+        )
+    }
+
+    /// Euclidean distance from `p` to the segment.
+    #[inline]
+    #[must_use]
+    pub fn distance_to(&self, p: &Point3<F>) -> f64 {
+        let c = self.closest_point(p);
+        let (dx, dy, dz) = (p.x - c.x, p.y - c.y, p.z - c.z);
+
+        math::sqrt(dx * dx + dy * dy + dz * dz)
+    }
+}
+
+impl<F: Frame> Clone for Segment<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame> Copy for Segment<F> {}
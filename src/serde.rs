@@ -0,0 +1,384 @@
+//! Serialization support (feature `serde`).
+//!
+//! [`Point3`], [`Vector3`], [`UnitQuat`], [`Transform`] and [`Quantity`]
+//! implement `Serialize`/`Deserialize` as plain structs of their numeric
+//! fields; the frame and unit markers are zero-sized and do not appear:
+//!
+//! ```json
+//! { "x": 1.0, "y": 2.0, "z": 3.0 }
+//! ```
+//!
+//! **Frame and unit identity are not checked on deserialization.** A point
+//! saved as `Point3<Body>` loads just as happily as a `Point3<World>`; the
+//! type you deserialize into is the claim. Quaternions are renormalized on
+//! load (and rejected if they cannot be), everything else is taken as is.
+//!
+//! For self-describing logs, [`unit_symbol`] serializes a [`Quantity<U>`]
+//! together with its unit symbol instead:
+//!
+//! ```json
+//! { "value": 42.0, "unit": "m" }
+//! ```
+//!
+//! Use it on a field with `#[serde(with = "spatial_typestate::serde::unit_symbol")]`.
+//! On deserialization the stored symbol must match [`UnitSymbol::SYMBOL`] of
+//! the target unit, so data written in one unit cannot silently load as
+//! another.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde_dep::de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_dep::ser::SerializeStruct;
+use serde_dep::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::frame::Frame;
+use crate::point::Point3;
+use crate::quaternion::UnitQuat;
+use crate::transform::Transform;
+use crate::units::{Quantity, Unit, UnitSymbol};
+use crate::vector::Vector3;
+
+impl<F: Frame> Serialize for Point3<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_fields(serializer, "Point3", &XYZ, &[self.x, self.y, self.z])
+    }
+}
+
+impl<'de, F: Frame> Deserialize<'de> for Point3<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z] = deserialize_fields(deserializer, "Point3", &XYZ)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+impl<F: Frame> Serialize for Vector3<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_fields(serializer, "Vector3", &XYZ, &[self.x, self.y, self.z])
+    }
+}
+
+impl<'de, F: Frame> Deserialize<'de> for Vector3<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z] = deserialize_fields(deserializer, "Vector3", &XYZ)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+impl<F: Frame> Serialize for UnitQuat<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_fields(
+            serializer,
+            "UnitQuat",
+            &XYZW,
+            &[self.x, self.y, self.z, self.w],
+        )
+    }
+}
+
+/// Goes through [`UnitQuat::try_from_components`], so the result is
+/// normalized and non-finite or zero quaternions are rejected.
+impl<'de, F: Frame> Deserialize<'de> for UnitQuat<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z, w] = deserialize_fields(deserializer, "UnitQuat", &XYZW)?;
+        Self::try_from_components(x, y, z, w)
+            .map_err(|e| de::Error::custom(format_args!("invalid quaternion: {e:?}")))
+    }
+}
+
+impl<From: Frame, To: Frame> Serialize for Transform<From, To> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_fields(serializer, "Transform", &["matrix"], &[self.matrix])
+    }
+}
+
+impl<'de, From: Frame, To: Frame> Deserialize<'de> for Transform<From, To> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [matrix] = deserialize_fields(deserializer, "Transform", &["matrix"])?;
+        Ok(Self::from_matrix(matrix))
+    }
+}
+
+impl<U: Unit> Serialize for Quantity<U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_fields(serializer, "Quantity", &["value"], &[self.value])
+    }
+}
+
+impl<'de, U: Unit> Deserialize<'de> for Quantity<U> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [value] = deserialize_fields(deserializer, "Quantity", &["value"])?;
+        Ok(Self::new(value))
+    }
+}
+
+const XYZ: [&str; 3] = ["x", "y", "z"];
+const XYZW: [&str; 4] = ["x", "y", "z", "w"];
+
+/// Serialize `values` as a struct whose fields are named by `fields`.
+fn serialize_fields<S, T, const N: usize>(
+    serializer: S,
+    name: &'static str,
+    fields: &'static [&'static str; N],
+    values: &[T; N],
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut state = serializer.serialize_struct(name, N)?;
+    for (field, value) in fields.iter().zip(values) {
+        state.serialize_field(field, value)?;
+    }
+    state.end()
+}
+
+/// Deserialize a struct written by [`serialize_fields`], from either a map
+/// or a sequence. Unknown map keys are ignored.
+fn deserialize_fields<'de, D, T, const N: usize>(
+    deserializer: D,
+    name: &'static str,
+    fields: &'static [&'static str; N],
+) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    deserializer.deserialize_struct(
+        name,
+        fields,
+        FieldsVisitor {
+            name,
+            fields,
+            _value: PhantomData,
+        },
+    )
+}
+
+struct FieldsVisitor<T, const N: usize> {
+    name: &'static str,
+    fields: &'static [&'static str; N],
+    _value: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de> + Default, const N: usize> Visitor<'de> for FieldsVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct {} with fields {:?}", self.name, self.fields)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[T; N], A::Error> {
+        let mut out: [T; N] = core::array::from_fn(|_| T::default());
+        for (i, slot) in out.iter_mut().enumerate() {
+            *slot = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok(out)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<[T; N], A::Error> {
+        let mut out: [T; N] = core::array::from_fn(|_| T::default());
+        let mut seen = [false; N];
+
+        while let Some(index) = map.next_key_seed(FieldIndex(self.fields))? {
+            match index {
+                Some(i) if seen[i] => return Err(de::Error::duplicate_field(self.fields[i])),
+                Some(i) => {
+                    out[i] = map.next_value()?;
+                    seen[i] = true;
+                }
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        if let Some(i) = seen.iter().position(|s| !s) {
+            return Err(de::Error::missing_field(self.fields[i]));
+        }
+        Ok(out)
+    }
+}
+
+/// Maps a struct key to its position in the field list, or `None` for an
+/// unknown key.
+struct FieldIndex<const N: usize>(&'static [&'static str; N]);
+
+impl<'de, const N: usize> DeserializeSeed<'de> for FieldIndex<N> {
+    type Value = Option<usize>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Option<usize>, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<const N: usize> Visitor<'_> for FieldIndex<N> {
+    type Value = Option<usize>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "one of {:?}", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Option<usize>, E> {
+        Ok(self.0.iter().position(|field| *field == v))
+    }
+}
+
+/// `serialize`/`deserialize` functions writing a quantity as
+/// `{ "value": .., "unit": .. }` and checking the unit on the way back in.
+pub mod unit_symbol {
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use serde_dep::de::{
+        self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor,
+    };
+    use serde_dep::ser::SerializeStruct;
+    use serde_dep::{Deserialize, Deserializer, Serializer};
+
+    use super::{Quantity, UnitSymbol};
+
+    const FIELDS: &[&str] = &["value", "unit"];
+
+    /// Serialize `quantity` as a `Quantity { value, unit }` struct.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error reported by `serializer`.
+    pub fn serialize<U, S>(quantity: &Quantity<U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        U: UnitSymbol,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Quantity", FIELDS.len())?;
+        state.serialize_field("value", &quantity.value)?;
+        state.serialize_field("unit", U::SYMBOL)?;
+        state.end()
+    }
+
+    /// Deserialize a quantity written by [`serialize`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if either field is missing or duplicated, and with an
+    /// "invalid value" error if the stored unit symbol is not `U::SYMBOL`.
+    pub fn deserialize<'de, U, D>(deserializer: D) -> Result<Quantity<U>, D::Error>
+    where
+        U: UnitSymbol,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Quantity", FIELDS, QuantityVisitor(PhantomData))
+    }
+
+    enum Field {
+        Value,
+        Unit,
+        Other,
+    }
+
+    impl<'de> Deserialize<'de> for Field {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct FieldVisitor;
+
+            impl Visitor<'_> for FieldVisitor {
+                type Value = Field;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("`value` or `unit`")
+                }
+
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<Field, E> {
+                    Ok(match v {
+                        "value" => Field::Value,
+                        "unit" => Field::Unit,
+                        _ => Field::Other,
+                    })
+                }
+            }
+
+            deserializer.deserialize_identifier(FieldVisitor)
+        }
+    }
+
+    /// Reads a unit symbol and checks it against `U::SYMBOL`.
+    struct Symbol<U>(PhantomData<U>);
+
+    impl<'de, U: UnitSymbol> DeserializeSeed<'de> for Symbol<U> {
+        type Value = ();
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<U: UnitSymbol> Visitor<'_> for Symbol<U> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "the unit symbol {:?}", U::SYMBOL)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<(), E> {
+            if v == U::SYMBOL {
+                Ok(())
+            } else {
+                Err(E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+    }
+
+    struct QuantityVisitor<U>(PhantomData<U>);
+
+    impl<'de, U: UnitSymbol> Visitor<'de> for QuantityVisitor<U> {
+        type Value = Quantity<U>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a quantity in {:?}", U::SYMBOL)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Quantity<U>, A::Error> {
+            let value: f64 = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            seq.next_element_seed(Symbol::<U>(PhantomData))?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+            Ok(Quantity::new(value))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Quantity<U>, A::Error> {
+            let mut value = None;
+            let mut unit_checked = false;
+
+            while let Some(field) = map.next_key::<Field>()? {
+                match field {
+                    Field::Value => {
+                        if value.is_some() {
+                            return Err(de::Error::duplicate_field("value"));
+                        }
+                        value = Some(map.next_value::<f64>()?);
+                    }
+                    Field::Unit => {
+                        if unit_checked {
+                            return Err(de::Error::duplicate_field("unit"));
+                        }
+                        map.next_value_seed(Symbol::<U>(PhantomData))?;
+                        unit_checked = true;
+                    }
+                    Field::Other => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+            }
+
+            let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+            if !unit_checked {
+                return Err(de::Error::missing_field("unit"));
+            }
+
+            Ok(Quantity::new(value))
+        }
+    }
+}
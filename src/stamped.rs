@@ -0,0 +1,34 @@
+//! Timestamped values.
+//!
+//! [`Stamped<T>`] pairs any value (a transform, a point, a measurement) with
+//! the time it refers to. The stamp is a [`Quantity<Seconds>`] so it cannot be
+//! confused with a length or an angle.
+//!
+//! ```rust
+//! use spatial_typestate::{Frame, Point3, Quantity, Stamped};
+//!
+//! struct World;
+//! impl Frame for World {}
+//!
+//! let fix = Stamped::new(Quantity::new(12.5), Point3::<World>::new(1.0, 2.0, 3.0));
+//! assert_eq!(fix.stamp.get(), 12.5);
+//! ```
+
+use crate::units::{Quantity, Seconds};
+
+/// A value of type `T` tagged with the time it refers to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stamped<T> {
+    /// Time at which `value` was valid.
+    pub stamp: Quantity<Seconds>,
+    /// The stamped value.
+    pub value: T,
+}
+
+impl<T> Stamped<T> {
+    /// Pair `value` with the time `stamp`.
+    #[inline]
+    pub const fn new(stamp: Quantity<Seconds>, value: T) -> Self {
+        Self { stamp, value }
+    }
+}
@@ -18,10 +18,24 @@
 //! let p_world = t_body_world.apply_point(p_body);
 //! ```
 
+use core::fmt::{self, Write as _};
 use core::marker::PhantomData;
+use core::ops::{Mul, Neg};
 
-use crate::frame::Frame;
+use crate::aabb::Aabb;
+use crate::errors::SpatialError;
+use crate::frame::{assert_zero_sized, Frame, FrameName};
+use crate::kinematics::{AngularVelocity3, Velocity3};
+use crate::math;
+use crate::plane::Plane;
 use crate::point::Point3;
+use crate::quaternion::{quat_exp3, quat_from_rotation3, quat_log3, rotation3_from_quat, UnitQuat};
+use crate::ray::Ray;
+use crate::scalar::Scalar;
+use crate::segment::Segment;
+use crate::stamped::Stamped;
+use crate::units::{Quantity, Radians};
+use crate::vector::Vector3;
 
 /// A rigid transform from frame `From` to frame `To`.
 ///
@@ -32,28 +46,39 @@ use crate::point::Point3;
 /// The exact storage may change in future versions as we integrate with a
 /// math backend, but the **type-level frame parameters** are intended to
 /// remain stable.
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct Transform<From: Frame, To: Frame> {
+///
+/// Entries are `f64` unless another [`Scalar`] is given as `T`; only the
+/// construction, composition and application methods are available for
+/// other scalars.
+///
+/// The struct is `#[repr(transparent)]` over its matrix (the frame markers
+/// are zero-sized), which is what makes [`Transform::from_matrix_ref`] sound.
+#[derive(PartialEq)]
+#[repr(transparent)]
+pub struct Transform<From: Frame, To: Frame, T: Scalar = f64> {
     /// Column-major 4×4 transform matrix.
     ///
     /// This is intentionally simple and explicit. In future versions, this may
     /// be replaced by or wrap a math-backend-specific type while preserving
     /// the public API guarantees.
-    pub matrix: [[f64; 4]; 4],
+    pub matrix: [[T; 4]; 4],
     _from: PhantomData<From>,
     _to: PhantomData<To>,
 }
 
-impl<From: Frame, To: Frame> Transform<From, To> {
+impl<From: Frame, To: Frame, T: Scalar> Transform<From, To, T> {
     /// Construct an identity transform (no rotation, no translation).
     #[inline]
+    #[must_use]
     pub const fn identity() -> Self {
+        assert_zero_sized::<From>();
+        assert_zero_sized::<To>();
         Self {
             matrix: [
-                [1.0, 0.0, 0.0, 0.0],
-                [0.0, 1.0, 0.0, 0.0],
-                [0.0, 0.0, 1.0, 0.0],
-                [0.0, 0.0, 0.0, 1.0],
+                [T::ONE, T::ZERO, T::ZERO, T::ZERO],
+                [T::ZERO, T::ONE, T::ZERO, T::ZERO],
+                [T::ZERO, T::ZERO, T::ONE, T::ZERO],
+                [T::ZERO, T::ZERO, T::ZERO, T::ONE],
             ],
             _from: PhantomData,
             _to: PhantomData,
@@ -65,7 +90,10 @@ impl<From: Frame, To: Frame> Transform<From, To> {
     /// The caller is responsible for ensuring this represents a valid rigid
     /// transform if that is required by the domain.
     #[inline]
-    pub const fn from_matrix(matrix: [[f64; 4]; 4]) -> Self {
+    #[must_use]
+    pub const fn from_matrix(matrix: [[T; 4]; 4]) -> Self {
+        assert_zero_sized::<From>();
+        assert_zero_sized::<To>();
         Self {
             matrix,
             _from: PhantomData,
@@ -75,12 +103,9 @@ impl<From: Frame, To: Frame> Transform<From, To> {
 
     /// Construct a pure translation transform (no rotation).
     #[inline]
-    pub fn from_translation(tx: f64, ty: f64, tz: f64) -> Self {
-        let mut m = [[0.0_f64; 4]; 4];
-        m[0][0] = 1.0;
-        m[1][1] = 1.0;
-        m[2][2] = 1.0;
-        m[3][3] = 1.0;
+    #[must_use]
+    pub fn from_translation(tx: T, ty: T, tz: T) -> Self {
+        let mut m = Self::identity().matrix;
         m[0][3] = tx;
         m[1][3] = ty;
         m[2][3] = tz;
@@ -93,7 +118,9 @@ impl<From: Frame, To: Frame> Transform<From, To> {
     ///
     /// This uses homogeneous coordinates (`w = 1`) under the hood.
     #[inline]
-    pub fn apply_point(&self, p: Point3<From>) -> Point3<To> {
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn apply_point(&self, p: Point3<From, T>) -> Point3<To, T> {
         let m = &self.matrix;
         let x = p.x;
         let y = p.y;
@@ -103,6 +130,1515 @@ impl<From: Frame, To: Frame> Transform<From, To> {
         let yp = m[1][0] * x + m[1][1] * y + m[1][2] * z + m[1][3];
         let zp = m[2][0] * x + m[2][1] * y + m[2][2] * z + m[2][3];
 
-        Point3::<To>::new(xp, yp, zp)
+        Point3::new(xp, yp, zp)
+    }
+
+    /// Apply this transform to every point of `src`, writing the results to
+    /// the matching slots of `dst`.
+    ///
+    /// Produces exactly the same coordinates as calling
+    /// [`Transform::apply_point`] on each point, but reads the matrix once
+    /// up front so the loop body is plain arithmetic the compiler can
+    /// vectorize.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::LengthMismatch`] if `src` and `dst` differ in
+    /// length; `dst` is left untouched in that case.
+    pub fn apply_points(
+        &self,
+        src: &[Point3<From, T>],
+        dst: &mut [Point3<To, T>],
+    ) -> Result<(), SpatialError> {
+        if src.len() != dst.len() {
+            return Err(SpatialError::LengthMismatch);
+        }
+
+        let [r0, r1, r2, _] = self.matrix;
+        for (p, out) in src.iter().zip(dst.iter_mut()) {
+            out.x = r0[0] * p.x + r0[1] * p.y + r0[2] * p.z + r0[3];
+            out.y = r1[0] * p.x + r1[1] * p.y + r1[2] * p.z + r1[3];
+            out.z = r2[0] * p.x + r2[1] * p.y + r2[2] * p.z + r2[3];
+        }
+
+        Ok(())
+    }
+
+    /// Apply this transform to a `From`-frame vector, producing a `To`-frame
+    /// vector.
+    ///
+    /// Vectors are directions and differences (velocities, axes,
+    /// displacements), not positions, so only the upper-left 3×3
+    /// block is used and the translation column is ignored (`w = 0` in
+    /// homogeneous terms). Use [`Transform::apply_point`] for positions.
+    #[inline]
+    #[must_use]
+    pub fn apply_vector(&self, v: Vector3<From, T>) -> Vector3<To, T> {
+        let m = &self.matrix;
+        Vector3::new(
+            m[0][0] * v.x + m[0][1] * v.y + m[0][2] * v.z,
+            m[1][0] * v.x + m[1][1] * v.y + m[1][2] * v.z,
+            m[2][0] * v.x + m[2][1] * v.y + m[2][2] * v.z,
+        )
+    }
+
+    /// Chain this transform with one that continues from `To` to `Next`.
+    ///
+    /// The result first applies `self` and then `next`, i.e. its matrix is
+    /// `next.matrix × self.matrix`. The shared middle frame must match, so
+    /// chaining transforms that do not line up is a compile error.
+    #[inline]
+    #[must_use]
+    pub fn compose<Next: Frame>(&self, next: &Transform<To, Next, T>) -> Transform<From, Next, T> {
+        let a = &next.matrix;
+        let b = &self.matrix;
+
+        let mut m = [[T::ZERO; 4]; 4];
+        for (row, m_row) in m.iter_mut().enumerate() {
+            for (col, v) in m_row.iter_mut().enumerate() {
+                *v = a[row][0] * b[0][col]
+                    + a[row][1] * b[1][col]
+                    + a[row][2] * b[2][col]
+                    + a[row][3] * b[3][col];
+            }
+        }
+
+        Transform::from_matrix(m)
+    }
+
+    /// Construct from a rotation given as a quaternion followed by a
+    /// translation `(tx, ty, tz)`: `p ↦ q p + t`.
+    ///
+    /// The quaternion is tagged with `From` because it acts on `From`
+    /// coordinates: the rotation block is exactly `q`, so for a `From` point
+    /// the result equals [`UnitQuat::rotate_point`] followed by the
+    /// translation, with the output read in `To`. Equivalently, `q` is the
+    /// orientation of `From` in `To` and `(tx, ty, tz)` the `From` origin
+    /// in `To`, as with [`Transform::from_pose_components`].
+    #[inline]
+    #[must_use]
+    pub fn from_quaternion_translation(q: UnitQuat<From, T>, tx: T, ty: T, tz: T) -> Self {
+        Self::from_rotation3_translation(rotation3_from_quat(q.to_xyzw()), [tx, ty, tz])
+    }
+
+    /// Assemble a transform from a 3×3 rotation block and a translation.
+    #[inline]
+    fn from_rotation3_translation(r: [[T; 3]; 3], t: [T; 3]) -> Self {
+        Self::from_matrix([
+            [r[0][0], r[0][1], r[0][2], t[0]],
+            [r[1][0], r[1][1], r[1][2], t[1]],
+            [r[2][0], r[2][1], r[2][2], t[2]],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ])
+    }
+}
+
+impl<From: Frame, To: Frame> Transform<From, To> {
+    /// Borrow a raw 4×4 matrix as a frame-tagged transform, without copying.
+    ///
+    /// Intended for FFI or shared buffers where another component owns the
+    /// matrix storage. As with [`Transform::from_matrix`], the caller is
+    /// responsible for the matrix actually mapping `From` into `To`.
+    ///
+    /// ```rust
+    /// use spatial_typestate::{Frame, Point3, Transform};
+    ///
+    /// struct World;
+    /// struct Body;
+    /// impl Frame for World {}
+    /// impl Frame for Body {}
+    ///
+    /// let buffer = [
+    ///     [1.0, 0.0, 0.0, 5.0],
+    ///     [0.0, 1.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ];
+    ///
+    /// let t: &Transform<Body, World> = Transform::from_matrix_ref(&buffer);
+    /// assert_eq!(t.apply_point(Point3::new(0.0, 0.0, 0.0)).x, 5.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_matrix_ref(matrix: &[[f64; 4]; 4]) -> &Self {
+        assert_zero_sized::<From>();
+        assert_zero_sized::<To>();
+        crate::layout::transform_from_matrix_ref(matrix)
+    }
+
+    /// Construct from a raw 4×4 matrix, checking that it is a proper rigid
+    /// transform.
+    ///
+    /// Unlike [`Transform::from_matrix`], this rejects anything but a
+    /// rotation plus translation: the bottom row must be `[0, 0, 0, 1]` and
+    /// the rotation block orthonormal with determinant `+1` (no reflection),
+    /// all within `1e-6`. The matrix is stored unchanged; use
+    /// [`Transform::sanitize`] to repair small drift instead of rejecting it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any entry is `NaN` or infinite,
+    /// and [`SpatialError::NotRigid`] if the checks above fail.
+    pub fn try_from_matrix(matrix: [[f64; 4]; 4]) -> Result<Self, SpatialError> {
+        const TOL: f64 = 1e-6;
+
+        if matrix.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(SpatialError::NonFinite);
+        }
+
+        let t = Self::from_matrix(matrix);
+        if !t.is_rigid(TOL) {
+            return Err(SpatialError::NotRigid);
+        }
+
+        Ok(t)
+    }
+
+    /// Validate and clean up a raw matrix, e.g. one loaded from disk.
+    ///
+    /// The matrix must be finite. Its rotation block is re-orthonormalized
+    /// with Gram–Schmidt (the x column is kept as the reference direction,
+    /// the y column is made orthogonal to it and z is rebuilt as `x × y`),
+    /// and the bottom row is forced to `[0, 0, 0, 1]`. The translation column
+    /// is kept as is.
+    ///
+    /// `max_repair` bounds how much any single matrix entry may change
+    /// during this clean-up. Small numerical drift is repaired silently;
+    /// anything larger means the input was not a rigid transform to begin
+    /// with.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any entry is `NaN` or infinite,
+    /// and [`SpatialError::NotRigid`] if the rotation block is degenerate or
+    /// if repairing it would move an entry by more than `max_repair`.
+    pub fn sanitize(raw: [[f64; 4]; 4], max_repair: f64) -> Result<Self, SpatialError> {
+        if raw.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(SpatialError::NonFinite);
+        }
+
+        let x_axis = normalize3([raw[0][0], raw[1][0], raw[2][0]]).ok_or(SpatialError::NotRigid)?;
+        let y_raw = [raw[0][1], raw[1][1], raw[2][1]];
+        let d = dot3(x_axis, y_raw);
+        let y_axis = normalize3([
+            y_raw[0] - d * x_axis[0],
+            y_raw[1] - d * x_axis[1],
+            y_raw[2] - d * x_axis[2],
+        ])
+        .ok_or(SpatialError::NotRigid)?;
+        let z_axis = cross3(x_axis, y_axis);
+
+        let mut m = raw;
+        for row in 0..3 {
+            m[row][0] = x_axis[row];
+            m[row][1] = y_axis[row];
+            m[row][2] = z_axis[row];
+        }
+        m[3] = [0.0, 0.0, 0.0, 1.0];
+
+        let repair = m
+            .iter()
+            .flatten()
+            .zip(raw.iter().flatten())
+            .fold(0.0_f64, |acc, (a, b)| acc.max((a - b).abs()));
+        if repair > max_repair {
+            return Err(SpatialError::NotRigid);
+        }
+
+        Ok(Self::from_matrix(m))
     }
+
+    /// Construct the rigid transform that aligns a source origin and forward
+    /// direction (in `From`) with a target origin and forward direction (in
+    /// `To`).
+    ///
+    /// The result maps `src_origin` exactly onto `dst_origin` and rotates
+    /// `src_forward` onto the direction of `dst_forward`. Magnitudes of the
+    /// forward vectors are ignored.
+    ///
+    /// Two constraints leave the roll about the forward axis undetermined.
+    /// This constructor resolves it by using the **shortest-arc** rotation
+    /// between the two directions: the rotation axis is
+    /// `src_forward × dst_forward`, so nothing is spun about the forward
+    /// axis itself. When the directions are exactly opposite the shortest
+    /// arc is not unique; a half-turn about an axis perpendicular to
+    /// `src_forward` is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any input is not finite, and
+    /// [`SpatialError::DegenerateInput`] if either forward vector has zero
+    /// length.
+    pub fn align(
+        src_origin: Point3<From>,
+        src_forward: Vector3<From>,
+        dst_origin: Point3<To>,
+        dst_forward: Vector3<To>,
+    ) -> Result<Self, SpatialError> {
+        let inputs = [
+            src_origin.x,
+            src_origin.y,
+            src_origin.z,
+            src_forward.x,
+            src_forward.y,
+            src_forward.z,
+            dst_origin.x,
+            dst_origin.y,
+            dst_origin.z,
+            dst_forward.x,
+            dst_forward.y,
+            dst_forward.z,
+        ];
+        if inputs.iter().any(|v| !v.is_finite()) {
+            return Err(SpatialError::NonFinite);
+        }
+
+        let from_dir = normalize3([src_forward.x, src_forward.y, src_forward.z])
+            .ok_or(SpatialError::DegenerateInput)?;
+        let to_dir = normalize3([dst_forward.x, dst_forward.y, dst_forward.z])
+            .ok_or(SpatialError::DegenerateInput)?;
+        let rot = shortest_arc3(from_dir, to_dir)?;
+
+        let origin = [src_origin.x, src_origin.y, src_origin.z];
+        let target = [dst_origin.x, dst_origin.y, dst_origin.z];
+
+        let mut m = [[0.0_f64; 4]; 4];
+        for row in 0..3 {
+            m[row][..3].copy_from_slice(&rot[row]);
+            m[row][3] = target[row] - dot3(rot[row], origin);
+        }
+        m[3][3] = 1.0;
+
+        Ok(Self::from_matrix(m))
+    }
+
+    /// Construct from ROS `geometry_msgs/Pose`-style components: position
+    /// `(px, py, pz)` followed by orientation quaternion `(qx, qy, qz, qw)`.
+    ///
+    /// The quaternion is normalized before use. The pose is interpreted as
+    /// the pose of `From` in `To`, matching [`Transform::to_pose_components`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any component is not finite,
+    /// and [`SpatialError::ZeroNormQuaternion`] if the quaternion has zero
+    /// norm.
+    pub fn from_pose_components(
+        px: f64,
+        py: f64,
+        pz: f64,
+        qx: f64,
+        qy: f64,
+        qz: f64,
+        qw: f64,
+    ) -> Result<Self, SpatialError> {
+        if [px, py, pz, qx, qy, qz, qw].iter().any(|v| !v.is_finite()) {
+            return Err(SpatialError::NonFinite);
+        }
+
+        let norm = math::sqrt(qx * qx + qy * qy + qz * qz + qw * qw);
+        if norm == 0.0 {
+            return Err(SpatialError::ZeroNormQuaternion);
+        }
+
+        let r = rotation3_from_quat([qx / norm, qy / norm, qz / norm, qw / norm]);
+        Ok(Self::from_rotation3_translation(r, [px, py, pz]))
+    }
+
+    /// Split a rigid transform into its rotation quaternion and translation,
+    /// the inverse of [`Transform::from_quaternion_translation`].
+    ///
+    /// The quaternion is tagged with `From` for the same reason as in the
+    /// constructor (it acts on `From` coordinates), so the parts feed
+    /// straight back into it. It is renormalized, and since `q` and `-q`
+    /// encode the same rotation its overall sign is arbitrary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any entry is not finite, and
+    /// [`SpatialError::NotRigid`] unless the bottom row is `[0, 0, 0, 1]` and
+    /// the rotation block is orthonormal with determinant `+1`, all within
+    /// `1e-6`.
+    pub fn to_quaternion_translation(&self) -> Result<(UnitQuat<From>, [f64; 3]), SpatialError> {
+        const TOL: f64 = 1e-6;
+
+        let m = &self.matrix;
+        if m.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(SpatialError::NonFinite);
+        }
+        if !self.is_rigid(TOL) {
+            return Err(SpatialError::NotRigid);
+        }
+
+        let raw = quat_from_rotation3(self.rotation3());
+        let rotation = UnitQuat::try_from_components(raw[0], raw[1], raw[2], raw[3])?;
+
+        Ok((rotation, [m[0][3], m[1][3], m[2][3]]))
+    }
+
+    /// Export as ROS `geometry_msgs/Pose`-style components: position
+    /// `(px, py, pz)` followed by orientation quaternion `(qx, qy, qz, qw)`.
+    ///
+    /// The transform is read as the pose of `From` in `To`: the position is
+    /// the translation column and the orientation is the rotation block.
+    /// The rotation block is assumed orthonormal. Since `q` and `-q` encode
+    /// the same rotation, the sign of the returned quaternion is arbitrary.
+    #[must_use]
+    pub fn to_pose_components(&self) -> (f64, f64, f64, f64, f64, f64, f64) {
+        let m = &self.matrix;
+        let q = quat_from_rotation3(self.rotation3());
+
+        (m[0][3], m[1][3], m[2][3], q[0], q[1], q[2], q[3])
+    }
+
+    /// Assemble a rigid transform from the `From` frame's basis vectors and
+    /// origin, all expressed in `To`.
+    ///
+    /// The axes become the columns of the rotation block and `origin` the
+    /// translation, so `x_axis` is where `From`'s unit x vector lands in `To`.
+    /// This is the inverse of [`Transform::x_axis`], [`Transform::y_axis`],
+    /// [`Transform::z_axis`] and [`Transform::origin`].
+    ///
+    /// The axes must be unit length, mutually orthogonal and right-handed to
+    /// within `1e-6`. Noisy measured axes should be cleaned up first, e.g.
+    /// with [`Transform::sanitize`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any input is not finite, and
+    /// [`SpatialError::NotRigid`] if the axes are not orthonormal or form a
+    /// left-handed basis.
+    pub fn from_basis(
+        x_axis: Vector3<To>,
+        y_axis: Vector3<To>,
+        z_axis: Vector3<To>,
+        origin: Point3<To>,
+    ) -> Result<Self, SpatialError> {
+        const TOL: f64 = 1e-6;
+
+        let axes = [
+            [x_axis.x, x_axis.y, x_axis.z],
+            [y_axis.x, y_axis.y, y_axis.z],
+            [z_axis.x, z_axis.y, z_axis.z],
+        ];
+        let translation = [origin.x, origin.y, origin.z];
+        if axes
+            .iter()
+            .flatten()
+            .chain(&translation)
+            .any(|v| !v.is_finite())
+        {
+            return Err(SpatialError::NonFinite);
+        }
+
+        for (i, a) in axes.iter().enumerate() {
+            for (j, b) in axes.iter().enumerate().skip(i) {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                if (dot3(*a, *b) - expected).abs() > TOL {
+                    return Err(SpatialError::NotRigid);
+                }
+            }
+        }
+        // Orthonormal, so the determinant is ±1; reject reflections.
+        if dot3(cross3(axes[0], axes[1]), axes[2]) < 0.0 {
+            return Err(SpatialError::NotRigid);
+        }
+
+        let mut rotation = [[0.0_f64; 3]; 3];
+        for (col, axis) in axes.iter().enumerate() {
+            for (row, v) in axis.iter().enumerate() {
+                rotation[row][col] = *v;
+            }
+        }
+
+        Ok(Self::from_rotation3_translation(rotation, translation))
+    }
+
+    /// Place a camera frame `From` at `eye`, looking toward `target`.
+    ///
+    /// The camera axes follow the computer-vision convention: `+z` points
+    /// from `eye` toward `target`, `+x` to the right of the image and `+y`
+    /// down, with "up" in the image as close to `up` as possible. The result
+    /// is the camera pose (camera → `To`); its [`Transform::inverse`] is the
+    /// view transform that brings `To` points into camera coordinates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any input is not finite, and
+    /// [`SpatialError::DegenerateInput`] if `eye` and `target` coincide or
+    /// `up` is parallel to the viewing direction (the roll about the view
+    /// axis is then undetermined).
+    pub fn look_at(
+        eye: Point3<To>,
+        target: Point3<To>,
+        up: Vector3<To>,
+    ) -> Result<Self, SpatialError> {
+        let inputs = [
+            eye.x, eye.y, eye.z, target.x, target.y, target.z, up.x, up.y, up.z,
+        ];
+        if inputs.iter().any(|v| !v.is_finite()) {
+            return Err(SpatialError::NonFinite);
+        }
+
+        let forward = normalize3([target.x - eye.x, target.y - eye.y, target.z - eye.z])
+            .ok_or(SpatialError::DegenerateInput)?;
+        let up = normalize3([up.x, up.y, up.z]).ok_or(SpatialError::DegenerateInput)?;
+        let right = cross3(forward, up);
+        if dot3(right, right) < 1e-12 {
+            return Err(SpatialError::DegenerateInput);
+        }
+        let right = normalize3(right).ok_or(SpatialError::DegenerateInput)?;
+        let down = cross3(forward, right);
+
+        Self::from_basis(
+            Vector3::new(right[0], right[1], right[2]),
+            Vector3::new(down[0], down[1], down[2]),
+            Vector3::new(forward[0], forward[1], forward[2]),
+            eye,
+        )
+    }
+
+    /// Pure rotation by `angle` about the x-axis (right-handed: positive
+    /// angles turn +y towards +z).
+    #[inline]
+    #[must_use]
+    pub fn rotation_x(angle: Quantity<Radians>) -> Self {
+        let (s, c) = math::sin_cos(angle.get());
+        Self::from_rotation3_translation(
+            [[1.0, 0.0, 0.0], [0.0, c, -s], [0.0, s, c]],
+            [0.0, 0.0, 0.0],
+        )
+    }
+
+    /// Pure rotation by `angle` about the y-axis (right-handed: positive
+    /// angles turn +z towards +x).
+    #[inline]
+    #[must_use]
+    pub fn rotation_y(angle: Quantity<Radians>) -> Self {
+        let (s, c) = math::sin_cos(angle.get());
+        Self::from_rotation3_translation(
+            [[c, 0.0, s], [0.0, 1.0, 0.0], [-s, 0.0, c]],
+            [0.0, 0.0, 0.0],
+        )
+    }
+
+    /// Pure rotation by `angle` about the z-axis (right-handed: positive
+    /// angles turn +x towards +y).
+    #[inline]
+    #[must_use]
+    pub fn rotation_z(angle: Quantity<Radians>) -> Self {
+        let (s, c) = math::sin_cos(angle.get());
+        Self::from_rotation3_translation(
+            [[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]],
+            [0.0, 0.0, 0.0],
+        )
+    }
+
+    /// Perspective projection from a camera frame `From` into clip space
+    /// `To`.
+    ///
+    /// Camera coordinates use the same axes as [`Transform::look_at`]: `+z`
+    /// forward, `+x` right, `+y` down. Clip space follows the Vulkan / Direct3D
+    /// convention: after the perspective divide (see
+    /// [`Transform::apply_point_homogeneous`]) visible points land in
+    /// `x, y ∈ [-1, 1]` (with `+y` still down) and depth `z ∈ [0, 1]`, where
+    /// the `near` plane maps to `0` and the `far` plane to `1`.
+    ///
+    /// `fovy` is the full vertical field of view and `aspect` the
+    /// width / height ratio. The result is not rigid (its bottom row is
+    /// `[0, 0, 1, 0]`), so use [`Transform::try_inverse`] rather than
+    /// [`Transform::inverse`] to undo it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any input is not finite, and
+    /// [`SpatialError::DegenerateInput`] unless `0 < fovy < π`, `aspect > 0`
+    /// and `0 < near < far`.
+    pub fn perspective(
+        fovy: Quantity<Radians>,
+        aspect: f64,
+        near: f64,
+        far: f64,
+    ) -> Result<Self, SpatialError> {
+        let fovy = fovy.get();
+        if ![fovy, aspect, near, far].iter().all(|v| v.is_finite()) {
+            return Err(SpatialError::NonFinite);
+        }
+        if !(fovy > 0.0 && fovy < core::f64::consts::PI && aspect > 0.0 && near > 0.0 && far > near)
+        {
+            return Err(SpatialError::DegenerateInput);
+        }
+
+        let focal = 1.0 / math::tan(fovy / 2.0);
+        let depth = far / (far - near);
+
+        Ok(Self::from_matrix([
+            [focal / aspect, 0.0, 0.0, 0.0],
+            [0.0, focal, 0.0, 0.0],
+            [0.0, 0.0, depth, -near * depth],
+            [0.0, 0.0, 1.0, 0.0],
+        ]))
+    }
+
+    /// Orthographic projection from a camera frame `From` into clip space
+    /// `To`.
+    ///
+    /// Uses the same camera axes and clip-space convention as
+    /// [`Transform::perspective`]: the box `x ∈ [left, right]`,
+    /// `y ∈ [top, bottom]`, `z ∈ [near, far]` maps onto `x, y ∈ [-1, 1]`
+    /// and depth `z ∈ [0, 1]`, with `left → -1`, `top → -1` (`+y` is down)
+    /// and `near → 0`. The result is affine, so no perspective divide is
+    /// needed, but it is generally not rigid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any input is not finite, and
+    /// [`SpatialError::DegenerateInput`] if the box is empty along any axis
+    /// (`left == right`, `top == bottom` or `near == far`).
+    pub fn orthographic(
+        left: f64,
+        right: f64,
+        top: f64,
+        bottom: f64,
+        near: f64,
+        far: f64,
+    ) -> Result<Self, SpatialError> {
+        if ![left, right, top, bottom, near, far]
+            .iter()
+            .all(|v| v.is_finite())
+        {
+            return Err(SpatialError::NonFinite);
+        }
+        let width = right - left;
+        let height = bottom - top;
+        let depth = far - near;
+        if width == 0.0 || height == 0.0 || depth == 0.0 {
+            return Err(SpatialError::DegenerateInput);
+        }
+
+        Ok(Self::from_matrix([
+            [2.0 / width, 0.0, 0.0, -(right + left) / width],
+            [0.0, 2.0 / height, 0.0, -(bottom + top) / height],
+            [0.0, 0.0, 1.0 / depth, -near / depth],
+            [0.0, 0.0, 0.0, 1.0],
+        ]))
+    }
+
+    /// [`Transform::apply_point`] writing into caller-provided storage.
+    ///
+    /// Produces exactly the same coordinates as the by-value form; it exists
+    /// so that loops over large point buffers can transform in place into a
+    /// preallocated output without moving points around.
+    #[inline]
+    pub fn apply_point_ref(&self, p: &Point3<From>, out: &mut Point3<To>) {
+        let m = &self.matrix;
+        out.x = m[0][0] * p.x + m[0][1] * p.y + m[0][2] * p.z + m[0][3];
+        out.y = m[1][0] * p.x + m[1][1] * p.y + m[1][2] * p.z + m[1][3];
+        out.z = m[2][0] * p.x + m[2][1] * p.y + m[2][2] * p.z + m[2][3];
+    }
+
+    /// Apply a possibly projective transform to a point, including the
+    /// perspective divide.
+    ///
+    /// The point is lifted to `[x, y, z, 1]`, multiplied by the full 4×4
+    /// matrix (bottom row included) and divided by the resulting `w`. For
+    /// affine transforms `w` is `1` and this agrees with
+    /// [`Transform::apply_point`]; for [`Transform::perspective`] it yields
+    /// normalized device coordinates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::DegenerateInput`] if `w` is zero (the point
+    /// projects to infinity, e.g. it lies in a perspective camera's `z = 0`
+    /// plane), and [`SpatialError::NonFinite`] if the result is not finite.
+    pub fn apply_point_homogeneous(&self, p: Point3<From>) -> Result<Point3<To>, SpatialError> {
+        let m = &self.matrix;
+        let lifted = [p.x, p.y, p.z, 1.0];
+        let clip = [0, 1, 2, 3].map(|row| {
+            m[row][0] * lifted[0]
+                + m[row][1] * lifted[1]
+                + m[row][2] * lifted[2]
+                + m[row][3] * lifted[3]
+        });
+
+        let w = clip[3];
+        if w == 0.0 {
+            return Err(SpatialError::DegenerateInput);
+        }
+        let out = [clip[0] / w, clip[1] / w, clip[2] / w];
+        if !out.iter().all(|v| v.is_finite()) {
+            return Err(SpatialError::NonFinite);
+        }
+
+        Ok(Point3::new(out[0], out[1], out[2]))
+    }
+
+    /// Express a `From`-frame segment in the `To` frame by mapping both
+    /// endpoints.
+    #[inline]
+    #[must_use]
+    pub fn apply_segment(&self, segment: Segment<From>) -> Segment<To> {
+        Segment::new(
+            self.apply_point(segment.start()),
+            self.apply_point(segment.end()),
+        )
+    }
+
+    /// Express a `From`-frame ray in the `To` frame.
+    ///
+    /// The origin is mapped as a point and the direction as a vector, then
+    /// renormalized. For a rigid transform that is a no-op, so `ray.at(t)`
+    /// and `transform.apply_ray(ray).at(t)` are the same point; under scaling
+    /// the two parameterizations differ by the scale factor.
+    ///
+    /// The linear block must not collapse the direction; otherwise the
+    /// result is not finite, which debug builds catch with an assertion. Use
+    /// [`Transform::try_apply_ray`] when the transform may be singular.
+    #[must_use]
+    pub fn apply_ray(&self, ray: Ray<From>) -> Ray<To> {
+        let d = self.apply_vector(ray.direction());
+        let length = d.norm();
+        let direction = Vector3::new(d.x / length, d.y / length, d.z / length);
+        debug_assert!(
+            direction.x.is_finite() && direction.y.is_finite() && direction.z.is_finite(),
+            "apply_ray: transform collapsed the ray direction"
+        );
+
+        Ray::from_unit_direction(self.apply_point(ray.origin()), direction)
+    }
+
+    /// Express a `From`-frame ray in the `To` frame, like
+    /// [`Transform::apply_ray`], checking that the result is a valid ray.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if the mapped origin or direction
+    /// is not finite, and [`SpatialError::ZeroNormVector`] if the linear
+    /// block collapses the direction to (near) zero length.
+    pub fn try_apply_ray(&self, ray: Ray<From>) -> Result<Ray<To>, SpatialError> {
+        Ray::try_new(
+            self.apply_point(ray.origin()),
+            self.apply_vector(ray.direction()),
+        )
+    }
+
+    /// Express a `From`-frame plane in the `To` frame.
+    ///
+    /// The normal is mapped by the inverse-transpose of the linear block, so
+    /// it stays perpendicular to the plane even under scaling or shear (for
+    /// rigid transforms this is just the rotation). The offset is then
+    /// recomputed from a transformed point of the plane; reusing the old
+    /// offset would ignore the translation.
+    ///
+    /// The linear block must be invertible; otherwise the result is not
+    /// finite or not meaningful, which debug builds catch with an assertion.
+    /// Use [`Transform::try_apply_plane`] when the transform may be singular.
+    #[must_use]
+    pub fn apply_plane(&self, plane: Plane<From>) -> Plane<To> {
+        let (normal, det) = self.map_plane_normal(plane.normal());
+        let length = math::sqrt(dot3(normal, normal));
+        let normal = Vector3::new(normal[0] / length, normal[1] / length, normal[2] / length);
+        debug_assert!(
+            det != 0.0 && normal.x.is_finite() && normal.y.is_finite() && normal.z.is_finite(),
+            "apply_plane: linear block is singular"
+        );
+
+        Plane::from_unit_normal(normal, self.apply_point(plane.closest_point_to_origin()))
+    }
+
+    /// Express a `From`-frame plane in the `To` frame, like
+    /// [`Transform::apply_plane`], checking that the linear block is
+    /// invertible.
+    ///
+    /// A determinant that vanishes relative to the largest entry of the
+    /// linear block counts as singular.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any entry is not finite, and
+    /// [`SpatialError::NotInvertible`] if the linear block is singular.
+    pub fn try_apply_plane(&self, plane: Plane<From>) -> Result<Plane<To>, SpatialError> {
+        let m = &self.matrix;
+        if m.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(SpatialError::NonFinite);
+        }
+
+        let (normal, det) = self.map_plane_normal(plane.normal());
+        let scale = m[..3]
+            .iter()
+            .flat_map(|row| &row[..3])
+            .fold(0.0_f64, |acc, v| acc.max(v.abs()));
+        if det.abs() <= f64::EPSILON * scale * scale * scale {
+            return Err(SpatialError::NotInvertible);
+        }
+
+        Plane::try_from_point_normal(
+            self.apply_point(plane.closest_point_to_origin()),
+            Vector3::new(normal[0], normal[1], normal[2]),
+        )
+    }
+
+    /// Map a plane normal by the inverse-transpose of the linear block, up to
+    /// a positive scale, returning it with the block's determinant.
+    fn map_plane_normal(&self, n: Vector3<From>) -> ([f64; 3], f64) {
+        let m = &self.matrix;
+        let a = [
+            [m[0][0], m[0][1], m[0][2]],
+            [m[1][0], m[1][1], m[1][2]],
+            [m[2][0], m[2][1], m[2][2]],
+        ];
+
+        // (A⁻¹)ᵀ = cof(A) / det(A); only the direction matters, so scale by
+        // the sign of the determinant to keep the normal's orientation.
+        let cofactor = [cross3(a[1], a[2]), cross3(a[2], a[0]), cross3(a[0], a[1])];
+        let n = [n.x, n.y, n.z];
+        let det = dot3(a[0], cofactor[0]);
+        (cofactor.map(|row| dot3(row, n) * det.signum()), det)
+    }
+
+    /// Apply this transform to a point, then clamp the result into `bounds`.
+    ///
+    /// Useful for keeping simulated positions inside an arena: points that
+    /// would land outside the box end up on its surface instead. Clamping
+    /// follows [`Aabb::clamp`], so it never panics on `NaN` bounds.
+    #[inline]
+    #[must_use]
+    pub fn apply_point_clamped(&self, p: Point3<From>, bounds: &Aabb<To>) -> Point3<To> {
+        bounds.clamp(self.apply_point(p))
+    }
+
+    /// Cross two `From`-frame vectors and express the result in `To`.
+    ///
+    /// [`Vector3::cross`] always stays in the frame of its operands. When the
+    /// product is meant as an axis of another frame, this is the explicit way
+    /// to move it there: the cross product is rotated by this transform
+    /// (translation does not apply to directions), so the result is tagged
+    /// with the frame it is actually expressed in.
+    #[inline]
+    #[must_use]
+    pub fn basis_cross(&self, a: Vector3<From>, b: Vector3<From>) -> Vector3<To> {
+        self.apply_vector(a.cross(b))
+    }
+
+    /// Where the `From` frame's unit x vector points, expressed in `To`.
+    #[inline]
+    #[must_use]
+    pub fn x_axis(&self) -> Vector3<To> {
+        let m = &self.matrix;
+        Vector3::new(m[0][0], m[1][0], m[2][0])
+    }
+
+    /// Where the `From` frame's unit y vector points, expressed in `To`.
+    #[inline]
+    #[must_use]
+    pub fn y_axis(&self) -> Vector3<To> {
+        let m = &self.matrix;
+        Vector3::new(m[0][1], m[1][1], m[2][1])
+    }
+
+    /// Where the `From` frame's unit z vector points, expressed in `To`.
+    #[inline]
+    #[must_use]
+    pub fn z_axis(&self) -> Vector3<To> {
+        let m = &self.matrix;
+        Vector3::new(m[0][2], m[1][2], m[2][2])
+    }
+
+    /// The `From` frame's origin, expressed in `To`.
+    #[inline]
+    #[must_use]
+    pub fn origin(&self) -> Point3<To> {
+        let m = &self.matrix;
+        Point3::new(m[0][3], m[1][3], m[2][3])
+    }
+
+    /// Like [`Transform::compose`], for a next hop that may have failed to
+    /// build.
+    ///
+    /// Returns the composition if `next` is `Ok` and passes its error through
+    /// unchanged otherwise. This keeps a chain of fallible constructors
+    /// readable without naming every intermediate:
+    ///
+    /// ```rust
+    /// use spatial_typestate::{spatial_frames, Point3, SpatialError, Transform, Vector3};
+    ///
+    /// spatial_frames! {
+    ///     Sensor,
+    ///     Body,
+    ///     World,
+    /// }
+    ///
+    /// fn sensor_to_world(
+    ///     mount: Transform<Sensor, Body>,
+    ///     heading: Vector3<World>,
+    /// ) -> Result<Transform<Sensor, World>, SpatialError> {
+    ///     let up = Vector3::new(0.0, 0.0, 1.0);
+    ///     let body_to_world = Transform::from_basis(
+    ///         heading.try_normalize()?,
+    ///         up.cross(heading).try_normalize()?,
+    ///         up,
+    ///         Point3::new(0.0, 0.0, 0.0),
+    ///     );
+    ///     mount.try_then(body_to_world)
+    /// }
+    ///
+    /// let mount = Transform::from_translation(0.0, 0.0, 1.0);
+    /// assert!(sensor_to_world(mount, Vector3::new(1.0, 0.0, 0.0)).is_ok());
+    /// assert_eq!(
+    ///     sensor_to_world(mount, Vector3::new(0.0, 0.0, 0.0)),
+    ///     Err(SpatialError::ZeroNormVector),
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error held by `next`, if any.
+    #[inline]
+    pub fn try_then<Next: Frame, E>(
+        &self,
+        next: Result<Transform<To, Next>, E>,
+    ) -> Result<Transform<From, Next>, E> {
+        next.map(|next| self.compose(&next))
+    }
+
+    /// Invert this transform, producing the mapping from `To` back to `From`.
+    ///
+    /// This assumes the transform is rigid (orthonormal rotation block plus
+    /// translation, bottom row `[0, 0, 0, 1]`) and uses the closed form
+    /// `[Rᵀ | -Rᵀ t]`, which is cheaper and numerically better behaved than a
+    /// general 4×4 inversion. The result is meaningless for non-rigid
+    /// matrices.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Transform<To, From> {
+        let m = &self.matrix;
+
+        // Transposed rotation block.
+        let r = [
+            [m[0][0], m[1][0], m[2][0]],
+            [m[0][1], m[1][1], m[2][1]],
+            [m[0][2], m[1][2], m[2][2]],
+        ];
+        let t = [m[0][3], m[1][3], m[2][3]];
+
+        Transform::<To, From>::from_matrix([
+            [
+                r[0][0],
+                r[0][1],
+                r[0][2],
+                -(r[0][0] * t[0] + r[0][1] * t[1] + r[0][2] * t[2]),
+            ],
+            [
+                r[1][0],
+                r[1][1],
+                r[1][2],
+                -(r[1][0] * t[0] + r[1][1] * t[1] + r[1][2] * t[2]),
+            ],
+            [
+                r[2][0],
+                r[2][1],
+                r[2][2],
+                -(r[2][0] * t[0] + r[2][1] * t[1] + r[2][2] * t[2]),
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// The motion from `reference` to `self`, two poses of the same frame
+    /// pair: `reference⁻¹ ∘ self`.
+    ///
+    /// Read both as snapshots of `From` (say, a robot body) placed in `To`.
+    /// The result maps `From` coordinates of the `self` snapshot into `From`
+    /// coordinates of the `reference` snapshot, i.e. it is where the body
+    /// ended up as seen from where it started, hence `Transform<From, From>`.
+    /// Composing it back onto the reference, `delta.compose(&reference)`,
+    /// recovers `self`. Both transforms must be rigid.
+    #[inline]
+    #[must_use]
+    pub fn relative_to(&self, reference: &Self) -> Transform<From, From> {
+        self.compose(&reference.inverse())
+    }
+
+    /// Invert a general (not necessarily rigid) transform.
+    ///
+    /// Rigid transforms take the closed-form [`Transform::inverse`]. Anything
+    /// else (scaling, shear, projection) is inverted through `nalgebra`'s LU
+    /// decomposition with the `nalgebra` feature, and by Gauss–Jordan
+    /// elimination with partial pivoting without it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any entry is not finite, and
+    /// [`SpatialError::NotInvertible`] if the matrix is singular or its
+    /// inverse is not finite.
+    pub fn try_inverse(&self) -> Result<Transform<To, From>, SpatialError> {
+        const RIGID_TOL: f64 = 1e-9;
+
+        let m = &self.matrix;
+        if m.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(SpatialError::NonFinite);
+        }
+        if self.is_rigid(RIGID_TOL) {
+            return Ok(self.inverse());
+        }
+
+        #[cfg(feature = "nalgebra")]
+        let inv = crate::nalgebra::lu_inverse(m)?;
+
+        #[cfg(not(feature = "nalgebra"))]
+        let inv = gauss_jordan_inverse(m)?;
+
+        if inv.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(SpatialError::NotInvertible);
+        }
+
+        Ok(Transform::from_matrix(inv))
+    }
+
+    /// Estimate linear and angular velocity from two timestamped samples of a
+    /// moving frame's pose.
+    ///
+    /// Each sample is the transform from the moving frame `From` (e.g. a
+    /// body) into the reference frame `To` (e.g. the world), i.e. the pose of
+    /// `From` in `To`. Both velocities are returned in `To`:
+    ///
+    /// - the linear velocity is the change of the translation column (the
+    ///   `From` origin) divided by `dt`;
+    /// - the angular velocity is the rotation vector (quaternion log) of the
+    ///   relative rotation `R₁ R₀ᵀ` divided by `dt`.
+    ///
+    /// This is a first-order finite difference: it assumes constant velocity
+    /// between the samples and a rotation of less than half a turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if the timestamps are not finite,
+    /// and [`SpatialError::DegenerateInput`] if `later` is not strictly after
+    /// `earlier`.
+    pub fn relative_angular_and_linear_velocity(
+        earlier: &Stamped<Self>,
+        later: &Stamped<Self>,
+    ) -> Result<(Velocity3<To>, AngularVelocity3<To>), SpatialError> {
+        let dt = later.stamp.get() - earlier.stamp.get();
+        if !dt.is_finite() {
+            return Err(SpatialError::NonFinite);
+        }
+        if dt <= 0.0 {
+            return Err(SpatialError::DegenerateInput);
+        }
+
+        let m0 = &earlier.value.matrix;
+        let m1 = &later.value.matrix;
+
+        let linear = Vector3::new(
+            (m1[0][3] - m0[0][3]) / dt,
+            (m1[1][3] - m0[1][3]) / dt,
+            (m1[2][3] - m0[2][3]) / dt,
+        );
+
+        // Relative rotation R₁ R₀ᵀ, expressed in `To`.
+        let mut delta = [[0.0_f64; 3]; 3];
+        for (row, delta_row) in delta.iter_mut().enumerate() {
+            for (col, v) in delta_row.iter_mut().enumerate() {
+                *v = m1[row][0] * m0[col][0] + m1[row][1] * m0[col][1] + m1[row][2] * m0[col][2];
+            }
+        }
+        let rv = quat_log3(quat_from_rotation3(delta));
+        let angular = Vector3::new(rv[0] / dt, rv[1] / dt, rv[2] / dt);
+
+        Ok((Velocity3(linear), AngularVelocity3(angular)))
+    }
+
+    /// Weighted average of several rigid transforms.
+    ///
+    /// Translations are averaged linearly by weight. Rotations are averaged
+    /// with the normalized weighted quaternion mean: each rotation is
+    /// converted to a quaternion, flipped onto the same hemisphere as the
+    /// first one (`q` and `-q` are the same rotation), summed by weight and
+    /// renormalized. This closely approximates the true rotational mean when
+    /// the inputs are clustered (e.g. several trackers observing the same
+    /// pose) and is exact for two rotations blended 50/50.
+    ///
+    /// Weights must be non-negative; they need not sum to one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any weight or matrix entry is
+    /// not finite, and [`SpatialError::DegenerateInput`] if `poses` is empty,
+    /// a weight is negative, the weights sum to zero, or the rotations cancel
+    /// out.
+    pub fn blend_many(poses: &[(Self, f64)]) -> Result<Self, SpatialError> {
+        let mut total = 0.0_f64;
+        let mut t = [0.0_f64; 3];
+        let mut q_sum = [0.0_f64; 4];
+        let mut reference: Option<[f64; 4]> = None;
+
+        for (pose, weight) in poses {
+            let weight = *weight;
+            if !weight.is_finite() || pose.matrix.iter().flatten().any(|v| !v.is_finite()) {
+                return Err(SpatialError::NonFinite);
+            }
+            if weight < 0.0 {
+                return Err(SpatialError::DegenerateInput);
+            }
+
+            let m = &pose.matrix;
+            for (acc, row) in t.iter_mut().zip(m.iter()) {
+                *acc += weight * row[3];
+            }
+
+            let q = quat_from_rotation3(pose.rotation3());
+            let r = *reference.get_or_insert(q);
+            let sign = if q.iter().zip(r.iter()).map(|(a, b)| a * b).sum::<f64>() < 0.0 {
+                -weight
+            } else {
+                weight
+            };
+            for (acc, c) in q_sum.iter_mut().zip(q.iter()) {
+                *acc += sign * c;
+            }
+
+            total += weight;
+        }
+
+        if total <= 0.0 {
+            return Err(SpatialError::DegenerateInput);
+        }
+
+        let q_norm = math::sqrt(q_sum.iter().map(|c| c * c).sum::<f64>());
+        if q_norm <= f64::EPSILON {
+            return Err(SpatialError::DegenerateInput);
+        }
+
+        let r = rotation3_from_quat(q_sum.map(|c| c / q_norm));
+        Ok(Self::from_rotation3_translation(
+            r,
+            [t[0] / total, t[1] / total, t[2] / total],
+        ))
+    }
+
+    /// Interpolate between two rigid transforms along the SE(3) geodesic.
+    ///
+    /// The relative motion `self⁻¹ · other` is taken to its twist with the
+    /// SE(3) logarithm, scaled by `t` and mapped back with the exponential,
+    /// then applied on top of `self`. The result moves along a single screw
+    /// axis at constant rate: rotation and translation are coupled, so a
+    /// turn about an offset axis sweeps a helix rather than the straight
+    /// chord that blending translation and rotation separately would give.
+    ///
+    /// `t = 0` yields `self` and `t = 1` yields `other`; values outside
+    /// `[0, 1]` extrapolate along the same screw. Both inputs must be rigid.
+    #[must_use]
+    pub fn lerp_se3(&self, other: &Self, t: f64) -> Self {
+        let delta: Transform<From, From> = other.compose(&self.inverse());
+        let dm = &delta.matrix;
+
+        let (omega, u) = se3_log(delta.rotation3(), [dm[0][3], dm[1][3], dm[2][3]]);
+        let (r, p) = se3_exp(omega.map(|v| v * t), u.map(|v| v * t));
+
+        Transform::<From, From>::from_rotation3_translation(r, p).compose(self)
+    }
+
+    /// Whether the matrix is a proper rigid transform to within `tol`: bottom
+    /// row `[0, 0, 0, 1]`, orthonormal rotation block with determinant `+1`.
+    pub(crate) fn is_rigid(&self, tol: f64) -> bool {
+        if !self.is_affine(tol) {
+            return false;
+        }
+
+        let r = self.rotation3();
+        let columns = [0, 1, 2].map(|c| [r[0][c], r[1][c], r[2][c]]);
+        for (i, a) in columns.iter().enumerate() {
+            for (j, b) in columns.iter().enumerate().skip(i) {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                if (dot3(*a, *b) - expected).abs() > tol {
+                    return false;
+                }
+            }
+        }
+
+        dot3(cross3(columns[0], columns[1]), columns[2]) > 0.0
+    }
+
+    /// Upper-left 3×3 rotation block.
+    #[inline]
+    fn rotation3(&self) -> [[f64; 3]; 3] {
+        let m = &self.matrix;
+        [
+            [m[0][0], m[0][1], m[0][2]],
+            [m[1][0], m[1][1], m[1][2]],
+            [m[2][0], m[2][1], m[2][2]],
+        ]
+    }
+
+    /// Whether the bottom row is `[0, 0, 0, 1]` within `tol`, i.e. the
+    /// transform has no projective component.
+    ///
+    /// Rigid, scaling and shearing transforms are all affine; perspective
+    /// projections are not.
+    #[must_use]
+    pub fn is_affine(&self, tol: f64) -> bool {
+        self.matrix[3]
+            .iter()
+            .zip([0.0, 0.0, 0.0, 1.0])
+            .all(|(v, expected)| (v - expected).abs() <= tol)
+    }
+
+    /// Whether every matrix entry is within `epsilon` of the corresponding
+    /// entry of `other`.
+    ///
+    /// Use this instead of `==` when the two transforms came out of
+    /// floating-point arithmetic. Entries that are `NaN` never compare equal.
+    /// With the `approx` feature this agrees with the
+    /// [`approx`](crate::approx) `AbsDiffEq` impl.
+    #[must_use]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.matrix
+            .iter()
+            .flatten()
+            .zip(other.matrix.iter().flatten())
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+
+    /// Whether this transform is the identity to within `epsilon` per
+    /// matrix entry, e.g. to skip applying it.
+    #[inline]
+    #[must_use]
+    pub fn is_identity(&self, epsilon: f64) -> bool {
+        self.abs_diff_eq(&Self::identity(), epsilon)
+    }
+
+    /// Copy of this transform with the bottom row forced to `[0, 0, 0, 1]`.
+    ///
+    /// The upper 3×4 block is kept as is. This is lossless when
+    /// [`Transform::is_affine`] already holds. Otherwise the projective part
+    /// is simply dropped, **not** divided out: points are no longer mapped
+    /// the way the original matrix maps them (a perspective projection, for
+    /// example, stops being one), so only use this to clean up numerical
+    /// noise or deliberately discard a projection.
+    #[must_use]
+    pub fn affine_truncated(&self) -> Self {
+        let mut m = self.matrix;
+        m[3] = [0.0, 0.0, 0.0, 1.0];
+
+        Self::from_matrix(m)
+    }
+
+    /// Flip the sign of the translation column, keeping the rotation block.
+    ///
+    /// For `p ↦ R p + t` this returns `p ↦ R p - t`. This is **not** the
+    /// inverse: [`Transform::inverse`] yields `p ↦ Rᵀ p - Rᵀ t` and maps `To`
+    /// back to `From`, whereas this keeps the `From → To` direction. The two
+    /// only coincide for pure translations.
+    #[inline]
+    #[must_use]
+    pub fn negated_translation(&self) -> Self {
+        let mut m = self.matrix;
+        m[0][3] = -m[0][3];
+        m[1][3] = -m[1][3];
+        m[2][3] = -m[2][3];
+
+        Self::from_matrix(m)
+    }
+}
+
+impl<From: Frame, To: Frame, T: Scalar> Clone for Transform<From, To, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<From: Frame, To: Frame, T: Scalar> Copy for Transform<From, To, T> {}
+
+impl<From: Frame, To: Frame, T: Scalar> fmt::Debug for Transform<From, To, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transform")
+            .field("from", &FrameName::<From>::new())
+            .field("to", &FrameName::<To>::new())
+            .field("matrix", &self.matrix)
+            .finish()
+    }
+}
+
+/// Prints the frames followed by the matrix, one bracketed row per line,
+/// with the columns aligned:
+///
+/// ```text
+/// Transform Body -> World
+/// [1.0000  0.0000  0.0000  10.0000]
+/// ...
+/// ```
+///
+/// Entries use the formatter's precision, four decimals by default, so
+/// `{:.2}` prints a more compact matrix.
+impl<From: Frame, To: Frame, T: Scalar> fmt::Display for Transform<From, To, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const DEFAULT_PRECISION: usize = 4;
+
+        let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
+        let mut widths = [0; 4];
+        for row in &self.matrix {
+            for (width, v) in widths.iter_mut().zip(row) {
+                let mut counter = CharCount(0);
+                write!(counter, "{v:.precision$?}")?;
+                *width = (*width).max(counter.0);
+            }
+        }
+
+        write!(
+            f,
+            "Transform {:?} -> {:?}",
+            FrameName::<From>::new(),
+            FrameName::<To>::new()
+        )?;
+        for row in &self.matrix {
+            f.write_str("\n[")?;
+            for (col, (v, width)) in row.iter().zip(widths).enumerate() {
+                if col > 0 {
+                    f.write_str("  ")?;
+                }
+                write!(f, "{v:>width$.precision$?}")?;
+            }
+            f.write_str("]")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A `fmt::Write` sink that only counts characters, to measure formatted
+/// output without allocating.
+struct CharCount(usize);
+
+impl fmt::Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Point reflection through the origin of `To`, applied after the transform.
+///
+/// For `p ↦ R p + t`, `-transform` is `p ↦ -(R p + t)`: every output point is
+/// mirrored through the `To` origin. The resulting rotation block has
+/// determinant −1, so the result is **not** rigid and must not be fed to
+/// [`Transform::inverse`]. It is neither the inverse transform nor
+/// [`Transform::negated_translation`].
+impl<From: Frame, To: Frame> Neg for Transform<From, To> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let mut m = self.matrix;
+        for row in m.iter_mut().take(3) {
+            for v in row.iter_mut() {
+                *v = -*v;
+            }
+        }
+
+        Self::from_matrix(m)
+    }
+}
+
+/// `a * b` is [`a.compose(&b)`](Transform::compose): apply `a`, then `b`.
+///
+/// The operands read in the same order as the frames they connect
+/// (`Transform<A, B> * Transform<B, C>` gives `Transform<A, C>`), which is the
+/// reverse of the underlying matrix product `b.matrix × a.matrix`. Operands
+/// whose shared frame does not match do not compile.
+impl<From: Frame, Mid: Frame, To: Frame, T: Scalar> Mul<Transform<Mid, To, T>>
+    for Transform<From, Mid, T>
+{
+    type Output = Transform<From, To, T>;
+
+    #[inline]
+    fn mul(self, rhs: Transform<Mid, To, T>) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+
+/// General 4×4 inverse by Gauss–Jordan elimination with partial pivoting,
+/// for the non-rigid branch of [`Transform::try_inverse`] when the
+/// `nalgebra` feature (and its LU decomposition) is not available.
+///
+/// Treats a pivot that vanishes relative to the largest entry as singular.
+#[cfg(not(feature = "nalgebra"))]
+fn gauss_jordan_inverse(m: &[[f64; 4]; 4]) -> Result<[[f64; 4]; 4], SpatialError> {
+    let scale = m.iter().flatten().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+    let mut a = *m;
+    let mut inv = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+
+    for col in 0..4 {
+        let pivot_row = (col..4)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+            .unwrap_or(col);
+        let pivot = a[pivot_row][col];
+        if pivot.abs() <= f64::EPSILON * scale {
+            return Err(SpatialError::NotInvertible);
+        }
+        a.swap(col, pivot_row);
+        inv.swap(col, pivot_row);
+
+        for c in 0..4 {
+            a[col][c] /= pivot;
+            inv[col][c] /= pivot;
+        }
+        for row in 0..4 {
+            if row == col {
+                continue;
+            }
+            let factor = a[row][col];
+            for c in 0..4 {
+                a[row][c] -= factor * a[col][c];
+                inv[row][c] -= factor * inv[col][c];
+            }
+        }
+    }
+
+    Ok(inv)
+}
+
+#[inline]
+fn dot3(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+fn cross3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Normalize a 3-vector, or `None` if it is too short to have a direction.
+#[inline]
+fn normalize3(a: [f64; 3]) -> Option<[f64; 3]> {
+    let norm = math::sqrt(dot3(a, a));
+    if norm <= f64::EPSILON {
+        return None;
+    }
+
+    Some([a[0] / norm, a[1] / norm, a[2] / norm])
+}
+
+/// Coefficients `(a, b, c)` of the SE(3) left Jacobian `V = I + a [ω]ₓ +
+/// b [ω]ₓ²` and its inverse `V⁻¹ = I − ½ [ω]ₓ + c [ω]ₓ²`, for `θ = |ω|`.
+///
+/// The closed forms cancel catastrophically for small `θ` (the error in `c`
+/// grows like `ε/θ²`), so below `θ = 10⁻²` their Taylor series are used
+/// instead; the first omitted terms are then below `10⁻¹⁶`.
+fn se3_coefficients(theta_sq: f64) -> (f64, f64, f64) {
+    if theta_sq < 1e-4 {
+        let theta_4 = theta_sq * theta_sq;
+        return (
+            0.5 - theta_sq / 24.0 + theta_4 / 720.0,
+            1.0 / 6.0 - theta_sq / 120.0 + theta_4 / 5040.0,
+            1.0 / 12.0 + theta_sq / 720.0 + theta_4 / 30240.0,
+        );
+    }
+
+    let theta = math::sqrt(theta_sq);
+    let (sin, cos) = math::sin_cos(theta);
+    (
+        (1.0 - cos) / theta_sq,
+        (theta - sin) / (theta_sq * theta),
+        (1.0 - theta * sin / (2.0 * (1.0 - cos))) / theta_sq,
+    )
+}
+
+/// SE(3) logarithm of a rigid motion: rotation vector `ω` and the linear
+/// part `u = V⁻¹ t` of the twist.
+fn se3_log(r: [[f64; 3]; 3], t: [f64; 3]) -> ([f64; 3], [f64; 3]) {
+    let omega = quat_log3(quat_from_rotation3(r));
+    let (_, _, c) = se3_coefficients(dot3(omega, omega));
+
+    let wt = cross3(omega, t);
+    let wwt = cross3(omega, wt);
+    let u = [
+        t[0] - 0.5 * wt[0] + c * wwt[0],
+        t[1] - 0.5 * wt[1] + c * wwt[1],
+        t[2] - 0.5 * wt[2] + c * wwt[2],
+    ];
+
+    (omega, u)
+}
+
+/// SE(3) exponential of a twist `(ω, u)`: rotation `exp(ω)` and
+/// translation `V u`.
+fn se3_exp(omega: [f64; 3], u: [f64; 3]) -> ([[f64; 3]; 3], [f64; 3]) {
+    let (a, b, _) = se3_coefficients(dot3(omega, omega));
+
+    let wu = cross3(omega, u);
+    let wwu = cross3(omega, wu);
+    let t = [
+        u[0] + a * wu[0] + b * wwu[0],
+        u[1] + a * wu[1] + b * wwu[1],
+        u[2] + a * wu[2] + b * wwu[2],
+    ];
+
+    (rotation3_from_quat(quat_exp3(omega)), t)
+}
+
+/// Rotation matrix taking unit vector `from` onto unit vector `to` along the
+/// shortest arc.
+fn shortest_arc3(from: [f64; 3], to: [f64; 3]) -> Result<[[f64; 3]; 3], SpatialError> {
+    let cos = dot3(from, to);
+
+    if cos < -1.0 + 1e-12 {
+        // Opposite directions: half-turn about any axis perpendicular to
+        // `from`, i.e. R = 2 u uᵀ - I.
+        let helper = if from[0].abs() < 0.9 {
+            [1.0, 0.0, 0.0]
+        } else {
+            [0.0, 1.0, 0.0]
+        };
+        let u = normalize3(cross3(from, helper)).ok_or(SpatialError::DegenerateInput)?;
+        return Ok([
+            [
+                2.0 * u[0] * u[0] - 1.0,
+                2.0 * u[0] * u[1],
+                2.0 * u[0] * u[2],
+            ],
+            [
+                2.0 * u[1] * u[0],
+                2.0 * u[1] * u[1] - 1.0,
+                2.0 * u[1] * u[2],
+            ],
+            [
+                2.0 * u[2] * u[0],
+                2.0 * u[2] * u[1],
+                2.0 * u[2] * u[2] - 1.0,
+            ],
+        ]);
+    }
+
+    // Rodrigues' formula with the axis scaled by sin θ:
+    // R = cos θ I + [v]ₓ + v vᵀ / (1 + cos θ), where v = from × to.
+    let v = cross3(from, to);
+    let k = 1.0 / (1.0 + cos);
+    Ok([
+        [
+            v[0] * v[0] * k + cos,
+            v[0] * v[1] * k - v[2],
+            v[0] * v[2] * k + v[1],
+        ],
+        [
+            v[1] * v[0] * k + v[2],
+            v[1] * v[1] * k + cos,
+            v[1] * v[2] * k - v[0],
+        ],
+        [
+            v[2] * v[0] * k - v[1],
+            v[2] * v[1] * k + v[0],
+            v[2] * v[2] * k + cos,
+        ],
+    ])
 }
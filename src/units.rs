@@ -11,9 +11,32 @@
 //! let altitude: Quantity<Meters> = Quantity::new(1500.0);
 //! let angle: Quantity<Radians> = Quantity::new(1.5708);
 //! ```
+//!
+//! Multiplying and dividing quantities combines their units at the type
+//! level (see [`UnitMul`] and [`UnitDiv`]). The common composites have names
+//! such as [`SquareMeters`] and [`MetersPerSecond`]:
+//!
+//! ```rust
+//! use spatial_typestate::units::{MetersPerSecond, SquareMeters};
+//! use spatial_typestate::{Meters, Quantity, Seconds};
+//!
+//! let side = Quantity::<Meters>::new(3.0);
+//! let area: Quantity<SquareMeters> = side * side;
+//! assert_eq!(area.get(), 9.0);
+//!
+//! let speed: Quantity<MetersPerSecond> = side / Quantity::<Seconds>::new(2.0);
+//! let travelled: Quantity<Meters> = speed * Quantity::<Seconds>::new(4.0);
+//! assert_eq!(travelled.get(), 6.0);
+//! ```
 
+use core::cmp::Ordering;
+use core::fmt;
 use core::marker::PhantomData;
-use core::ops::{Add, Sub};
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::frame::write_short_type_name;
+use crate::math;
+use crate::scalar::Scalar;
 
 /// Marker trait for a physical unit.
 ///
@@ -23,7 +46,13 @@ use core::ops::{Add, Sub};
 pub trait Unit: 'static {}
 
 /// Marker trait for length units.
-pub trait LengthUnit: Unit {}
+///
+/// Every length unit states its size in meters, which is what lets
+/// [`ConvertTo`] convert between any two of them.
+pub trait LengthUnit: Unit {
+    /// Length of one of this unit, in meters.
+    const METERS: f64;
+}
 
 /// Marker trait for angle units.
 pub trait AngleUnit: Unit {}
@@ -32,6 +61,20 @@ pub trait AngleUnit: Unit {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Meters;
 
+/// Zero-sized marker type representing international feet (0.3048 m) as a
+/// length unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Feet;
+
+/// Zero-sized marker type representing kilometers as a length unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Kilometers;
+
+/// Zero-sized marker type representing international nautical miles
+/// (1852 m) as a length unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NauticalMiles;
+
 /// Zero-sized marker type representing radians as an angle unit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Radians;
@@ -40,8 +83,29 @@ pub struct Radians;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Degrees;
 
+/// Zero-sized marker type representing seconds as a time unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Seconds;
+
 impl Unit for Meters {}
-impl LengthUnit for Meters {}
+impl LengthUnit for Meters {
+    const METERS: f64 = 1.0;
+}
+
+impl Unit for Feet {}
+impl LengthUnit for Feet {
+    const METERS: f64 = 0.3048;
+}
+
+impl Unit for Kilometers {}
+impl LengthUnit for Kilometers {
+    const METERS: f64 = 1000.0;
+}
+
+impl Unit for NauticalMiles {}
+impl LengthUnit for NauticalMiles {
+    const METERS: f64 = 1852.0;
+}
 
 impl Unit for Radians {}
 impl AngleUnit for Radians {}
@@ -49,27 +113,236 @@ impl AngleUnit for Radians {}
 impl Unit for Degrees {}
 impl AngleUnit for Degrees {}
 
+impl Unit for Seconds {}
+
+/// Zero-sized marker type for pure numbers: ratios, gains, scale factors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dimensionless;
+
+impl Unit for Dimensionless {}
+
+/// Zero-sized marker type representing degrees Celsius as a temperature
+/// unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Celsius;
+
+/// Zero-sized marker type representing kelvin as a temperature unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Kelvin;
+
+impl Unit for Celsius {}
+impl Unit for Kelvin {}
+
+/// A unit with a conventional printed symbol, e.g. `"m"` for [`Meters`].
+///
+/// Used wherever a unit has to be spelled out at runtime, such as the
+/// self-describing serialized form of a quantity (feature `serde`). Composite
+/// units built from [`Product`] and [`Quotient`] have no symbol.
+pub trait UnitSymbol: Unit {
+    /// The unit's symbol.
+    const SYMBOL: &'static str;
+}
+
+impl UnitSymbol for Meters {
+    const SYMBOL: &'static str = "m";
+}
+
+impl UnitSymbol for Feet {
+    const SYMBOL: &'static str = "ft";
+}
+
+impl UnitSymbol for Kilometers {
+    const SYMBOL: &'static str = "km";
+}
+
+impl UnitSymbol for NauticalMiles {
+    const SYMBOL: &'static str = "NM";
+}
+
+impl UnitSymbol for Radians {
+    const SYMBOL: &'static str = "rad";
+}
+
+impl UnitSymbol for Degrees {
+    const SYMBOL: &'static str = "deg";
+}
+
+impl UnitSymbol for Seconds {
+    const SYMBOL: &'static str = "s";
+}
+
+impl UnitSymbol for Dimensionless {
+    const SYMBOL: &'static str = "1";
+}
+
+impl UnitSymbol for Celsius {
+    const SYMBOL: &'static str = "°C";
+}
+
+impl UnitSymbol for Kelvin {
+    const SYMBOL: &'static str = "K";
+}
+
+/// The product of two units, e.g. `Product<Meters, Meters>` for square meters.
+///
+/// This is a purely type-level marker: it carries no scale factor and only
+/// records which units were multiplied together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Product<A: Unit, B: Unit>(PhantomData<(A, B)>);
+
+impl<A: Unit, B: Unit> Unit for Product<A, B> {}
+
+/// The quotient of two units, e.g. `Quotient<Meters, Seconds>` for speed.
+///
+/// Like [`Product`], this is a purely type-level marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quotient<A: Unit, B: Unit>(PhantomData<(A, B)>);
+
+impl<A: Unit, B: Unit> Unit for Quotient<A, B> {}
+
+/// Type-level multiplication of units: `Self × Rhs = Output`.
+///
+/// This drives `Quantity<A> * Quantity<B>`: the product is only defined when
+/// `A: UnitMul<B>`, and its unit is the associated `Output`. Every unit is
+/// multiplied by [`Dimensionless`] into itself.
+pub trait UnitMul<Rhs: Unit>: Unit {
+    /// Unit of the product.
+    type Output: Unit;
+}
+
+/// Type-level division of units: `Self ÷ Rhs = Output`.
+///
+/// This drives `Quantity<A> / Quantity<B>`. Every unit divided by itself is
+/// [`Dimensionless`].
+pub trait UnitDiv<Rhs: Unit>: Unit {
+    /// Unit of the quotient.
+    type Output: Unit;
+}
+
+impl<U: Unit> UnitMul<Dimensionless> for U {
+    type Output = U;
+}
+
+impl<U: Unit> UnitDiv<U> for U {
+    type Output = Dimensionless;
+}
+
+/// Square meters, the unit of area.
+pub type SquareMeters = Product<Meters, Meters>;
+
+/// Cubic meters, the unit of volume.
+pub type CubicMeters = Product<SquareMeters, Meters>;
+
+/// Meters per second, the unit of linear speed.
+pub type MetersPerSecond = Quotient<Meters, Seconds>;
+
+/// Radians per second, the unit of angular rate.
+pub type RadiansPerSecond = Quotient<Radians, Seconds>;
+
+macro_rules! unit_mul {
+    ($( $a:ty, $b:ty => $out:ty; )+) => {
+        $(
+            impl UnitMul<$b> for $a {
+                type Output = $out;
+            }
+        )+
+    };
+}
+
+macro_rules! unit_div {
+    ($( $a:ty, $b:ty => $out:ty; )+) => {
+        $(
+            impl UnitDiv<$b> for $a {
+                type Output = $out;
+            }
+        )+
+    };
+}
+
+unit_mul! {
+    Meters, Meters => SquareMeters;
+    SquareMeters, Meters => CubicMeters;
+    Meters, SquareMeters => CubicMeters;
+    MetersPerSecond, Seconds => Meters;
+    Seconds, MetersPerSecond => Meters;
+    RadiansPerSecond, Seconds => Radians;
+    Seconds, RadiansPerSecond => Radians;
+}
+
+unit_div! {
+    SquareMeters, Meters => Meters;
+    CubicMeters, Meters => SquareMeters;
+    CubicMeters, SquareMeters => Meters;
+    Meters, Seconds => MetersPerSecond;
+    Radians, Seconds => RadiansPerSecond;
+}
+
+/// Explicit conversion between length units.
+///
+/// Implemented for every `Quantity<U>` with `U: LengthUnit`, scaling through
+/// the units' [`LengthUnit::METERS`] factors. Converting a unit into itself
+/// leaves the value unchanged.
+///
+/// ```rust
+/// use spatial_typestate::units::{ConvertTo, Feet, Kilometers};
+/// use spatial_typestate::{Meters, Quantity};
+///
+/// let leg = Quantity::<Kilometers>::new(1.5);
+/// let meters: Quantity<Meters> = leg.convert();
+/// assert_eq!(meters.get(), 1500.0);
+///
+/// let ceiling = Quantity::<Feet>::new(10_000.0);
+/// assert_eq!(ConvertTo::<Meters>::convert(ceiling).get(), 3048.0);
+/// ```
+pub trait ConvertTo<V: LengthUnit> {
+    /// The same length expressed in `V`.
+    #[must_use]
+    fn convert(self) -> Quantity<V>;
+}
+
+impl<U: LengthUnit, V: LengthUnit> ConvertTo<V> for Quantity<U> {
+    #[inline]
+    fn convert(self) -> Quantity<V> {
+        Quantity::new(self.value * (U::METERS / V::METERS))
+    }
+}
+
+/// Which bound, if any, [`Quantity::clamp_reporting`] clamped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClampResult {
+    /// The value was already within `[lo, hi]` and is returned unchanged.
+    InRange,
+    /// The value was below `lo` and was raised to it.
+    ClampedLow,
+    /// The value was above `hi` and was lowered to it.
+    ClampedHigh,
+}
+
 /// A scalar quantity tagged with a unit `U`.
 ///
-/// The underlying numeric type is `f64` for now. This can be generalized to
-/// other types later if needed.
+/// The value is an `f64` unless another [`Scalar`] is given as `T`.
+/// Construction, comparison and arithmetic are available for every scalar;
+/// conversions, trigonometry and rounding are defined for `f64`.
 ///
 /// ```rust
 /// use spatial_typestate::{Quantity, Meters};
 ///
 /// let distance: Quantity<Meters> = Quantity::new(42.0);
 /// ```
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct Quantity<U: Unit> {
+///
+/// Quantities of the same unit compare with `==`, `<` and friends by value;
+/// comparing quantities of different units does not compile.
+pub struct Quantity<U: Unit, T: Scalar = f64> {
     /// The numeric value of this quantity.
-    pub value: f64,
+    pub value: T,
     _unit: PhantomData<U>,
 }
 
-impl<U: Unit> Quantity<U> {
+impl<U: Unit, T: Scalar> Quantity<U, T> {
     /// Construct a new quantity with unit `U`.
     #[inline]
-    pub const fn new(value: f64) -> Self {
+    #[must_use]
+    pub const fn new(value: T) -> Self {
         Self {
             value,
             _unit: PhantomData,
@@ -78,12 +351,315 @@ impl<U: Unit> Quantity<U> {
 
     /// Access the raw numeric value.
     #[inline]
-    pub const fn get(self) -> f64 {
+    #[must_use]
+    pub const fn get(self) -> T {
         self.value
     }
 }
 
-impl<U: Unit> Add for Quantity<U> {
+impl<U: Unit> Quantity<U> {
+    /// Round to the nearest multiple of `increment`, keeping the unit.
+    ///
+    /// Ties round away from zero. A zero `increment` leaves the value
+    /// unchanged; the sign of `increment` is irrelevant.
+    ///
+    /// ```rust
+    /// use spatial_typestate::{Meters, Quantity};
+    ///
+    /// let length = Quantity::<Meters>::new(1.27).round_to(Quantity::new(0.1));
+    /// assert!((length.get() - 1.3).abs() < 1e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn round_to(self, increment: Self) -> Self {
+        if increment.value == 0.0 {
+            return self;
+        }
+
+        Self::new(math::round(self.value / increment.value) * increment.value)
+    }
+
+    /// The smaller of `self` and `other`; if one is `NaN`, the other.
+    #[inline]
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.value.min(other.value))
+    }
+
+    /// The larger of `self` and `other`; if one is `NaN`, the other.
+    #[inline]
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.value.max(other.value))
+    }
+
+    /// Clamp into `[lo, hi]`.
+    ///
+    /// Like [`Quantity::clamp_reporting`] without the report: `lo` must not
+    /// exceed `hi`, and a `NaN` value is passed through. Unlike
+    /// [`f64::clamp`] this never panics.
+    #[inline]
+    #[must_use]
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        self.clamp_reporting(lo, hi).0
+    }
+
+    /// Magnitude, keeping the unit.
+    #[inline]
+    #[must_use]
+    pub fn abs(self) -> Self {
+        Self::new(self.value.abs())
+    }
+
+    /// Clamp into `[lo, hi]`, reporting whether a bound was hit.
+    ///
+    /// Meant for actuator limits and similar saturation checks, where the
+    /// caller needs to know that the command was cut. `lo` must not exceed
+    /// `hi`. A `NaN` value is passed through as [`ClampResult::InRange`].
+    ///
+    /// ```rust
+    /// use spatial_typestate::units::ClampResult;
+    /// use spatial_typestate::{Quantity, Radians};
+    ///
+    /// let limit = Quantity::<Radians>::new(0.5);
+    /// let (cmd, hit) = Quantity::new(0.8).clamp_reporting(Quantity::new(-0.5), limit);
+    ///
+    /// assert_eq!(cmd.get(), 0.5);
+    /// assert_eq!(hit, ClampResult::ClampedHigh);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn clamp_reporting(self, lo: Self, hi: Self) -> (Self, ClampResult) {
+        if self.value < lo.value {
+            (lo, ClampResult::ClampedLow)
+        } else if self.value > hi.value {
+            (hi, ClampResult::ClampedHigh)
+        } else {
+            (self, ClampResult::InRange)
+        }
+    }
+
+    /// Convert to unit `B` with the affine map `value · scale + offset`.
+    ///
+    /// Most unit changes are pure scalings (`offset = 0`), but some are not:
+    /// temperature scales and altitude datums differ by an offset as well.
+    /// The caller is responsible for `scale` and `offset` actually mapping
+    /// `U` onto `B`; prefer a predefined conversion such as
+    /// [`Quantity::to_kelvin`] where one exists.
+    ///
+    /// ```rust
+    /// use spatial_typestate::{Meters, Quantity};
+    /// use spatial_typestate::units::Unit;
+    ///
+    /// /// Height above mean sea level.
+    /// struct AboveMsl;
+    /// impl Unit for AboveMsl {}
+    ///
+    /// // The local ground datum sits 312 m above sea level.
+    /// let height_above_ground = Quantity::<Meters>::new(50.0);
+    /// let height_above_msl: Quantity<AboveMsl> = height_above_ground.convert_affine(1.0, 312.0);
+    /// assert_eq!(height_above_msl.get(), 362.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn convert_affine<B: Unit>(self, scale: f64, offset: f64) -> Quantity<B> {
+        Quantity::new(self.value * scale + offset)
+    }
+
+    /// Compute the mean and sample variance of a sequence of quantities.
+    ///
+    /// The variance is expressed in the square of the input unit. It uses
+    /// the unbiased `n - 1` denominator and Welford's single-pass update, so
+    /// large offsets (e.g. altitudes around 10 km with centimeter noise) do
+    /// not suffer from catastrophic cancellation.
+    ///
+    /// Returns `None` if fewer than two samples are provided, since the
+    /// sample variance is undefined in that case.
+    ///
+    /// ```rust
+    /// use spatial_typestate::{Meters, Quantity};
+    ///
+    /// let samples = [1.0, 2.0, 3.0].map(Quantity::<Meters>::new);
+    /// let (mean, var) = Quantity::mean_and_variance(samples).unwrap();
+    ///
+    /// assert_eq!(mean.get(), 2.0);
+    /// assert_eq!(var.get(), 1.0);
+    /// ```
+    pub fn mean_and_variance<I>(samples: I) -> Option<(Self, Quantity<Product<U, U>>)>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut n = 0.0_f64;
+        let mut mean = 0.0_f64;
+        let mut m2 = 0.0_f64;
+
+        for sample in samples {
+            n += 1.0;
+            let delta = sample.value - mean;
+            mean += delta / n;
+            m2 += delta * (sample.value - mean);
+        }
+
+        if n < 2.0 {
+            return None;
+        }
+
+        Some((Self::new(mean), Quantity::new(m2 / (n - 1.0))))
+    }
+}
+
+/// Offset between the Celsius and Kelvin scales.
+const CELSIUS_ZERO_IN_KELVIN: f64 = 273.15;
+
+impl Quantity<Degrees> {
+    /// The same angle in radians (`× π / 180`).
+    #[inline]
+    #[must_use]
+    pub fn to_radians(self) -> Quantity<Radians> {
+        Quantity::new(self.value.to_radians())
+    }
+}
+
+impl Quantity<Radians> {
+    /// The same angle in degrees (`× 180 / π`).
+    #[inline]
+    #[must_use]
+    pub fn to_degrees(self) -> Quantity<Degrees> {
+        Quantity::new(self.value.to_degrees())
+    }
+
+    /// Sine of the angle.
+    ///
+    /// Trigonometry is only offered on radians, so a [`Degrees`] quantity
+    /// has to go through [`Quantity::to_radians`] first.
+    ///
+    /// ```rust
+    /// use spatial_typestate::{Degrees, Quantity};
+    ///
+    /// let tilt = Quantity::<Degrees>::new(30.0);
+    /// assert!((tilt.to_radians().sin() - 0.5).abs() < 1e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> f64 {
+        math::sin(self.value)
+    }
+
+    /// Cosine of the angle.
+    #[inline]
+    #[must_use]
+    pub fn cos(self) -> f64 {
+        math::cos(self.value)
+    }
+
+    /// Tangent of the angle; very large near odd multiples of `π/2`.
+    #[inline]
+    #[must_use]
+    pub fn tan(self) -> f64 {
+        math::tan(self.value)
+    }
+
+    /// Sine and cosine of the angle, as `(sin, cos)`.
+    #[inline]
+    #[must_use]
+    pub fn sin_cos(self) -> (f64, f64) {
+        math::sin_cos(self.value)
+    }
+
+    /// The angle in `[-π/2, π/2]` whose sine is `x`; `NaN` unless
+    /// `-1 ≤ x ≤ 1`.
+    #[inline]
+    #[must_use]
+    pub fn asin(x: f64) -> Self {
+        Self::new(math::asin(x))
+    }
+
+    /// The angle in `[0, π]` whose cosine is `x`; `NaN` unless `-1 ≤ x ≤ 1`.
+    #[inline]
+    #[must_use]
+    pub fn acos(x: f64) -> Self {
+        Self::new(math::acos(x))
+    }
+
+    /// The angle in `[-π/2, π/2]` whose tangent is `x`.
+    #[inline]
+    #[must_use]
+    pub fn atan(x: f64) -> Self {
+        Self::new(math::atan(x))
+    }
+
+    /// The angle in `[-π, π]` of the point `(x, y)` from the `x`-axis.
+    #[inline]
+    #[must_use]
+    pub fn atan2(y: f64, x: f64) -> Self {
+        Self::new(math::atan2(y, x))
+    }
+}
+
+impl Quantity<Celsius> {
+    /// The same temperature in kelvin.
+    #[inline]
+    #[must_use]
+    pub fn to_kelvin(self) -> Quantity<Kelvin> {
+        self.convert_affine(1.0, CELSIUS_ZERO_IN_KELVIN)
+    }
+}
+
+impl Quantity<Kelvin> {
+    /// The same temperature in degrees Celsius.
+    #[inline]
+    #[must_use]
+    pub fn to_celsius(self) -> Quantity<Celsius> {
+        self.convert_affine(1.0, -CELSIUS_ZERO_IN_KELVIN)
+    }
+}
+
+// Like frame-tagged types, quantities implement their traits by hand so that
+// nothing is required of the unit marker.
+impl<U: Unit, T: Scalar> Clone for Quantity<U, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: Unit, T: Scalar> Copy for Quantity<U, T> {}
+
+// Comparisons only look at the value.
+impl<U: Unit, T: Scalar> PartialEq for Quantity<U, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<U: Unit, T: Scalar> PartialOrd for Quantity<U, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<U: Unit, T: Scalar> fmt::Debug for Quantity<U, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Quantity")
+            .field("value", &self.value)
+            .field("unit", &UnitName::<U>(PhantomData))
+            .finish()
+    }
+}
+
+/// `Debug` stand-in for a unit tag: prints the unit's type name without
+/// module paths.
+struct UnitName<U: Unit>(PhantomData<U>);
+
+impl<U: Unit> fmt::Debug for UnitName<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_short_type_name(f, core::any::type_name::<U>())
+    }
+}
+
+impl<U: Unit, T: Scalar> Add for Quantity<U, T> {
     type Output = Self;
 
     #[inline]
@@ -92,7 +668,102 @@ impl<U: Unit> Add for Quantity<U> {
     }
 }
 
-impl<U: Unit> Sub for Quantity<U> {
+/// Multiply two quantities; the unit follows [`UnitMul`].
+///
+/// ```rust
+/// use spatial_typestate::{Dimensionless, Meters, Quantity};
+///
+/// let gain = Quantity::<Dimensionless>::new(1.5);
+/// let scaled: Quantity<Meters> = Quantity::<Meters>::new(4.0) * gain;
+/// assert_eq!(scaled.get(), 6.0);
+/// ```
+impl<A: UnitMul<B>, B: Unit, T: Scalar> Mul<Quantity<B, T>> for Quantity<A, T> {
+    type Output = Quantity<A::Output, T>;
+
+    #[inline]
+    fn mul(self, rhs: Quantity<B, T>) -> Self::Output {
+        Quantity::new(self.value * rhs.value)
+    }
+}
+
+/// Divide two quantities; the unit follows [`UnitDiv`].
+///
+/// ```rust
+/// use spatial_typestate::{Dimensionless, Meters, Quantity};
+///
+/// let ratio: Quantity<Dimensionless> = Quantity::<Meters>::new(3.0) / Quantity::<Meters>::new(4.0);
+/// assert_eq!(ratio.get(), 0.75);
+/// ```
+impl<A: UnitDiv<B>, B: Unit, T: Scalar> Div<Quantity<B, T>> for Quantity<A, T> {
+    type Output = Quantity<A::Output, T>;
+
+    #[inline]
+    fn div(self, rhs: Quantity<B, T>) -> Self::Output {
+        Quantity::new(self.value / rhs.value)
+    }
+}
+
+/// Scale a quantity by a bare number, keeping its unit.
+impl<U: Unit> Mul<f64> for Quantity<U> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.value * rhs)
+    }
+}
+
+/// Scale a quantity by a bare number from the left, keeping its unit.
+impl<U: Unit> Mul<Quantity<U>> for f64 {
+    type Output = Quantity<U>;
+
+    #[inline]
+    fn mul(self, rhs: Quantity<U>) -> Self::Output {
+        Quantity::new(self * rhs.value)
+    }
+}
+
+/// Divide a quantity by a bare number, keeping its unit.
+impl<U: Unit> Div<f64> for Quantity<U> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.value / rhs)
+    }
+}
+
+/// Unwrap a pure number, e.g. the ratio of two quantities of the same unit.
+///
+/// ```rust
+/// use spatial_typestate::{Meters, Quantity};
+///
+/// let ratio: f64 = (Quantity::<Meters>::new(3.0) / Quantity::<Meters>::new(4.0)).into();
+/// assert_eq!(ratio, 0.75);
+/// ```
+impl From<Quantity<Dimensionless>> for f64 {
+    #[inline]
+    fn from(q: Quantity<Dimensionless>) -> Self {
+        q.value
+    }
+}
+
+/// Fold a sequence of scale factors into one. The empty product is `1`.
+impl core::iter::Product for Quantity<Dimensionless> {
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::new(iter.map(Quantity::get).product())
+    }
+}
+
+impl<'a> core::iter::Product<&'a Quantity<Dimensionless>> for Quantity<Dimensionless> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a Quantity<Dimensionless>>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<U: Unit, T: Scalar> Sub for Quantity<U, T> {
     type Output = Self;
 
     #[inline]
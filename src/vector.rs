@@ -13,31 +13,58 @@
 //!
 //! let v: Vector3<Body> = Vector3::new(0.0, 1.0, 0.0);
 //! ```
+//!
+//! Vectors in the same frame add, subtract and negate with the usual
+//! operators, and scale by a scalar with `*` and `/`. Adding vectors from
+//! different frames is a compile error.
+//!
+//! # Checked operations
+//!
+//! Operations that are undefined for some inputs (normalizing a zero vector,
+//! projecting onto one, measuring an angle against one) come as `try_`
+//! methods returning [`SpatialError`] instead of silently producing `NaN`.
+//! They all follow the same discipline as
+//! [`crate::quaternion::UnitQuat::try_from_components`]: non-finite inputs
+//! yield [`SpatialError::NonFinite`] first, and degenerate (zero-length)
+//! inputs then yield [`SpatialError::ZeroNormVector`].
 
+use core::fmt;
 use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
-use crate::frame::Frame;
+use crate::errors::SpatialError;
+use crate::frame::{assert_zero_sized, Frame, FrameName, RelabelSafe};
+use crate::math;
+use crate::scalar::Scalar;
+use crate::units::{Meters, Quantity, Radians};
 
 /// A 3D vector tagged with a coordinate frame `F`.
 ///
 /// Vectors typically represent directions, velocities, or differences between
 /// points. As with [`crate::point::Point3`], the frame is encoded in the
-/// type parameter.
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct Vector3<F: Frame> {
+/// type parameter, and the component type `T` defaults to `f64` (see
+/// [`crate::scalar`]).
+///
+/// Like [`crate::point::Point3`], the struct is `#[repr(C)]` so that
+/// [`Vector3::as_array`] can borrow the components in place.
+#[derive(PartialEq)]
+#[repr(C)]
+pub struct Vector3<F: Frame, T: Scalar = f64> {
     /// X component in frame `F`.
-    pub x: f64,
+    pub x: T,
     /// Y component in frame `F`.
-    pub y: f64,
+    pub y: T,
     /// Z component in frame `F`.
-    pub z: f64,
+    pub z: T,
     _frame: PhantomData<F>,
 }
 
-impl<F: Frame> Vector3<F> {
+impl<F: Frame, T: Scalar> Vector3<F, T> {
     /// Construct a new vector in the frame `F`.
     #[inline]
-    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+    #[must_use]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        assert_zero_sized::<F>();
         Self {
             x,
             y,
@@ -45,4 +72,368 @@ impl<F: Frame> Vector3<F> {
             _frame: PhantomData,
         }
     }
+
+    /// Construct from `[x, y, z]` components.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(xyz: [T; 3]) -> Self {
+        Self::new(xyz[0], xyz[1], xyz[2])
+    }
+
+    /// The components as `[x, y, z]`.
+    #[inline]
+    #[must_use]
+    pub const fn into_array(self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Borrow the components as `[x, y, z]` without copying, e.g. to hand
+    /// them to a graphics API or FFI buffer.
+    #[inline]
+    #[must_use]
+    pub fn as_array(&self) -> &[T; 3] {
+        crate::layout::vector_as_array(self)
+    }
+
+    /// Re-tag this vector as belonging to frame `G`, keeping its coordinates.
+    ///
+    /// Only available when `F` and `G` have been declared to be the same
+    /// frame via [`RelabelSafe`]. Use a transform for genuinely different
+    /// frames.
+    #[inline]
+    #[must_use]
+    pub fn relabel<G: Frame>(self) -> Vector3<G, T>
+    where
+        F: RelabelSafe<G>,
+    {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    /// Linear interpolation: `self` at `t = 0`, `other` at `t = 1`.
+    ///
+    /// Computed as `self · (1 − t) + other · t` component-wise, so both
+    /// endpoints are reproduced exactly. `t` is not clamped; values outside
+    /// `[0, 1]` extrapolate along the line through the two vectors.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        let s = T::ONE - t;
+        Self::new(
+            self.x * s + other.x * t,
+            self.y * s + other.y * t,
+            self.z * s + other.z * t,
+        )
+    }
+
+    /// Dot product `self · other`.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Squared Euclidean length.
+    #[inline]
+    #[must_use]
+    pub fn norm_squared(self) -> T {
+        self.dot(self)
+    }
+
+    /// Euclidean length.
+    #[inline]
+    #[must_use]
+    pub fn norm(self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    /// Cross product `self × other`.
+    ///
+    /// Both operands and the result live in frame `F`; the usual right-handed
+    /// convention applies (`x̂ × ŷ = ẑ`).
+    #[inline]
+    #[must_use]
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Unit vector pointing in the same direction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any component is not finite,
+    /// and [`SpatialError::ZeroNormVector`] if the length is too small to
+    /// normalize safely (at most the scalar's machine epsilon).
+    pub fn try_normalize(self) -> Result<Self, SpatialError> {
+        let norm = self.finite_norm()?;
+        Ok(Self::new(self.x / norm, self.y / norm, self.z / norm))
+    }
+
+    fn check_finite(self) -> Result<(), SpatialError> {
+        if !self.x.is_finite() || !self.y.is_finite() || !self.z.is_finite() {
+            return Err(SpatialError::NonFinite);
+        }
+
+        Ok(())
+    }
+
+    /// Length of a finite, non-degenerate vector.
+    fn finite_norm(self) -> Result<T, SpatialError> {
+        self.check_finite()?;
+
+        let norm = self.norm();
+        if norm <= T::EPSILON {
+            return Err(SpatialError::ZeroNormVector);
+        }
+
+        Ok(norm)
+    }
+}
+
+impl<F: Frame> Vector3<F> {
+    /// Euclidean length as a distance in meters.
+    ///
+    /// Use this when the vector is a physical displacement whose components
+    /// are in meters, so that the result carries its unit.
+    #[inline]
+    #[must_use]
+    pub fn norm_m(self) -> Quantity<Meters> {
+        Quantity::new(self.norm())
+    }
+
+    /// Two unit vectors that complete the direction of `self` to an
+    /// orthonormal basis.
+    ///
+    /// Returns `(t, b)` such that `(t, b, n)` is a right-handed orthonormal
+    /// basis, where `n` is `self` normalized (so `t × b = n`). Uses the
+    /// branchless construction of Duff et al., "Building an Orthonormal
+    /// Basis, Revisited" (2017), which stays accurate for every direction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if any component is not finite,
+    /// and [`SpatialError::ZeroNormVector`] if `self` is too short to have a
+    /// direction.
+    pub fn orthonormal_basis(self) -> Result<(Self, Self), SpatialError> {
+        let n = self.try_normalize()?;
+
+        let sign = 1.0_f64.copysign(n.z);
+        let a = -1.0 / (sign + n.z);
+        let b = n.x * n.y * a;
+
+        let tangent = Self::new(1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x);
+        let bitangent = Self::new(b, sign + n.y * n.y * a, -n.y);
+        Ok((tangent, bitangent))
+    }
+
+    /// Component of `self` along `onto`, i.e. `(self · onto / onto · onto) onto`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if either vector is not finite,
+    /// and [`SpatialError::ZeroNormVector`] if `onto` has (near) zero length,
+    /// since there is no direction to project onto.
+    pub fn try_project_onto(self, onto: Self) -> Result<Self, SpatialError> {
+        self.check_finite()?;
+        onto.finite_norm()?;
+
+        let scale = self.dot(onto) / onto.norm_squared();
+        Ok(Self::new(scale * onto.x, scale * onto.y, scale * onto.z))
+    }
+
+    /// Component of `self` perpendicular to `from`, i.e. `self` minus its
+    /// [projection](Vector3::try_project_onto) onto `from`.
+    ///
+    /// Together the two split `self` into parts along and across `from`, as
+    /// used by Gram–Schmidt or to remove the normal part of a velocity.
+    ///
+    /// # Errors
+    ///
+    /// Fails exactly when [`Vector3::try_project_onto`] does.
+    pub fn try_reject_from(self, from: Self) -> Result<Self, SpatialError> {
+        Ok(self - self.try_project_onto(from)?)
+    }
+
+    /// Unsigned angle between `self` and `other`, in `[0, π]`.
+    ///
+    /// Computed as `atan2(|a × b|, a · b)`, which stays accurate near `0`
+    /// and `π` where `acos` of a normalized dot product loses precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if either vector is not finite,
+    /// and [`SpatialError::ZeroNormVector`] if either has (near) zero length,
+    /// since a zero vector has no direction.
+    pub fn try_angle_to(self, other: Self) -> Result<Quantity<Radians>, SpatialError> {
+        self.finite_norm()?;
+        other.finite_norm()?;
+
+        Ok(Quantity::new(math::atan2(
+            self.cross(other).norm(),
+            self.dot(other),
+        )))
+    }
+
+    /// Unsigned angle between `self` and `other`, in `[0, π]`.
+    ///
+    /// Infallible form of [`Vector3::try_angle_to`], using the same
+    /// `atan2(|a × b|, a · b)` formula. If either vector is zero the result
+    /// is `0`; if either is not finite the result is NaN.
+    #[inline]
+    #[must_use]
+    pub fn angle_between(self, other: Self) -> Quantity<Radians> {
+        Quantity::new(math::atan2(self.cross(other).norm(), self.dot(other)))
+    }
+
+    /// Mirror `self` across the plane with unit normal `normal`, i.e.
+    /// `v - 2 (v · n) n`.
+    ///
+    /// `normal` is assumed to be unit length; the result is scaled
+    /// incorrectly otherwise. Only the component along the normal is flipped,
+    /// so a downward velocity reflected off an up-facing floor keeps its
+    /// horizontal motion and points upward.
+    #[inline]
+    #[must_use]
+    pub fn reflect(self, normal: Self) -> Self {
+        let k = 2.0 * self.dot(normal);
+        Self::new(
+            self.x - k * normal.x,
+            self.y - k * normal.y,
+            self.z - k * normal.z,
+        )
+    }
+
+    /// Scalar triple product `self · (b × c)`.
+    ///
+    /// This is the signed volume of the parallelepiped spanned by the three
+    /// vectors: positive when `(self, b, c)` is right-handed, zero when they
+    /// are coplanar.
+    #[inline]
+    #[must_use]
+    pub fn scalar_triple(self, b: Self, c: Self) -> f64 {
+        self.dot(b.cross(c))
+    }
+
+    /// Vector triple product `self × (b × c)`.
+    ///
+    /// The result lies in the plane spanned by `b` and `c`, and equals
+    /// `b (self · c) − c (self · b)`.
+    #[inline]
+    #[must_use]
+    pub fn vector_triple(self, b: Self, c: Self) -> Self {
+        self.cross(b.cross(c))
+    }
+
+    /// Cross product that rejects (nearly) collinear inputs.
+    ///
+    /// Parallel or anti-parallel vectors have a cross product close to zero,
+    /// whose direction is dominated by rounding noise. Using such a result as
+    /// a rotation axis or plane normal silently misbehaves, so this variant
+    /// refuses to return it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpatialError::NonFinite`] if the result is not finite, and
+    /// [`SpatialError::DegenerateInput`] if its magnitude is below `tol`.
+    pub fn try_cross(self, other: Self, tol: f64) -> Result<Self, SpatialError> {
+        let c = self.cross(other);
+        if !c.x.is_finite() || !c.y.is_finite() || !c.z.is_finite() {
+            return Err(SpatialError::NonFinite);
+        }
+
+        let norm_sq = c.x * c.x + c.y * c.y + c.z * c.z;
+        if norm_sq < tol * tol {
+            return Err(SpatialError::DegenerateInput);
+        }
+
+        Ok(c)
+    }
+}
+
+impl<F: Frame, T: Scalar> Clone for Vector3<F, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Frame, T: Scalar> Copy for Vector3<F, T> {}
+
+impl<F: Frame, T: Scalar> From<[T; 3]> for Vector3<F, T> {
+    #[inline]
+    fn from(xyz: [T; 3]) -> Self {
+        Self::from_array(xyz)
+    }
+}
+
+impl<F: Frame, T: Scalar> From<Vector3<F, T>> for [T; 3] {
+    #[inline]
+    fn from(v: Vector3<F, T>) -> Self {
+        v.into_array()
+    }
+}
+
+/// Component-wise sum of two vectors in the same frame.
+impl<F: Frame, T: Scalar> Add for Vector3<F, T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+/// Component-wise difference of two vectors in the same frame.
+impl<F: Frame, T: Scalar> Sub for Vector3<F, T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// The vector pointing the opposite way.
+impl<F: Frame, T: Scalar> Neg for Vector3<F, T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// Scale every component by `rhs`.
+impl<F: Frame, T: Scalar> Mul<T> for Vector3<F, T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// Divide every component by `rhs`; dividing by zero gives non-finite
+/// components, as for the bare scalars.
+impl<F: Frame, T: Scalar> Div<T> for Vector3<F, T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<F: Frame, T: Scalar> fmt::Debug for Vector3<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Vector3")
+            .field("frame", &FrameName::<F>::new())
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .finish()
+    }
 }
@@ -0,0 +1,52 @@
+//! Tests for the `approx` trait implementations.
+
+#![cfg(feature = "approx")]
+
+use approx_dep::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_ulps_eq};
+use spatial_typestate::{spatial_frames, Meters, Point3, Quantity, Transform, UnitQuat, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+#[test]
+fn points_and_vectors_compare_componentwise() {
+    let t: Transform<Body, World> = Transform::from_translation(0.1, 0.2, 0.3);
+    let p = t.apply_point(Point3::new(0.2, 0.1, 0.0));
+
+    assert_relative_eq!(p, Point3::new(0.3, 0.3, 0.3));
+    assert_ulps_eq!(p, Point3::new(0.3, 0.3, 0.3));
+    assert_abs_diff_ne!(p, Point3::new(0.3, 0.3, 0.31), epsilon = 1e-3);
+
+    let v = Vector3::<World, f32>::new(1.0, 2.0, 3.0);
+    assert_abs_diff_eq!(v, Vector3::new(1.0005, 2.0, 3.0), epsilon = 1e-3);
+}
+
+#[test]
+fn transforms_and_quantities_compare_componentwise() {
+    let a: Transform<Body, World> = Transform::from_translation(1.0, 0.0, 0.0);
+    let b =
+        Transform::<Body, Body>::from_translation(0.5, 0.0, 0.0)
+            .compose(&Transform::<Body, World>::from_translation(0.5, 1e-13, 0.0));
+    assert_abs_diff_eq!(a, b, epsilon = 1e-12);
+    assert_abs_diff_ne!(a, b, epsilon = 1e-14);
+
+    let d: Quantity<Meters> = Quantity::new(0.1) + Quantity::new(0.2);
+    assert_relative_eq!(d, Quantity::new(0.3));
+}
+
+#[test]
+fn quaternion_sign_only_matters_componentwise() {
+    let q = UnitQuat::<World>::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), Quantity::new(0.8))
+        .unwrap();
+    let minus_q = UnitQuat::<World>::new_unchecked(-q.x, -q.y, -q.z, -q.w);
+
+    assert_abs_diff_ne!(q, minus_q, epsilon = 1e-12);
+    assert!(q.rotation_abs_diff_eq(&minus_q, 1e-12));
+    assert!(q.rotation_abs_diff_eq(&q, 1e-12));
+
+    let other = UnitQuat::<World>::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), Quantity::new(0.9))
+        .unwrap();
+    assert!(!q.rotation_abs_diff_eq(&other, 1e-6));
+}
@@ -0,0 +1,43 @@
+//! Conversions between points/vectors and `[x, y, z]` arrays.
+
+use spatial_typestate::{spatial_frames, Point3, Vector3};
+
+spatial_frames! {
+    World,
+}
+
+#[test]
+fn point_array_conversions_round_trip_in_xyz_order() {
+    let p: Point3<World> = Point3::from_array([1.0, 2.0, 3.0]);
+    assert_eq!((p.x, p.y, p.z), (1.0, 2.0, 3.0));
+
+    assert_eq!(p.into_array(), [1.0, 2.0, 3.0]);
+    assert_eq!(p.as_array(), &[1.0, 2.0, 3.0]);
+
+    let q: Point3<World> = [1.0, 2.0, 3.0].into();
+    assert_eq!(q, p);
+    assert_eq!(<[f64; 3]>::from(q), [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn vector_array_conversions_round_trip_in_xyz_order() {
+    let v: Vector3<World> = Vector3::from_array([-4.0, 0.5, 9.0]);
+    assert_eq!((v.x, v.y, v.z), (-4.0, 0.5, 9.0));
+
+    assert_eq!(v.into_array(), [-4.0, 0.5, 9.0]);
+    assert_eq!(v.as_array(), &[-4.0, 0.5, 9.0]);
+
+    let w: Vector3<World> = [-4.0, 0.5, 9.0].into();
+    assert_eq!(w, v);
+    assert_eq!(<[f64; 3]>::from(w), [-4.0, 0.5, 9.0]);
+}
+
+#[test]
+fn as_array_borrows_in_place() {
+    let p: Point3<World, f32> = Point3::new(7.0, 8.0, 9.0);
+    let xyz = p.as_array();
+
+    assert!(core::ptr::eq(&xyz[0], &p.x));
+    assert!(core::ptr::eq(&xyz[1], &p.y));
+    assert!(core::ptr::eq(&xyz[2], &p.z));
+}
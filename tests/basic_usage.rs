@@ -5,7 +5,7 @@
 //! - Exercise the core types: Frame, Point3, Vector3, Transform, Quantity.
 
 use spatial_typestate::{
-    spatial_frames, Frame, Meters, Point3, Quantity, Radians, Transform, Vector3,
+    spatial_frames, Celsius, Degrees, Kelvin, Meters, Point3, Quantity, Radians, Transform, Vector3,
 };
 
 spatial_frames! {
@@ -55,3 +55,175 @@ fn quantities_add_and_subtract_with_same_unit() {
     let angle: Quantity<Radians> = Quantity::new(core::f64::consts::FRAC_PI_2);
     assert!(angle.get() > 0.0);
 }
+
+#[test]
+fn quantity_mean_and_sample_variance() {
+    use spatial_typestate::Product;
+
+    // Hand-computed: mean = 5, squared deviations sum to 32, n - 1 = 7.
+    let samples = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].map(Quantity::<Meters>::new);
+
+    let (mean, var): (Quantity<Meters>, Quantity<Product<Meters, Meters>>) =
+        Quantity::mean_and_variance(samples).unwrap();
+
+    assert!((mean.get() - 5.0).abs() < 1e-12);
+    assert!((var.get() - 32.0 / 7.0).abs() < 1e-12);
+
+    assert!(Quantity::<Meters>::mean_and_variance([Quantity::new(1.0)]).is_none());
+}
+
+#[test]
+fn quantity_rounds_to_increment() {
+    let length = Quantity::<Meters>::new(1.27).round_to(Quantity::new(0.1));
+    assert!((length.get() - 1.3).abs() < 1e-12);
+
+    let angle = Quantity::<Radians>::new(-0.74).round_to(Quantity::new(0.25));
+    assert!((angle.get() + 0.75).abs() < 1e-12);
+
+    let unchanged = Quantity::<Meters>::new(1.27).round_to(Quantity::new(0.0));
+    assert_eq!(unchanged.get(), 1.27);
+}
+
+#[test]
+fn degrees_and_radians_convert_explicitly() {
+    let half_turn = Quantity::<Degrees>::new(180.0);
+
+    let radians: Quantity<Radians> = half_turn.to_radians();
+    assert!((radians.get() - core::f64::consts::PI).abs() < 1e-12);
+
+    let back: Quantity<Degrees> = radians.to_degrees();
+    assert!((back.get() - 180.0).abs() < 1e-12);
+}
+
+#[test]
+fn celsius_and_kelvin_convert_with_offset() {
+    let freezing = Quantity::<Celsius>::new(0.0);
+
+    let kelvin: Quantity<Kelvin> = freezing.to_kelvin();
+    assert!((kelvin.get() - 273.15).abs() < 1e-12);
+
+    let back: Quantity<Celsius> = kelvin.to_celsius();
+    assert!(back.get().abs() < 1e-12);
+}
+
+#[test]
+fn quantity_clamp_reports_which_bound_was_hit() {
+    use spatial_typestate::units::ClampResult;
+
+    let lo = Quantity::<Meters>::new(-1.0);
+    let hi = Quantity::<Meters>::new(2.0);
+
+    let (v, hit) = Quantity::new(0.5).clamp_reporting(lo, hi);
+    assert_eq!((v.get(), hit), (0.5, ClampResult::InRange));
+
+    let (v, hit) = Quantity::new(-3.0).clamp_reporting(lo, hi);
+    assert_eq!((v.get(), hit), (-1.0, ClampResult::ClampedLow));
+
+    let (v, hit) = Quantity::new(7.5).clamp_reporting(lo, hi);
+    assert_eq!((v.get(), hit), (2.0, ClampResult::ClampedHigh));
+}
+
+#[test]
+fn dimensionless_scale_factors_multiply() {
+    use spatial_typestate::Dimensionless;
+
+    let gains = [2.0, 0.5, 3.0, 4.0].map(Quantity::<Dimensionless>::new);
+
+    let total: Quantity<Dimensionless> = gains.iter().product();
+    assert_eq!(total.get(), 12.0);
+
+    let owned: Quantity<Dimensionless> = gains.into_iter().product();
+    assert_eq!(owned.get(), 12.0);
+
+    let empty: Quantity<Dimensionless> = core::iter::empty::<Quantity<Dimensionless>>().product();
+    assert_eq!(empty.get(), 1.0);
+}
+
+#[test]
+fn dimensionless_is_the_multiplicative_identity_unit() {
+    use spatial_typestate::Dimensionless;
+
+    let ratio: Quantity<Dimensionless> =
+        Quantity::<Meters>::new(6.0) / Quantity::<Meters>::new(4.0);
+    assert_eq!(ratio.get(), 1.5);
+
+    let scaled: Quantity<Meters> = Quantity::<Meters>::new(2.0) * ratio;
+    assert_eq!(scaled.get(), 3.0);
+
+    let angle: Quantity<Radians> = Quantity::<Radians>::new(0.5) * ratio;
+    assert_eq!(angle.get(), 0.75);
+}
+
+#[test]
+fn weighted_centroid_leans_toward_heavier_point() {
+    use spatial_typestate::SpatialError;
+
+    let light = Point3::<World>::new(0.0, 0.0, 0.0);
+    let heavy = Point3::<World>::new(4.0, 0.0, 0.0);
+
+    let c = Point3::weighted_centroid(&[(light, 1.0), (heavy, 3.0)]).unwrap();
+    assert!((c.x - 3.0).abs() < 1e-12);
+    assert_eq!((c.y, c.z), (0.0, 0.0));
+
+    assert_eq!(
+        Point3::weighted_centroid(&[(light, 0.0), (heavy, 0.0)]),
+        Err(SpatialError::DegenerateInput)
+    );
+    assert_eq!(
+        Point3::weighted_centroid(&[(light, f64::NAN)]),
+        Err(SpatialError::NonFinite)
+    );
+}
+
+#[test]
+fn distance_between_points_is_symmetric_and_in_meters() {
+    let a: Point3<World> = Point3::new(0.0, 0.0, 0.0);
+    let b: Point3<World> = Point3::new(3.0, 4.0, 0.0);
+
+    let d: Quantity<Meters> = a.distance(b);
+    assert_eq!(d.get(), 5.0);
+    assert_eq!(b.distance(a), d);
+    assert_eq!(a.distance_squared(b).get(), 25.0);
+}
+
+#[test]
+fn quantities_scale_by_bare_numbers() {
+    let length = Quantity::<Meters>::new(10.0);
+
+    assert_eq!(length * 2.0, Quantity::new(20.0));
+    assert_eq!(2.0 * length, Quantity::new(20.0));
+    assert_eq!(length / 4.0, Quantity::new(2.5));
+
+    // Same-unit division leaves a pure ratio.
+    let ratio: f64 = (length / Quantity::<Meters>::new(4.0)).into();
+    assert_eq!(ratio, 2.5);
+}
+
+#[test]
+fn quantities_of_the_same_unit_are_ordered() {
+    let short = Quantity::<Meters>::new(1.5);
+    let long = Quantity::<Meters>::new(4.0);
+
+    assert!(short < long);
+    assert!(long >= short);
+    assert_eq!(short.partial_cmp(&Quantity::new(f64::NAN)), None);
+
+    assert_eq!(short.min(long), short);
+    assert_eq!(short.max(long), long);
+    assert_eq!(Quantity::<Meters>::new(-2.5).abs(), Quantity::new(2.5));
+
+    // Units without `PartialEq` still compare, since only the value matters.
+    struct Furlongs;
+    impl spatial_typestate::units::Unit for Furlongs {}
+    assert!(Quantity::<Furlongs>::new(1.0) < Quantity::new(2.0));
+}
+
+#[test]
+fn quantity_clamp_uses_bounds_of_the_same_unit() {
+    let lo = Quantity::<Radians>::new(-0.5);
+    let hi = Quantity::<Radians>::new(0.5);
+
+    assert_eq!(Quantity::new(0.8).clamp(lo, hi), hi);
+    assert_eq!(Quantity::new(-1.2).clamp(lo, hi), lo);
+    assert_eq!(Quantity::new(0.1).clamp(lo, hi), Quantity::new(0.1));
+}
@@ -0,0 +1,47 @@
+//! Frame-tagged types are `Copy` even when the frame marker is not.
+
+use spatial_typestate::{
+    Aabb, AngularVelocity3, Displacement, Frame, FrameChain, IsometryTransform, Plane, Point3,
+    Pose, Ray, Rotation3, Segment, Transform, UnitQuat, Vector3, Velocity3,
+};
+
+/// A marker with no derives at all.
+struct World;
+struct Body;
+
+impl Frame for World {}
+impl Frame for Body {}
+
+fn assert_copy<T: Copy>() {}
+
+#[test]
+fn frame_tagged_types_are_copy_for_bare_markers() {
+    assert_copy::<Point3<World>>();
+    assert_copy::<Point3<World, f32>>();
+    assert_copy::<Vector3<World>>();
+    assert_copy::<Displacement<World>>();
+    assert_copy::<UnitQuat<World>>();
+    assert_copy::<UnitQuat<World, f32>>();
+    assert_copy::<Transform<Body, World>>();
+    assert_copy::<Transform<Body, World, f32>>();
+    assert_copy::<Rotation3<Body, World>>();
+    assert_copy::<IsometryTransform<Body, World>>();
+    assert_copy::<Pose<World, Body>>();
+    assert_copy::<FrameChain<(Body, World)>>();
+    assert_copy::<Aabb<World>>();
+    assert_copy::<Plane<World>>();
+    assert_copy::<Ray<World>>();
+    assert_copy::<Segment<World>>();
+    assert_copy::<Velocity3<World>>();
+    assert_copy::<AngularVelocity3<World>>();
+}
+
+#[test]
+fn copies_leave_the_original_usable() {
+    let pose = Pose::from_transform(Transform::<Body, World>::from_translation(1.0, 2.0, 3.0));
+    let copy = pose;
+
+    let a = Transform::from(copy).apply_point(Point3::new(0.0, 0.0, 0.0));
+    let b = Transform::from(pose).apply_point(Point3::new(0.0, 0.0, 0.0));
+    assert_eq!((a.x, a.y, a.z), (b.x, b.y, b.z));
+}
@@ -0,0 +1,75 @@
+//! Tests for the hand-written `Debug` output of frame-tagged types.
+
+use spatial_typestate::{
+    spatial_frames, Frame, Meters, Point3, Product, Quantity, Transform, UnitQuat, Vector3,
+};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+/// A marker implemented by hand, without setting `NAME`.
+struct Unnamed;
+impl Frame for Unnamed {}
+
+#[test]
+fn debug_output_names_frames_without_phantom_data() {
+    let outputs = [
+        format!("{:?}", Point3::<World>::new(1.0, 2.0, 3.0)),
+        format!("{:?}", Vector3::<World>::new(1.0, 2.0, 3.0)),
+        format!("{:?}", UnitQuat::<World>::identity()),
+        format!("{:?}", Transform::<Body, World>::identity()),
+    ];
+
+    for out in &outputs {
+        assert!(out.contains("World"), "{out}");
+        assert!(!out.contains("PhantomData"), "{out}");
+    }
+
+    assert_eq!(
+        outputs[0],
+        "Point3 { frame: World, x: 1.0, y: 2.0, z: 3.0 }"
+    );
+    assert!(outputs[3].starts_with("Transform { from: Body, to: World, matrix: "));
+}
+
+#[test]
+fn debug_output_falls_back_to_type_name() {
+    let out = format!("{:?}", Point3::<Unnamed>::new(0.0, 0.0, 0.0));
+
+    assert!(out.starts_with("Point3 { frame: Unnamed, "), "{out}");
+}
+
+#[test]
+fn quantity_debug_shows_unit_without_module_paths() {
+    let area: Quantity<Product<Meters, Meters>> = Quantity::new(2.5);
+
+    assert_eq!(
+        format!("{area:?}"),
+        "Quantity { value: 2.5, unit: Product<Meters, Meters> }"
+    );
+}
+
+#[test]
+fn display_prints_frames_and_aligned_rows() {
+    let t = Transform::<Body, World>::from_translation(10.0, -2.5, 0.0);
+    let out = format!("{t}");
+    let lines: Vec<&str> = out.lines().collect();
+
+    assert_eq!(lines.len(), 5, "{out}");
+    assert_eq!(lines[0], "Transform Body -> World");
+    assert_eq!(lines[1], "[1.0000  0.0000  0.0000  10.0000]");
+    assert_eq!(lines[2], "[0.0000  1.0000  0.0000  -2.5000]");
+    assert_eq!(lines[3], "[0.0000  0.0000  1.0000   0.0000]");
+    assert_eq!(lines[4], "[0.0000  0.0000  0.0000   1.0000]");
+}
+
+#[test]
+fn display_honors_precision_and_falls_back_to_type_names() {
+    let t = Transform::<Unnamed, World>::identity();
+    let out = format!("{t:.1}");
+
+    assert!(out.starts_with("Transform Unnamed -> World\n"), "{out}");
+    assert!(out.ends_with("[0.0  0.0  0.0  1.0]"), "{out}");
+}
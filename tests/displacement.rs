@@ -0,0 +1,36 @@
+//! Tests for unit-carrying displacements and affine point arithmetic.
+
+use spatial_typestate::{spatial_frames, Displacement, Point3, Vector3};
+
+spatial_frames! {
+    World,
+}
+
+#[test]
+fn adding_a_displacement_moves_the_point() {
+    let p = Point3::<World>::new(1.0, 2.0, 3.0);
+    let d = Displacement(Vector3::new(3.0, 4.0, 0.0));
+
+    let q = p + d;
+
+    assert_eq!(q, Point3::new(4.0, 6.0, 3.0));
+    assert_eq!(d.length().get(), 5.0);
+}
+
+#[test]
+fn difference_of_points_is_the_vector_between_them() {
+    let a = Point3::<World>::new(1.0, 2.0, 3.0);
+    let b = Point3::<World>::new(4.0, 6.0, 3.0);
+
+    let v: Vector3<World> = b - a;
+
+    assert_eq!(v, Vector3::new(3.0, 4.0, 0.0));
+    assert_eq!(a + v, b);
+    assert_eq!(b - v, a);
+
+    // Wrapping the difference carries its unit along.
+    let d = Displacement(b - a);
+    assert_eq!(d.length().get(), 5.0);
+    assert_eq!(a + d, b);
+    assert_eq!(b - d, a);
+}
@@ -0,0 +1,34 @@
+//! Tests for transforms between runtime-identified frames.
+
+use spatial_typestate::dynamic::{DynFrame, DynFrameId, DynTransform};
+use spatial_typestate::{Point3, SpatialError, Transform};
+
+const LIDAR: DynFrameId = DynFrameId(0);
+const BODY: DynFrameId = DynFrameId(1);
+const WORLD: DynFrameId = DynFrameId(2);
+
+#[test]
+fn matching_middle_frames_compose() {
+    let lidar_to_body = DynTransform::new(LIDAR, BODY, Transform::from_translation(0.0, 0.0, 1.0));
+    let body_to_world = DynTransform::new(BODY, WORLD, Transform::from_translation(5.0, 0.0, 0.0));
+
+    let lidar_to_world = lidar_to_body.try_compose(&body_to_world).unwrap();
+
+    assert_eq!(lidar_to_world.from(), LIDAR);
+    assert_eq!(lidar_to_world.to(), WORLD);
+    let p = lidar_to_world
+        .transform()
+        .apply_point(Point3::<DynFrame>::new(0.0, 0.0, 0.0));
+    assert_eq!((p.x, p.y, p.z), (5.0, 0.0, 1.0));
+}
+
+#[test]
+fn mismatched_middle_frames_are_rejected() {
+    let lidar_to_body = DynTransform::new(LIDAR, BODY, Transform::identity());
+    let lidar_to_world = DynTransform::new(LIDAR, WORLD, Transform::identity());
+
+    assert_eq!(
+        lidar_to_body.try_compose(&lidar_to_world),
+        Err(SpatialError::FrameMismatch)
+    );
+}
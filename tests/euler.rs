@@ -0,0 +1,120 @@
+//! Tests for Euler-angle conversions.
+
+use core::f64::consts::FRAC_PI_2;
+
+use spatial_typestate::euler::EulerOrder;
+use spatial_typestate::{spatial_frames, Point3, Quantity, Transform, UnitQuat, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+const ORDERS: [EulerOrder; 6] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+];
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+fn assert_same_rotation(a: &UnitQuat<World>, b: &UnitQuat<World>) {
+    let dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+    assert!(approx_eq(dot.abs(), 1.0, 1e-12), "{a:?} vs {b:?}");
+}
+
+#[test]
+fn every_order_round_trips_away_from_gimbal_lock() {
+    let angles = (0.7, -0.4, 2.5);
+
+    for order in ORDERS {
+        let q = UnitQuat::<World>::from_euler(
+            order,
+            Quantity::new(angles.0),
+            Quantity::new(angles.1),
+            Quantity::new(angles.2),
+        );
+        let (a, b, c) = q.to_euler(order);
+
+        assert!(approx_eq(a.get(), angles.0, 1e-12), "{order:?}");
+        assert!(approx_eq(b.get(), angles.1, 1e-12), "{order:?}");
+        assert!(approx_eq(c.get(), angles.2, 1e-12), "{order:?}");
+    }
+}
+
+#[test]
+fn zyx_is_intrinsic_yaw_pitch_roll() {
+    let (yaw, pitch, roll) = (0.4, -0.3, 0.9);
+    let q = UnitQuat::<World>::from_euler(
+        EulerOrder::ZYX,
+        Quantity::new(yaw),
+        Quantity::new(pitch),
+        Quantity::new(roll),
+    );
+
+    let about = |axis: Vector3<World>, angle: f64| {
+        UnitQuat::from_axis_angle(axis, Quantity::new(angle)).unwrap()
+    };
+    let expected = about(Vector3::new(0.0, 0.0, 1.0), yaw)
+        * about(Vector3::new(0.0, 1.0, 0.0), pitch)
+        * about(Vector3::new(1.0, 0.0, 0.0), roll);
+
+    assert_same_rotation(&q, &expected);
+}
+
+#[test]
+fn gimbal_lock_folds_rotation_into_first_angle() {
+    for order in ORDERS {
+        for pitch in [FRAC_PI_2, -FRAC_PI_2] {
+            let q = UnitQuat::<World>::from_euler(
+                order,
+                Quantity::new(0.5),
+                Quantity::new(pitch),
+                Quantity::new(0.2),
+            );
+            let (a, b, c) = q.to_euler(order);
+
+            assert_eq!(c.get(), 0.0);
+            assert!(approx_eq(b.get(), pitch, 1e-6), "{order:?}");
+            assert_same_rotation(&UnitQuat::from_euler(order, a, b, c), &q);
+        }
+    }
+}
+
+#[test]
+fn transform_from_euler_matches_the_quaternion_version() {
+    let (a, b, c) = (Quantity::new(0.7), Quantity::new(-0.4), Quantity::new(2.5));
+
+    for order in ORDERS {
+        let direct = Transform::<Body, World>::from_euler(order, a, b, c);
+        let via_quat = Transform::<Body, World>::from_quaternion_translation(
+            UnitQuat::from_euler(order, a, b, c),
+            0.0,
+            0.0,
+            0.0,
+        );
+
+        for (row, expected) in direct.matrix.iter().zip(via_quat.matrix.iter()) {
+            for (x, y) in row.iter().zip(expected.iter()) {
+                assert!(approx_eq(*x, *y, 1e-12), "{order:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn pure_yaw_rotates_about_the_vertical_axis() {
+    let zero = Quantity::new(0.0);
+    let yaw: Transform<Body, World> =
+        Transform::from_euler(EulerOrder::ZYX, Quantity::new(FRAC_PI_2), zero, zero);
+
+    let p = yaw.apply_point(Point3::new(1.0, 0.0, 2.0));
+    assert!(approx_eq(p.x, 0.0, 1e-12));
+    assert!(approx_eq(p.y, 1.0, 1e-12));
+    assert!(approx_eq(p.z, 2.0, 1e-12));
+}
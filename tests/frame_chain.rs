@@ -0,0 +1,68 @@
+//! Tests for `FrameChain` pipeline composition and fallible chaining.
+
+use spatial_typestate::{spatial_frames, FrameChain, Point3, SpatialError, Transform, Vector3};
+
+spatial_frames! {
+    Sensor,
+    Body,
+    World,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[test]
+fn well_formed_chain_composes_hops_in_order() {
+    // Sensor is yawed 90° on the body; the body sits 10 m along world x.
+    let sensor_to_body: Transform<Sensor, Body> = Transform::from_matrix([
+        [0.0, -1.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.5],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    let body_to_world: Transform<Body, World> = Transform::from_translation(10.0, 0.0, 0.0);
+
+    let sensor_to_world: Transform<Sensor, World> =
+        <FrameChain![Sensor, Body, World]>::compose((sensor_to_body, body_to_world));
+
+    let p_sensor = Point3::<Sensor>::new(1.0, 0.0, 0.0);
+    let direct = sensor_to_world.apply_point(p_sensor);
+    let stepwise = body_to_world.apply_point(sensor_to_body.apply_point(p_sensor));
+
+    assert!(approx_eq(direct.x, 10.0, 1e-12));
+    assert!(approx_eq(direct.y, 1.0, 1e-12));
+    assert!(approx_eq(direct.z, 0.5, 1e-12));
+    assert_eq!(direct, stepwise);
+}
+
+#[test]
+fn single_hop_chain_is_the_hop_itself() {
+    let t: Transform<Sensor, Body> = Transform::from_translation(1.0, 2.0, 3.0);
+
+    assert_eq!(<FrameChain![Sensor, Body]>::compose((t,)), t);
+}
+
+#[test]
+fn fallible_link_propagates_its_error() {
+    let sensor_to_body: Transform<Sensor, Body> = Transform::from_translation(0.0, 0.0, 1.0);
+
+    let good = Transform::<Body, World>::from_basis(
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(-1.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        Point3::new(10.0, 0.0, 0.0),
+    );
+    let sensor_to_world = sensor_to_body.try_then(good).unwrap();
+    let p = sensor_to_world.apply_point(Point3::new(0.0, 0.0, 0.0));
+    assert_eq!((p.x, p.y, p.z), (10.0, 0.0, 1.0));
+
+    // A left-handed basis fails to build; the chain reports why.
+    let bad = Transform::<Body, World>::from_basis(
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, -1.0),
+        Point3::new(0.0, 0.0, 0.0),
+    );
+    assert_eq!(sensor_to_body.try_then(bad), Err(SpatialError::NotRigid));
+}
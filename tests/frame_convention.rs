@@ -0,0 +1,65 @@
+//! Tests for frame handedness and up-axis metadata.
+
+use spatial_typestate::{spatial_frames, Axis, Frame, FrameConvention, FrameSet, Handedness};
+
+spatial_frames! {
+    World,
+    #[frame(handedness = Left)]
+    Unity,
+    #[frame(up = NegZ)]
+    Ned,
+    #[frame(up = Y, handedness = Left)]
+    Unreal,
+}
+
+spatial_frames! {
+    pub type Imported = (#[frame(handedness = Left, up = Y)] Mesh, Scan);
+}
+
+struct Manual;
+impl Frame for Manual {
+    const HANDEDNESS: Handedness = Handedness::Left;
+}
+
+#[test]
+fn defaults_are_right_handed_z_up() {
+    assert_eq!(World::HANDEDNESS, Handedness::Right);
+    assert_eq!(World::UP_AXIS, Axis::Z);
+    assert_eq!(
+        FrameConvention::of::<Scan>(),
+        FrameConvention {
+            handedness: Handedness::Right,
+            up_axis: Axis::Z,
+        }
+    );
+}
+
+#[test]
+fn left_handed_frame_reports_correctly() {
+    let unity = FrameConvention::of::<Unity>();
+    assert_eq!(unity.handedness, Handedness::Left);
+    assert_eq!(unity.up_axis, Axis::Z);
+
+    assert_eq!(FrameConvention::of::<Manual>().handedness, Handedness::Left);
+}
+
+#[test]
+fn settings_are_independent_and_unordered() {
+    assert_eq!(Ned::HANDEDNESS, Handedness::Right);
+    assert_eq!(Ned::UP_AXIS, Axis::NegZ);
+
+    assert_eq!(Unreal::HANDEDNESS, Handedness::Left);
+    assert_eq!(Unreal::UP_AXIS, Axis::Y);
+}
+
+#[test]
+fn frame_set_members_accept_settings() {
+    assert_eq!(Imported::NAMES, ["Mesh", "Scan"]);
+    assert_eq!(
+        FrameConvention::of::<Mesh>(),
+        FrameConvention {
+            handedness: Handedness::Left,
+            up_axis: Axis::Y,
+        }
+    );
+}
@@ -0,0 +1,134 @@
+//! Tests for `FrameGraph`, the owned runtime frame tree.
+
+#![cfg(feature = "std")]
+
+use core::f64::consts::FRAC_PI_2;
+
+use spatial_typestate::{
+    spatial_frames, Frame, FrameGraph, FrameId, Point3, Quantity, SpatialError, Transform,
+};
+
+spatial_frames! {
+    World,
+    Body,
+    Camera,
+    Lidar,
+    Detached,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+/// `Camera` → `Body` → `World` and `Lidar` → `Body`; `Detached` is unknown.
+fn rig() -> FrameGraph {
+    let turn: Transform<Body, Body> = Transform::rotation_z(Quantity::new(FRAC_PI_2));
+    let body_to_world = turn.compose(&Transform::<Body, World>::from_translation(10.0, 0.0, 0.0));
+
+    let mut graph = FrameGraph::new();
+    graph.insert(&body_to_world);
+    graph.insert(&Transform::<Camera, Body>::from_translation(0.0, 0.0, 1.0));
+    graph.insert(&Transform::<Lidar, Body>::from_translation(1.0, 0.0, 0.0));
+    graph
+}
+
+#[test]
+fn multi_hop_lookup_composes_edges() {
+    let camera_to_world = rig().transform::<Camera, World>().unwrap();
+
+    let p = camera_to_world.apply_point(Point3::new(1.0, 0.0, 0.0));
+    assert!(approx_eq(p.x, 10.0, 1e-12));
+    assert!(approx_eq(p.y, 1.0, 1e-12));
+    assert!(approx_eq(p.z, 1.0, 1e-12));
+}
+
+#[test]
+fn sibling_lookup_goes_through_the_common_parent() {
+    let graph = rig();
+    let camera_to_lidar = graph.transform::<Camera, Lidar>().unwrap();
+    let lidar_to_camera = graph.transform::<Lidar, Camera>().unwrap();
+
+    let p = camera_to_lidar.apply_point(Point3::new(0.0, 0.0, 0.0));
+    assert!(approx_eq(p.x, -1.0, 1e-12));
+    assert!(approx_eq(p.y, 0.0, 1e-12));
+    assert!(approx_eq(p.z, 1.0, 1e-12));
+
+    let back = lidar_to_camera.apply_point(p);
+    assert!(approx_eq(back.x, 0.0, 1e-12));
+    assert!(approx_eq(back.y, 0.0, 1e-12));
+    assert!(approx_eq(back.z, 0.0, 1e-12));
+}
+
+#[test]
+fn unconnected_frames_have_no_path() {
+    let graph = rig();
+
+    assert!(!graph.has_parent::<Detached>());
+    assert_eq!(
+        graph.transform::<Camera, Detached>(),
+        Err(SpatialError::NoTransformPath)
+    );
+}
+
+#[test]
+fn reinserting_a_child_replaces_its_parent_edge() {
+    let mut graph = rig();
+    graph.insert(&Transform::<Camera, Body>::from_translation(0.0, 0.0, 2.0));
+
+    let p = graph
+        .transform::<Camera, Body>()
+        .unwrap()
+        .apply_point(Point3::new(0.0, 0.0, 0.0));
+    assert!(approx_eq(p.z, 2.0, 1e-12));
+}
+
+#[test]
+fn frames_are_keyed_by_module_qualified_id() {
+    mod left {
+        spatial_typestate::spatial_frames! {
+            Mount,
+        }
+    }
+    mod right {
+        spatial_typestate::spatial_frames! {
+            Mount,
+        }
+    }
+
+    assert_eq!(Lidar::ID, concat!(module_path!(), "::Lidar"));
+    assert_ne!(left::Mount::ID, right::Mount::ID);
+
+    let mut graph = rig();
+    graph.insert(&Transform::<left::Mount, Body>::from_translation(
+        0.0, 1.0, 0.0,
+    ));
+    graph.insert(&Transform::<right::Mount, Body>::from_translation(
+        0.0, -1.0, 0.0,
+    ));
+
+    let p = graph
+        .transform::<left::Mount, right::Mount>()
+        .unwrap()
+        .apply_point(Point3::new(0.0, 0.0, 0.0));
+    assert!(approx_eq(p.y, 2.0, 1e-12));
+}
+
+#[test]
+fn hand_written_frame_ids_take_part() {
+    struct Imu;
+
+    impl Frame for Imu {}
+    impl FrameId for Imu {
+        const ID: &'static str = "imu";
+    }
+
+    let mut graph = rig();
+    graph.insert(&Transform::<Imu, Body>::from_translation(0.0, 0.0, -1.0));
+
+    assert!(graph.has_parent::<Imu>());
+    let p = graph
+        .transform::<Imu, Camera>()
+        .unwrap()
+        .apply_point(Point3::new(0.0, 0.0, 0.0));
+    assert!(approx_eq(p.z, -2.0, 1e-12));
+}
@@ -0,0 +1,31 @@
+//! Tests for compile-time frame sets.
+
+use spatial_typestate::{spatial_frames, Frame, FrameSet};
+
+spatial_frames! {
+    pub type Sensors = (Lidar, Camera, Imu);
+}
+
+spatial_frames! {
+    World,
+}
+
+fn describe<S: FrameSet>() -> String {
+    S::NAMES.join(", ")
+}
+
+#[test]
+fn generated_set_exposes_length_and_names() {
+    assert_eq!(Sensors::LEN, 3);
+    assert_eq!(Sensors::NAMES, ["Lidar", "Camera", "Imu"]);
+    assert_eq!(describe::<Sensors>(), "Lidar, Camera, Imu");
+
+    // The members are ordinary frames.
+    assert_eq!(Camera::NAME, "Camera");
+}
+
+#[test]
+fn ad_hoc_tuples_are_sets_too() {
+    assert_eq!(<(World, Imu)>::LEN, 2);
+    assert_eq!(<(World, Imu)>::NAMES, ["World", "Imu"]);
+}
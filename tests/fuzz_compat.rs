@@ -6,7 +6,7 @@
 //! The idea is to keep the "what should never panic" logic centralized so
 //! fuzzing and regular tests share behavior.
 
-use spatial_typestate::{spatial_frames, Frame, Point3, Transform};
+use spatial_typestate::{spatial_frames, Point3, Transform};
 
 spatial_frames! {
     World,
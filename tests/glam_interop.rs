@@ -0,0 +1,121 @@
+//! Tests for the `glam` interop.
+
+#![cfg(feature = "glam")]
+
+use glam_dep::{Affine3A, DAffine3, DMat4, DQuat, DVec3, Mat4, Quat, Vec3};
+use spatial_typestate::{
+    spatial_frames, Point3, Quantity, SpatialError, Transform, UnitQuat, Vector3,
+};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn sample_transform() -> Transform<Body, World> {
+    let axis = Vector3::<Body>::new(1.0, 2.0, -0.5);
+    let q = UnitQuat::from_axis_angle(axis, Quantity::new(0.9)).unwrap();
+    Transform::from_quaternion_translation(q, 1.0, -2.0, 0.5)
+}
+
+#[test]
+fn points_and_vectors_convert_at_their_own_precision() {
+    let p = Point3::<World, f32>::new(1.0, -2.0, 3.5);
+    let v: Vec3 = p.into();
+    assert_eq!((v.x, v.y, v.z), (1.0, -2.0, 3.5));
+    assert_eq!(Point3::<World, f32>::from(v), p);
+
+    let d = Vector3::<World>::new(0.1, 0.2, 0.3);
+    let dv: DVec3 = d.into();
+    assert_eq!(Vector3::<World>::from(dv), d);
+}
+
+#[test]
+fn f64_transforms_round_trip_exactly() {
+    let t = sample_transform();
+
+    let matrix: DMat4 = t.into();
+    assert_eq!(Transform::<Body, World>::from(matrix), t);
+
+    let affine: DAffine3 = t.try_into().unwrap();
+    assert_eq!(Transform::<Body, World>::from(affine), t);
+
+    let p = Point3::<Body>::new(0.3, -1.2, 2.0);
+    let expected: DVec3 = t.apply_point(p).into();
+    assert_eq!(matrix.transform_point3(p.into()), expected);
+}
+
+#[test]
+fn f32_transforms_apply_like_glam_within_f32_tolerance() {
+    let mut m = [[0.0_f32; 4]; 4];
+    for (row, r) in sample_transform().matrix.iter().enumerate() {
+        for (col, v) in r.iter().enumerate() {
+            m[row][col] = *v as f32;
+        }
+    }
+    let t: Transform<Body, World, f32> = Transform::from_matrix(m);
+    let p = Point3::<Body, f32>::new(0.3, -1.2, 2.0);
+    let ours = t.apply_point(p);
+
+    let matrix: Mat4 = t.into();
+    let affine: Affine3A = t.try_into().unwrap();
+    for q in [
+        matrix.transform_point3(p.into()),
+        affine.transform_point3(p.into()),
+    ] {
+        assert!((q.x - ours.x).abs() <= 1e-5);
+        assert!((q.y - ours.y).abs() <= 1e-5);
+        assert!((q.z - ours.z).abs() <= 1e-5);
+    }
+    assert_eq!(Transform::<Body, World, f32>::from(matrix), t);
+}
+
+#[test]
+fn quaternions_round_trip_within_f32_tolerance() {
+    let q = UnitQuat::<World>::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), Quantity::new(1.1))
+        .unwrap();
+
+    let exact: DQuat = q.into();
+    assert_eq!(UnitQuat::<World>::try_from(exact).unwrap(), q);
+
+    let narrowed: Quat = q.into();
+    let back = UnitQuat::<World>::try_from(narrowed).unwrap();
+    let v = Vector3::new(1.0, 0.0, 0.0);
+    let (a, b) = (q.rotate_vector(v), back.rotate_vector(v));
+    assert!((a.x - b.x).abs() <= 1e-6);
+    assert!((a.y - b.y).abs() <= 1e-6);
+    assert!((a.z - b.z).abs() <= 1e-6);
+
+    let zero = Quat::from_xyzw(0.0, 0.0, 0.0, 0.0);
+    assert_eq!(
+        UnitQuat::<World>::try_from(zero).unwrap_err(),
+        SpatialError::ZeroNormQuaternion
+    );
+}
+
+#[test]
+fn f32_quaternions_convert_to_quat_without_widening() {
+    let q = UnitQuat::<World, f32>::from_axis_angle(
+        Vector3::new(0.0, 0.6, 0.8),
+        Quantity::new(1.1_f32),
+    )
+    .unwrap();
+
+    let quat: Quat = q.into();
+    assert_eq!(quat.to_array(), [q.x, q.y, q.z, q.w]);
+    assert_eq!(UnitQuat::<World, f32>::try_from(quat).unwrap(), q);
+
+    assert_eq!(
+        UnitQuat::<World, f32>::try_from(Quat::from_xyzw(f32::NAN, 0.0, 0.0, 1.0)).unwrap_err(),
+        SpatialError::NonFinite
+    );
+}
+
+#[test]
+fn projective_transforms_are_not_affine() {
+    let mut m = Transform::<Body, World>::identity().matrix;
+    m[3][2] = 1.0;
+
+    let result: Result<DAffine3, _> = Transform::<Body, World>::from_matrix(m).try_into();
+    assert_eq!(result.unwrap_err(), SpatialError::DegenerateInput);
+}
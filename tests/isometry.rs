@@ -0,0 +1,85 @@
+//! Tests that `IsometryTransform` agrees with the matrix `Transform`.
+
+use spatial_typestate::{spatial_frames, Frame, IsometryTransform, Point3, Transform};
+
+spatial_frames! {
+    World,
+    Body,
+    Sensor,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+fn assert_points_near<F: Frame>(a: Point3<F>, b: Point3<F>) {
+    assert!(approx_eq(a.x, b.x, 1e-12), "x: {} != {}", a.x, b.x);
+    assert!(approx_eq(a.y, b.y, 1e-12), "y: {} != {}", a.y, b.y);
+    assert!(approx_eq(a.z, b.z, 1e-12), "z: {} != {}", a.z, b.z);
+}
+
+/// Rotation of `angle` about the (normalized) axis (1, 2, 2) / 3, followed
+/// by a translation.
+fn rigid<A: Frame, B: Frame>(angle: f64, t: [f64; 3]) -> Transform<A, B> {
+    let (x, y, z) = (1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0);
+    let (s, c) = angle.sin_cos();
+    let k = 1.0 - c;
+
+    Transform::from_matrix([
+        [c + x * x * k, x * y * k - z * s, x * z * k + y * s, t[0]],
+        [y * x * k + z * s, c + y * y * k, y * z * k - x * s, t[1]],
+        [z * x * k - y * s, z * y * k + x * s, c + z * z * k, t[2]],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+fn sample_points<F: Frame>() -> [Point3<F>; 3] {
+    [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, -2.0, 3.0),
+        Point3::new(-4.5, 0.25, 10.0),
+    ]
+}
+
+#[test]
+fn isometry_is_smaller_than_matrix() {
+    assert_eq!(core::mem::size_of::<IsometryTransform<Body, World>>(), 56);
+    assert_eq!(core::mem::size_of::<Transform<Body, World>>(), 128);
+}
+
+#[test]
+fn apply_point_matches_matrix_form() {
+    let t: Transform<Body, World> = rigid(0.7, [1.0, 2.0, 3.0]);
+    let iso = IsometryTransform::from_transform(&t);
+
+    for p in sample_points::<Body>() {
+        assert_points_near(iso.apply_point(p), t.apply_point(p));
+    }
+
+    // And back to the matrix form.
+    let back: Transform<Body, World> = iso.into();
+    for (got, want) in back.matrix.iter().flatten().zip(t.matrix.iter().flatten()) {
+        assert!(approx_eq(*got, *want, 1e-12));
+    }
+}
+
+#[test]
+fn compose_and_inverse_match_matrix_form() {
+    let sensor_to_body: Transform<Sensor, Body> = rigid(-1.2, [0.1, 0.0, 0.5]);
+    let body_to_world: Transform<Body, World> = rigid(2.5, [10.0, -3.0, 0.0]);
+
+    let iso_sb = IsometryTransform::from_transform(&sensor_to_body);
+    let iso_bw = IsometryTransform::from_transform(&body_to_world);
+
+    let composed = iso_sb.compose(&iso_bw);
+    let matrix_composed = sensor_to_body.compose(&body_to_world);
+    for p in sample_points::<Sensor>() {
+        assert_points_near(composed.apply_point(p), matrix_composed.apply_point(p));
+    }
+
+    let inv = iso_bw.inverse();
+    let matrix_inv = body_to_world.inverse();
+    for p in sample_points::<World>() {
+        assert_points_near(inv.apply_point(p), matrix_inv.apply_point(p));
+    }
+}
@@ -0,0 +1,63 @@
+//! Tests for velocity estimation from timestamped poses.
+
+use spatial_typestate::{spatial_frames, Quantity, SpatialError, Stamped, Transform};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+/// Pose of the body at time `t` for a constant-velocity motion: moving at
+/// (2, -1, 0.5) m/s while yawing at 0.3 rad/s.
+fn pose_at(t: f64) -> Stamped<Transform<Body, World>> {
+    let yaw = 0.1 + 0.3 * t;
+    let (s, c) = yaw.sin_cos();
+    let m = [
+        [c, -s, 0.0, 1.0 + 2.0 * t],
+        [s, c, 0.0, 2.0 - t],
+        [0.0, 0.0, 1.0, 3.0 + 0.5 * t],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+
+    Stamped::new(Quantity::new(t), Transform::from_matrix(m))
+}
+
+#[test]
+fn constant_velocity_motion_is_recovered() {
+    let (v, w) =
+        Transform::relative_angular_and_linear_velocity(&pose_at(1.0), &pose_at(1.5)).unwrap();
+
+    assert!(approx_eq(v.0.x, 2.0, 1e-9));
+    assert!(approx_eq(v.0.y, -1.0, 1e-9));
+    assert!(approx_eq(v.0.z, 0.5, 1e-9));
+
+    assert!(approx_eq(w.0.x, 0.0, 1e-9));
+    assert!(approx_eq(w.0.y, 0.0, 1e-9));
+    assert!(approx_eq(w.0.z, 0.3, 1e-9));
+}
+
+#[test]
+fn non_increasing_timestamps_are_rejected() {
+    let result = Transform::relative_angular_and_linear_velocity(&pose_at(2.0), &pose_at(2.0));
+    assert!(matches!(result, Err(SpatialError::DegenerateInput)));
+}
+
+#[test]
+fn speed_and_rate_carry_their_units() {
+    use spatial_typestate::{
+        AngularVelocity3, Meters, Quotient, Radians, Seconds, Vector3, Velocity3,
+    };
+
+    let v = Velocity3(Vector3::<World>::new(3.0, 0.0, 4.0));
+    let w = AngularVelocity3(Vector3::<World>::new(0.0, 0.0, -0.5));
+
+    let speed: Quantity<Quotient<Meters, Seconds>> = v.speed();
+    let rate: Quantity<Quotient<Radians, Seconds>> = w.rate();
+
+    assert_eq!(speed.get(), 5.0);
+    assert_eq!(rate.get(), 0.5);
+}
@@ -0,0 +1,59 @@
+//! Tests for length units and conversions between them.
+
+use spatial_typestate::units::LengthUnit;
+use spatial_typestate::{ConvertTo, Feet, Kilometers, Meters, NauticalMiles, Quantity};
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[test]
+fn one_kilometer_is_a_thousand_meters() {
+    let m: Quantity<Meters> = Quantity::<Kilometers>::new(1.0).convert();
+    assert_eq!(m.get(), 1000.0);
+
+    let km: Quantity<Kilometers> = Quantity::<Meters>::new(250.0).convert();
+    assert_eq!(km.get(), 0.25);
+}
+
+#[test]
+fn feet_round_trip_through_meters() {
+    for ft in [0.0, 1.0, 35_000.0, -12.5, 1e-9] {
+        let original = Quantity::<Feet>::new(ft);
+        let meters: Quantity<Meters> = original.convert();
+        let back: Quantity<Feet> = meters.convert();
+
+        assert!(approx_eq(back.get(), ft, ft.abs() * 1e-15));
+    }
+
+    let meters: Quantity<Meters> = Quantity::<Feet>::new(1.0).convert();
+    assert_eq!(meters.get(), 0.3048);
+}
+
+#[test]
+fn converting_between_non_metric_units_goes_through_meters() {
+    let nm: Quantity<NauticalMiles> = Quantity::<Feet>::new(1852.0 / 0.3048).convert();
+    assert!(approx_eq(nm.get(), 1.0, 1e-12));
+
+    let km: Quantity<Kilometers> = Quantity::<NauticalMiles>::new(10.0).convert();
+    assert!(approx_eq(km.get(), 18.52, 1e-12));
+}
+
+#[test]
+fn converting_to_the_same_unit_is_exact() {
+    let q = Quantity::<Feet>::new(0.1 + 0.2);
+    let same: Quantity<Feet> = q.convert();
+    assert_eq!(same, q);
+}
+
+#[test]
+fn custom_length_units_convert_too() {
+    struct Furlongs;
+    impl spatial_typestate::Unit for Furlongs {}
+    impl LengthUnit for Furlongs {
+        const METERS: f64 = 201.168;
+    }
+
+    let m: Quantity<Meters> = Quantity::<Furlongs>::new(10.0).convert();
+    assert!(approx_eq(m.get(), 2011.68, 1e-9));
+}
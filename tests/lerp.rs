@@ -0,0 +1,52 @@
+//! Tests for linear interpolation of points and vectors.
+
+use spatial_typestate::{spatial_frames, Point3, Vector3};
+
+spatial_frames! {
+    World,
+}
+
+#[test]
+fn point_lerp_hits_endpoints_exactly() {
+    let a = Point3::<World>::new(0.1, -3.7, 1e-3);
+    let b = Point3::<World>::new(2.9, 0.3, -5.5);
+
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+}
+
+#[test]
+fn point_lerp_at_half_is_the_midpoint() {
+    let a = Point3::<World>::new(1.0, 2.0, -4.0);
+    let b = Point3::<World>::new(3.0, -2.0, 0.0);
+
+    let mid = Point3::new(2.0, 0.0, -2.0);
+    assert_eq!(a.lerp(b, 0.5), mid);
+    assert_eq!(a.midpoint(b), mid);
+    assert_eq!(b.midpoint(a), mid);
+}
+
+#[test]
+fn point_lerp_extrapolates_outside_unit_interval() {
+    let a = Point3::<World>::new(0.0, 0.0, 0.0);
+    let b = Point3::<World>::new(1.0, 2.0, 0.0);
+
+    assert_eq!(a.lerp(b, 2.0), Point3::new(2.0, 4.0, 0.0));
+    assert_eq!(a.lerp(b, -1.0), Point3::new(-1.0, -2.0, 0.0));
+}
+
+#[test]
+fn vector_lerp_blends_componentwise() {
+    let a = Vector3::<World>::new(1.0, 0.0, 0.0);
+    let b = Vector3::<World>::new(0.0, 1.0, 0.0);
+
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+    assert_eq!(a.lerp(b, 0.25), Vector3::new(0.75, 0.25, 0.0));
+
+    let c = Vector3::<World, f32>::new(2.0, 4.0, 6.0);
+    assert_eq!(
+        c.lerp(Vector3::new(0.0, 0.0, 0.0), 0.5),
+        Vector3::new(1.0, 2.0, 3.0)
+    );
+}
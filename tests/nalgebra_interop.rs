@@ -0,0 +1,127 @@
+//! Tests for the `nalgebra` interop: homogeneous columns, `From`/`Into`
+//! conversions and the LU-backed general inverse.
+
+#![cfg(feature = "nalgebra")]
+
+use nalgebra_dep::{Isometry3, Matrix4, UnitQuaternion};
+use spatial_typestate::{
+    spatial_frames, Point3, Quantity, SpatialError, Transform, UnitQuat, Vector3,
+};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+fn sample_transform() -> Transform<Body, World> {
+    let axis = Vector3::<Body>::new(1.0, 2.0, -0.5);
+    let q = UnitQuat::from_axis_angle(axis, Quantity::new(0.9)).unwrap();
+    Transform::from_quaternion_translation(q, 1.0, -2.0, 0.5)
+}
+
+#[test]
+fn points_become_homogeneous_columns_with_unit_w() {
+    let h = Point3::<World>::new(1.0, -2.0, 3.5).as_na_point();
+
+    assert_eq!((h.x, h.y, h.z, h.w), (1.0, -2.0, 3.5, 1.0));
+}
+
+#[test]
+fn vectors_become_homogeneous_columns_with_zero_w() {
+    let h = Vector3::<World>::new(0.25, 4.0, -1.0).as_na_vector();
+
+    assert_eq!((h.x, h.y, h.z, h.w), (0.25, 4.0, -1.0, 0.0));
+}
+
+#[test]
+fn points_and_vectors_round_trip_through_nalgebra() {
+    let p = Point3::<World>::new(1.0, -2.0, 3.5);
+    let na_p: nalgebra_dep::Point3<f64> = p.into();
+    assert_eq!((na_p.x, na_p.y, na_p.z), (1.0, -2.0, 3.5));
+    assert_eq!(Point3::<World>::from(na_p), p);
+
+    let v = Vector3::<World>::new(0.25, 4.0, -1.0);
+    let na_v: nalgebra_dep::Vector3<f64> = v.into();
+    assert_eq!((na_v.x, na_v.y, na_v.z), (0.25, 4.0, -1.0));
+    assert_eq!(Vector3::<World>::from(na_v), v);
+}
+
+#[test]
+fn quaternions_keep_their_components() {
+    let q = UnitQuat::<World>::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), Quantity::new(0.5))
+        .unwrap();
+    let na_q: UnitQuaternion<f64> = q.into();
+
+    assert_eq!(UnitQuat::<World>::from(na_q), q);
+}
+
+#[test]
+fn transforms_apply_identically_in_both_libraries() {
+    let t = sample_transform();
+    let p = Point3::<Body>::new(0.3, -1.2, 2.0);
+    let expected = t.apply_point(p);
+
+    let matrix: Matrix4<f64> = t.into();
+    let iso: Isometry3<f64> = t.try_into().unwrap();
+
+    for q in [
+        matrix.transform_point(&p.into()),
+        iso.transform_point(&p.into()),
+    ] {
+        assert!(approx_eq(q.x, expected.x, 1e-12));
+        assert!(approx_eq(q.y, expected.y, 1e-12));
+        assert!(approx_eq(q.z, expected.z, 1e-12));
+    }
+
+    assert_eq!(Transform::<Body, World>::from(matrix), t);
+    let back = Transform::<Body, World>::from(iso);
+    for (row, back_row) in t.matrix.iter().zip(back.matrix.iter()) {
+        for (a, b) in row.iter().zip(back_row.iter()) {
+            assert!(approx_eq(*a, *b, 1e-12));
+        }
+    }
+}
+
+#[test]
+fn non_rigid_transforms_do_not_become_isometries() {
+    let mut m = Transform::<Body, World>::identity().matrix;
+    m[0][0] = 2.0;
+
+    let result: Result<Isometry3<f64>, _> = Transform::<Body, World>::from_matrix(m).try_into();
+    assert_eq!(result.unwrap_err(), SpatialError::NotRigid);
+}
+
+#[test]
+fn try_inverse_round_trips_scaled_and_translated_transform() {
+    let t: Transform<Body, World> = Transform::from_matrix([
+        [2.0, 0.0, 0.0, 1.0],
+        [0.0, 0.5, 0.0, -3.0],
+        [0.0, 0.0, 4.0, 2.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    let inv = t.try_inverse().unwrap();
+
+    let p = Point3::<Body>::new(0.3, -1.2, 5.0);
+    let back = inv.apply_point(t.apply_point(p));
+    assert!(approx_eq(back.x, p.x, 1e-12));
+    assert!(approx_eq(back.y, p.y, 1e-12));
+    assert!(approx_eq(back.z, p.z, 1e-12));
+}
+
+#[test]
+fn try_inverse_rejects_singular_matrix() {
+    // Flattens everything onto the xy-plane.
+    let t: Transform<Body, World> = Transform::from_matrix([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    assert_eq!(t.try_inverse(), Err(SpatialError::NotInvertible));
+}
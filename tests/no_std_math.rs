@@ -0,0 +1,82 @@
+//! Float math that goes through the internal `std`/`libm` dispatch.
+//!
+//! Run this under both backends:
+//!
+//! ```text
+//! cargo test --test no_std_math
+//! cargo test --test no_std_math --no-default-features --features no_std,libm
+//! ```
+
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+use spatial_typestate::{spatial_frames, Quantity, Radians, UnitQuat, Vector3};
+
+spatial_frames! {
+    World,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[test]
+fn normalization_produces_a_unit_vector() {
+    let v = Vector3::<World>::new(3.0, 0.0, 4.0)
+        .try_normalize()
+        .unwrap();
+
+    assert!(approx_eq(v.x, 0.6, 1e-12));
+    assert!(approx_eq(v.z, 0.8, 1e-12));
+    assert!(approx_eq(v.norm(), 1.0, 1e-12));
+}
+
+#[test]
+fn trigonometry_matches_known_angles() {
+    let q =
+        UnitQuat::<World>::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), Quantity::new(FRAC_PI_2))
+            .unwrap();
+    let v = q.rotate_vector(Vector3::new(1.0, 0.0, 0.0));
+
+    assert!(approx_eq(v.x, 0.0, 1e-12));
+    assert!(approx_eq(v.y, 1.0, 1e-12));
+
+    let (_, angle) = q.to_axis_angle();
+    assert!(approx_eq(angle.get(), FRAC_PI_2, 1e-12));
+}
+
+#[test]
+fn trig_on_radians_matches_known_values() {
+    let right = Quantity::<Radians>::new(FRAC_PI_2);
+    assert!(approx_eq(right.sin(), 1.0, 1e-15));
+    assert!(approx_eq(right.cos(), 0.0, 1e-15));
+    assert!(approx_eq(
+        Quantity::<Radians>::new(FRAC_PI_4).tan(),
+        1.0,
+        1e-15
+    ));
+
+    let (s, c) = Quantity::<Radians>::new(PI / 6.0).sin_cos();
+    assert!(approx_eq(s, 0.5, 1e-15));
+    assert!(approx_eq(c, 3.0_f64.sqrt() / 2.0, 1e-15));
+}
+
+#[test]
+fn inverse_trig_builds_radians() {
+    assert!(approx_eq(
+        Quantity::<Radians>::asin(1.0).get(),
+        FRAC_PI_2,
+        1e-15
+    ));
+    assert!(approx_eq(Quantity::<Radians>::acos(-1.0).get(), PI, 1e-15));
+    assert!(approx_eq(
+        Quantity::<Radians>::atan(1.0).get(),
+        FRAC_PI_4,
+        1e-15
+    ));
+    assert!(approx_eq(
+        Quantity::<Radians>::atan2(-1.0, -1.0).get(),
+        -3.0 * FRAC_PI_4,
+        1e-15
+    ));
+    assert!(Quantity::<Radians>::asin(1.5).get().is_nan());
+}
@@ -0,0 +1,105 @@
+//! Tests for frame-tagged planes.
+
+use spatial_typestate::{spatial_frames, Plane, Point3, SpatialError, Transform, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[test]
+fn translating_a_plane_updates_its_offset() {
+    let plane = Plane::try_from_point_normal(
+        Point3::<Body>::new(0.0, 0.0, 1.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    )
+    .unwrap();
+    let t: Transform<Body, World> = Transform::from_translation(4.0, -2.0, 3.0);
+
+    let moved = t.apply_plane(plane);
+
+    let n = moved.normal();
+    assert!(approx_eq(n.x, 0.0, 1e-12));
+    assert!(approx_eq(n.y, 0.0, 1e-12));
+    assert!(approx_eq(n.z, 1.0, 1e-12));
+    assert!(approx_eq(moved.offset(), 4.0, 1e-12));
+}
+
+#[test]
+fn transformed_plane_contains_transformed_points_under_shear() {
+    // Non-rigid: shear x by z and scale y. A plain rotation of the normal
+    // would no longer be perpendicular to the mapped plane.
+    let t: Transform<Body, World> = Transform::from_matrix([
+        [1.0, 0.0, 2.0, 1.0],
+        [0.0, 3.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, -1.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    let plane = Plane::try_from_point_normal(
+        Point3::<Body>::new(1.0, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 0.0),
+    )
+    .unwrap();
+
+    let mapped = t.apply_plane(plane);
+
+    for p in [
+        Point3::<Body>::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 5.0),
+        Point3::new(3.0, -2.0, -1.0),
+    ] {
+        assert!(approx_eq(plane.signed_distance(p), 0.0, 1e-12));
+        assert!(approx_eq(
+            mapped.signed_distance(t.apply_point(p)),
+            0.0,
+            1e-12
+        ));
+    }
+
+    // Points on the positive side stay on the positive side.
+    let above = Point3::<Body>::new(2.0, 2.0, 0.0);
+    assert!(mapped.signed_distance(t.apply_point(above)) > 0.0);
+}
+
+#[test]
+fn degenerate_normal_is_rejected() {
+    assert_eq!(
+        Plane::try_from_point_normal(
+            Point3::<World>::new(0.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 0.0)
+        ),
+        Err(SpatialError::ZeroNormVector)
+    );
+}
+
+#[test]
+fn try_apply_plane_rejects_a_singular_linear_block() {
+    let plane = Plane::try_from_point_normal(
+        Point3::<Body>::new(0.0, 0.0, 1.0),
+        Vector3::new(0.0, 1.0, 1.0),
+    )
+    .unwrap();
+
+    // Projects onto the x-y plane: no inverse-transpose exists.
+    let flatten = Transform::<Body, World>::from_matrix([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    assert_eq!(
+        flatten.try_apply_plane(plane),
+        Err(SpatialError::NotInvertible)
+    );
+    assert_eq!(
+        Transform::<Body, World>::from_translation(f64::INFINITY, 0.0, 0.0).try_apply_plane(plane),
+        Err(SpatialError::NonFinite)
+    );
+
+    let t: Transform<Body, World> = Transform::from_translation(4.0, -2.0, 3.0);
+    assert_eq!(t.try_apply_plane(plane).unwrap(), t.apply_plane(plane));
+}
@@ -0,0 +1,196 @@
+//! Tests for the `Pose` convention: the pose of `Child` in `Parent` maps
+//! child-frame points into the parent frame, and back again.
+
+use spatial_typestate::units::{Quantity, Radians};
+use spatial_typestate::{spatial_frames, Point3, Pose, PoseIn, Transform, UnitQuat, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+    Sensor,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+/// Pose of a body sitting at (1, 2, 3) in the world, yawed 90° about z.
+fn body_in_world() -> Pose<World, Body> {
+    Pose::from_transform(Transform::from_matrix([
+        [0.0, -1.0, 0.0, 1.0],
+        [1.0, 0.0, 0.0, 2.0],
+        [0.0, 0.0, 1.0, 3.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]))
+}
+
+#[test]
+fn body_point_maps_into_parent() {
+    let pose = body_in_world();
+
+    // One meter ahead of the body is one meter along world +y from (1, 2, 3).
+    let p_world = pose.transform_point_into_parent(Point3::new(1.0, 0.0, 0.0));
+
+    assert!(approx_eq(p_world.x, 1.0, 1e-12));
+    assert!(approx_eq(p_world.y, 3.0, 1e-12));
+    assert!(approx_eq(p_world.z, 3.0, 1e-12));
+}
+
+#[test]
+fn world_point_maps_into_child() {
+    let pose = body_in_world();
+
+    // The body's own origin, given in world coordinates, is its origin.
+    let origin = pose.transform_point_into_child(Point3::new(1.0, 2.0, 3.0));
+    assert!(approx_eq(origin.x, 0.0, 1e-12));
+    assert!(approx_eq(origin.y, 0.0, 1e-12));
+    assert!(approx_eq(origin.z, 0.0, 1e-12));
+
+    // A point one meter along world +y from the body is straight ahead.
+    let ahead = pose.transform_point_into_child(Point3::new(1.0, 3.0, 3.0));
+    assert!(approx_eq(ahead.x, 1.0, 1e-12));
+    assert!(approx_eq(ahead.y, 0.0, 1e-12));
+    assert!(approx_eq(ahead.z, 0.0, 1e-12));
+}
+
+#[test]
+fn pose_round_trips_through_transform() {
+    let t: Transform<Body, World> = Transform::from_translation(4.0, 5.0, 6.0);
+
+    let pose: Pose<World, Body> = t.into();
+    assert_eq!(*pose.as_transform(), t);
+
+    let back: Transform<Body, World> = pose.into();
+    assert_eq!(back, t);
+}
+
+#[test]
+fn from_parts_rotates_then_translates() {
+    let orientation = UnitQuat::<Body>::from_axis_angle(
+        Vector3::new(1.0, 2.0, -0.5),
+        Quantity::<Radians>::new(0.8),
+    )
+    .unwrap();
+    let position = Point3::<World>::new(1.0, -2.0, 0.5);
+    let pose = Pose::from_parts(position, orientation);
+
+    let p = Point3::<Body>::new(0.3, -1.2, 2.0);
+    let rotated = orientation.rotate_point(p);
+    let via_transform = pose.into_transform().apply_point(p);
+
+    assert!(approx_eq(via_transform.x, rotated.x + position.x, 1e-12));
+    assert!(approx_eq(via_transform.y, rotated.y + position.y, 1e-12));
+    assert!(approx_eq(via_transform.z, rotated.z + position.z, 1e-12));
+}
+
+#[test]
+fn parts_round_trip() {
+    let (position, orientation) = body_in_world().to_parts().unwrap();
+    assert_eq!(position, body_in_world().position());
+    assert!(approx_eq(position.x, 1.0, 1e-12));
+    assert!(approx_eq(position.y, 2.0, 1e-12));
+    assert!(approx_eq(position.z, 3.0, 1e-12));
+
+    let rebuilt = Pose::from_parts(position, orientation);
+    for (row, expected) in rebuilt
+        .as_transform()
+        .matrix
+        .iter()
+        .zip(body_in_world().as_transform().matrix.iter())
+    {
+        for (a, b) in row.iter().zip(expected) {
+            assert!(approx_eq(*a, *b, 1e-12));
+        }
+    }
+}
+
+#[test]
+fn non_rigid_pose_has_no_parts() {
+    let scaled: Pose<World, Body> = Pose::from_transform(Transform::from_matrix([
+        [2.0, 0.0, 0.0, 0.0],
+        [0.0, 2.0, 0.0, 0.0],
+        [0.0, 0.0, 2.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]));
+    assert!(scaled.to_parts().is_err());
+}
+
+#[test]
+fn poses_compose_parent_first() {
+    // A sensor 0.5 m ahead of the body.
+    let sensor_in_body: Pose<Body, Sensor> =
+        Pose::from_transform(Transform::from_translation(0.5, 0.0, 0.0));
+    let sensor_in_world = body_in_world() * sensor_in_body;
+
+    // The body is yawed 90°, so "ahead" is world +y.
+    let origin = sensor_in_world.position();
+    assert!(approx_eq(origin.x, 1.0, 1e-12));
+    assert!(approx_eq(origin.y, 2.5, 1e-12));
+    assert!(approx_eq(origin.z, 3.0, 1e-12));
+
+    let p = Point3::<Sensor>::new(0.1, 0.2, 0.3);
+    let chained =
+        body_in_world().transform_point_into_parent(sensor_in_body.transform_point_into_parent(p));
+    let direct = sensor_in_world.transform_point_into_parent(p);
+    assert!(approx_eq(direct.x, chained.x, 1e-12));
+    assert!(approx_eq(direct.y, chained.y, 1e-12));
+    assert!(approx_eq(direct.z, chained.z, 1e-12));
+}
+
+fn yaw(angle: f64) -> UnitQuat<World> {
+    UnitQuat::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), Quantity::<Radians>::new(angle)).unwrap()
+}
+
+#[test]
+fn pose_in_transform_rotates_then_translates() {
+    let pose = PoseIn::new(Point3::<World>::new(1.0, 2.0, 3.0), yaw(0.7));
+    let p = Point3::new(0.5, -1.0, 2.0);
+
+    let via_transform = pose
+        .to_transform::<Body>()
+        .apply_point(Point3::new(p.x, p.y, p.z));
+    let rotated = pose.orientation.rotate_point(p);
+    let expected = Point3::<World>::new(rotated.x + 1.0, rotated.y + 2.0, rotated.z + 3.0);
+
+    assert!(approx_eq(via_transform.x, expected.x, 1e-12));
+    assert!(approx_eq(via_transform.y, expected.y, 1e-12));
+    assert!(approx_eq(via_transform.z, expected.z, 1e-12));
+
+    let direct = pose.transform_point(p);
+    assert!(approx_eq(direct.x, expected.x, 1e-12));
+    assert!(approx_eq(direct.y, expected.y, 1e-12));
+    assert!(approx_eq(direct.z, expected.z, 1e-12));
+}
+
+#[test]
+fn pose_in_round_trips_through_transform() {
+    let pose = PoseIn::new(Point3::<World>::new(-4.0, 0.5, 1.0), yaw(-1.2));
+
+    let back = PoseIn::from_transform(&pose.to_transform::<Body>()).unwrap();
+
+    assert!(approx_eq(back.position.x, -4.0, 1e-12));
+    assert!(approx_eq(back.position.y, 0.5, 1e-12));
+    assert!(approx_eq(back.position.z, 1.0, 1e-12));
+    assert!(back.orientation.angle_to(&pose.orientation).get() < 1e-9);
+}
+
+#[test]
+fn pose_in_composition_matches_transform_composition() {
+    let a = PoseIn::new(Point3::<World>::new(1.0, 0.0, 0.0), yaw(0.4));
+    let b = PoseIn::new(Point3::<World>::new(0.0, 2.0, -1.0), yaw(1.1));
+    let p = Point3::<Sensor>::new(0.3, 0.2, 0.1);
+
+    let composed = (a * b).to_transform::<Sensor>().apply_point(p);
+    let in_a = b.to_transform::<Sensor>().apply_point(p);
+    let chained = a
+        .to_transform::<Body>()
+        .apply_point(Point3::new(in_a.x, in_a.y, in_a.z));
+
+    assert!(approx_eq(composed.x, chained.x, 1e-12));
+    assert!(approx_eq(composed.y, chained.y, 1e-12));
+    assert!(approx_eq(composed.z, chained.z, 1e-12));
+    assert_eq!(
+        PoseIn::<World>::identity() * a,
+        a.compose(&PoseIn::identity())
+    );
+}
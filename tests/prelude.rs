@@ -0,0 +1,34 @@
+//! Checks that the prelude brings the core API into scope on its own.
+
+use spatial_typestate::prelude::*;
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+#[test]
+fn prelude_covers_core_api() {
+    let p: Point3<Body> = Point3::new(1.0, 0.0, 0.0);
+    let v: Vector3<Body> = Vector3::new(0.0, 1.0, 0.0);
+    let q: UnitQuat<Body> = UnitQuat::identity();
+    let t: Transform<Body, World> = Transform::from_translation(1.0, 0.0, 0.0);
+    let pose: Pose<World, Body> = Pose::from_transform(t);
+
+    let length: Quantity<Meters> = Quantity::new(1.0);
+    let angle: Quantity<Radians> = Quantity::new(0.5);
+    let heading: Quantity<Degrees> = Quantity::new(90.0);
+    let dt: Quantity<Seconds> = Quantity::new(0.1);
+
+    let p_world = pose.transform_point_into_parent(p);
+    assert_eq!(p_world.x, 2.0);
+    assert_eq!(v.y, 1.0);
+    assert_eq!(q.w, 1.0);
+    assert!(length.get() + angle.get() + heading.get() + dt.get() > 0.0);
+
+    let err: Result<UnitQuat<Body>, SpatialError> =
+        UnitQuat::try_from_components(0.0, 0.0, 0.0, 0.0);
+    assert!(err.is_err());
+}
+
+fn _generic_over_prelude_traits<F: Frame, U: Unit>(_: Point3<F>, _: Quantity<U>) {}
@@ -0,0 +1,84 @@
+//! Tests for attitude integration and its Jacobian.
+
+use spatial_typestate::{spatial_frames, UnitQuat, Vector3};
+
+spatial_frames! {
+    World,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+/// Left error rotation vector `log(a ⊗ b⁻¹)` between two unit quaternions.
+fn left_error(a: &UnitQuat<World>, b: &UnitQuat<World>) -> [f64; 3] {
+    // a ⊗ conj(b)
+    let (bx, by, bz, bw) = (-b.x, -b.y, -b.z, b.w);
+    let x = a.w * bx + a.x * bw + a.y * bz - a.z * by;
+    let y = a.w * by - a.x * bz + a.y * bw + a.z * bx;
+    let z = a.w * bz + a.x * by - a.y * bx + a.z * bw;
+    let w = a.w * bw - a.x * bx - a.y * by - a.z * bz;
+
+    let (x, y, z, w) = if w < 0.0 {
+        (-x, -y, -z, -w)
+    } else {
+        (x, y, z, w)
+    };
+    let s = (x * x + y * y + z * z).sqrt();
+    if s < 1e-15 {
+        return [2.0 * x, 2.0 * y, 2.0 * z];
+    }
+    let k = 2.0 * s.atan2(w) / s;
+    [k * x, k * y, k * z]
+}
+
+#[test]
+fn integrate_constant_rate_about_z() {
+    let q = UnitQuat::<World>::identity();
+    let omega = Vector3::new(0.0, 0.0, core::f64::consts::PI);
+
+    // Half a second at π rad/s is a quarter turn: (0, 0, sin 45°, cos 45°).
+    let q1 = q.integrate(omega, 0.5);
+    let h = core::f64::consts::FRAC_1_SQRT_2;
+    assert!(approx_eq(q1.z, h, 1e-12));
+    assert!(approx_eq(q1.w, h, 1e-12));
+}
+
+#[test]
+fn analytic_jacobian_matches_finite_differences() {
+    let q = UnitQuat::<World>::try_from_components(0.1, -0.3, 0.2, 0.9).unwrap();
+    let omega = Vector3::<World>::new(0.8, -1.1, 0.4);
+    let dt = 0.7;
+    let h = 1e-6;
+
+    let (q1, jac) = q.integrate_with_jacobian(omega, dt);
+
+    for col in 0..3 {
+        let mut d = [0.0; 3];
+        d[col] = h;
+        let perturbed = Vector3::new(omega.x + d[0], omega.y + d[1], omega.z + d[2]);
+
+        let err = left_error(&q.integrate(perturbed, dt), &q1);
+        for (row, e) in err.iter().enumerate() {
+            assert!(
+                approx_eq(e / h, jac[row][col], 1e-5),
+                "J[{row}][{col}]: numeric {} vs analytic {}",
+                e / h,
+                jac[row][col]
+            );
+        }
+    }
+}
+
+#[test]
+fn jacobian_at_zero_rate_is_dt_identity() {
+    let q = UnitQuat::<World>::identity();
+    let (_, jac) = q.integrate_with_jacobian(Vector3::new(0.0, 0.0, 0.0), 0.25);
+
+    for (row, jac_row) in jac.iter().enumerate() {
+        for (col, v) in jac_row.iter().enumerate() {
+            let expected = if row == col { 0.25 } else { 0.0 };
+            assert!(approx_eq(*v, expected, 1e-15));
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Tests for UnitQuat invariants.
 
-use spatial_typestate::{spatial_frames, Frame, SpatialError, UnitQuat};
+use spatial_typestate::{
+    spatial_frames, Point3, Quantity, Rotation3, SpatialError, UnitQuat, Vector3,
+};
 
 spatial_frames! {
     World,
@@ -39,3 +41,302 @@ fn non_finite_quaternion_is_rejected() {
     let result = UnitQuat::<World>::try_from_components(f64::NAN, 0.0, 0.0, 1.0);
     assert!(matches!(result, Err(SpatialError::NonFinite)));
 }
+
+#[test]
+fn axis_dot_of_z_rotation_with_z_axis() {
+    let half = core::f64::consts::FRAC_PI_4; // 90° about z
+    let q = UnitQuat::<World>::try_from_components(0.0, 0.0, half.sin(), half.cos()).unwrap();
+
+    let z = Vector3::<World>::new(0.0, 0.0, 1.0);
+    let x = Vector3::<World>::new(1.0, 0.0, 0.0);
+
+    assert!(approx_eq(q.axis_dot(z), half.sin(), 1e-12));
+    assert!(approx_eq(q.axis_dot(x), 0.0, 1e-12));
+}
+
+/// Hamilton product `a ⊗ b`.
+fn hamilton(a: &UnitQuat<World>, b: &UnitQuat<World>) -> [f64; 4] {
+    [
+        a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+        a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+        a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+    ]
+}
+
+#[test]
+fn relative_to_self_is_identity() {
+    let a = UnitQuat::<World>::try_from_components(0.1, -0.4, 0.3, 0.8).unwrap();
+
+    let delta = a.relative_to(&a);
+
+    assert!(approx_eq(delta.x, 0.0, 1e-12));
+    assert!(approx_eq(delta.y, 0.0, 1e-12));
+    assert!(approx_eq(delta.z, 0.0, 1e-12));
+    assert!(approx_eq(delta.w.abs(), 1.0, 1e-12));
+}
+
+#[test]
+fn applying_relative_rotation_to_reference_recovers_self() {
+    let reference = UnitQuat::<World>::try_from_components(0.2, 0.1, -0.5, 0.7).unwrap();
+    let target = UnitQuat::<World>::try_from_components(-0.3, 0.6, 0.2, 0.4).unwrap();
+
+    let delta = target.relative_to(&reference);
+    let [x, y, z, w] = hamilton(&reference, &delta);
+
+    assert!(approx_eq(x, target.x, 1e-12));
+    assert!(approx_eq(y, target.y, 1e-12));
+    assert!(approx_eq(z, target.z, 1e-12));
+    assert!(approx_eq(w, target.w, 1e-12));
+}
+
+#[test]
+fn rotation_vector_matches_axis_angle() {
+    // 1.2 rad about (2, -1, 2) / 3.
+    let axis = Vector3::<World>::new(2.0, -1.0, 2.0);
+    let rv = Vector3::<World>::new(0.8, -0.4, 0.8);
+
+    let from_rv = UnitQuat::from_rotation_vector(rv);
+    let from_aa = UnitQuat::from_axis_angle(axis, Quantity::new(1.2)).unwrap();
+
+    assert!(approx_eq(from_rv.x, from_aa.x, 1e-12));
+    assert!(approx_eq(from_rv.y, from_aa.y, 1e-12));
+    assert!(approx_eq(from_rv.z, from_aa.z, 1e-12));
+    assert!(approx_eq(from_rv.w, from_aa.w, 1e-12));
+}
+
+#[test]
+fn zero_rotation_vector_is_identity() {
+    let q = UnitQuat::from_rotation_vector(Vector3::<World>::new(0.0, 0.0, 0.0));
+
+    assert_eq!(q, UnitQuat::identity());
+}
+
+#[test]
+fn rotate_point_quarter_turn_about_z() {
+    let q = UnitQuat::<World>::from_axis_angle(
+        Vector3::new(0.0, 0.0, 1.0),
+        Quantity::new(core::f64::consts::FRAC_PI_2),
+    )
+    .unwrap();
+
+    let p = q.rotate_point(Point3::new(1.0, 0.0, 2.0));
+    assert!(approx_eq(p.x, 0.0, 1e-12));
+    assert!(approx_eq(p.y, 1.0, 1e-12));
+    assert!(approx_eq(p.z, 2.0, 1e-12));
+
+    // About the pivot (1, 1, 0): (2, 1) sits one unit along +x from it and
+    // ends up one unit along +y.
+    let pivot = Point3::new(1.0, 1.0, 0.0);
+    let p = q.rotate_point_about(Point3::new(2.0, 1.0, 5.0), pivot);
+    assert!(approx_eq(p.x, 1.0, 1e-12));
+    assert!(approx_eq(p.y, 2.0, 1e-12));
+    assert!(approx_eq(p.z, 5.0, 1e-12));
+}
+
+#[test]
+fn rotation_matrix_round_trip_preserves_quaternion_up_to_sign() {
+    for (axis, angle) in [
+        (Vector3::new(0.3, -1.0, 2.0), 0.4),
+        (Vector3::new(1.0, 1.0, 0.0), 2.9),
+        // Near a half turn the trace is negative and Shepperd's method
+        // switches branches.
+        (Vector3::new(0.0, 0.0, 1.0), core::f64::consts::PI - 1e-9),
+    ] {
+        let q = UnitQuat::<World>::from_axis_angle(axis, Quantity::new(angle)).unwrap();
+        let back = Rotation3::from_quat(q).to_quat();
+
+        let sign = if q.w * back.w + q.x * back.x + q.y * back.y + q.z * back.z < 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+        assert!(approx_eq(back.x * sign, q.x, 1e-9));
+        assert!(approx_eq(back.y * sign, q.y, 1e-9));
+        assert!(approx_eq(back.z * sign, q.z, 1e-9));
+        assert!(approx_eq(back.w * sign, q.w, 1e-9));
+
+        let v = Vector3::new(1.0, -2.0, 0.5);
+        let by_matrix = Rotation3::from_quat(q).apply_vector(v);
+        let by_quat = q.rotate_point(Point3::new(v.x, v.y, v.z));
+        assert!(approx_eq(by_matrix.x, by_quat.x, 1e-12));
+        assert!(approx_eq(by_matrix.y, by_quat.y, 1e-12));
+        assert!(approx_eq(by_matrix.z, by_quat.z, 1e-12));
+    }
+}
+
+#[test]
+fn product_with_conjugate_is_identity() {
+    let q = UnitQuat::<World>::try_from_components(0.3, -0.2, 0.9, 0.4).unwrap();
+
+    let id = q * q.conjugate();
+    assert!(approx_eq(id.x, 0.0, 1e-12));
+    assert!(approx_eq(id.y, 0.0, 1e-12));
+    assert!(approx_eq(id.z, 0.0, 1e-12));
+    assert!(approx_eq(id.w, 1.0, 1e-12));
+}
+
+#[test]
+fn product_matches_rotation_matrix_product() {
+    let a = UnitQuat::<World>::from_axis_angle(Vector3::new(1.0, 0.0, 1.0), Quantity::new(0.9))
+        .unwrap();
+    let b = UnitQuat::<World>::from_axis_angle(Vector3::new(0.0, 2.0, -1.0), Quantity::new(-1.7))
+        .unwrap();
+
+    let ra = Rotation3::from_quat(a).matrix();
+    let rb = Rotation3::from_quat(b).matrix();
+    let rab = Rotation3::from_quat(a * b).matrix();
+
+    for (i, row) in rab.iter().enumerate() {
+        for (j, v) in row.iter().enumerate() {
+            let expected: f64 = (0..3).map(|k| ra[i][k] * rb[k][j]).sum();
+            assert!(approx_eq(*v, expected, 1e-12));
+        }
+    }
+}
+
+#[test]
+fn rotate_vector_quarter_turn_about_z_maps_x_to_y() {
+    let q = UnitQuat::<World>::from_axis_angle(
+        Vector3::new(0.0, 0.0, 1.0),
+        Quantity::new(core::f64::consts::FRAC_PI_2),
+    )
+    .unwrap();
+
+    let v = q.rotate_vector(Vector3::new(1.0, 0.0, 0.0));
+    assert!(approx_eq(v.x, 0.0, 1e-12));
+    assert!(approx_eq(v.y, 1.0, 1e-12));
+    assert!(approx_eq(v.z, 0.0, 1e-12));
+}
+
+fn about_z(angle: f64) -> UnitQuat<World> {
+    UnitQuat::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), Quantity::new(angle)).unwrap()
+}
+
+fn assert_same_rotation(a: &UnitQuat<World>, b: &UnitQuat<World>) {
+    let dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+    assert!(approx_eq(dot.abs(), 1.0, 1e-12), "{a:?} vs {b:?}");
+}
+
+#[test]
+fn slerp_hits_endpoints_and_half_angle() {
+    let a = about_z(0.2);
+    let b = about_z(1.4);
+
+    assert_same_rotation(&a.slerp(&b, 0.0), &a);
+    assert_same_rotation(&a.slerp(&b, 1.0), &b);
+    assert_same_rotation(&a.slerp(&b, 0.5), &about_z(0.8));
+}
+
+#[test]
+fn slerp_takes_the_shorter_arc() {
+    let a = about_z(0.1);
+    // Same rotation as `about_z(0.5)`, stored in the opposite hemisphere.
+    let b = {
+        let q = about_z(0.5);
+        UnitQuat::new_unchecked(-q.x, -q.y, -q.z, -q.w)
+    };
+
+    assert_same_rotation(&a.slerp(&b, 0.5), &about_z(0.3));
+}
+
+#[test]
+fn slerp_of_nearly_equal_quaternions_stays_unit() {
+    let a = about_z(0.3);
+    let b = about_z(0.3 + 1e-9);
+
+    let mid = a.slerp(&b, 0.5);
+    let norm = (mid.x * mid.x + mid.y * mid.y + mid.z * mid.z + mid.w * mid.w).sqrt();
+    assert!(approx_eq(norm, 1.0, 1e-15));
+    assert_same_rotation(&mid, &a);
+}
+
+#[test]
+fn half_turn_about_z_maps_x_to_minus_x() {
+    let q = about_z(core::f64::consts::PI);
+    assert!(approx_eq(q.z.abs(), 1.0, 1e-12));
+    assert!(approx_eq(q.w, 0.0, 1e-12));
+
+    let v = q.rotate_vector(Vector3::new(1.0, 0.0, 0.0));
+    assert!(approx_eq(v.x, -1.0, 1e-12));
+    assert!(approx_eq(v.y, 0.0, 1e-12));
+    assert!(approx_eq(v.z, 0.0, 1e-12));
+}
+
+#[test]
+fn from_axis_angle_rejects_zero_axis_and_non_finite_angle() {
+    assert_eq!(
+        UnitQuat::<World>::from_axis_angle(Vector3::new(0.0, 0.0, 0.0), Quantity::new(1.0)),
+        Err(SpatialError::ZeroNormVector)
+    );
+    assert_eq!(
+        UnitQuat::<World>::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), Quantity::new(f64::NAN)),
+        Err(SpatialError::NonFinite)
+    );
+}
+
+#[test]
+fn axis_angle_round_trips() {
+    for (axis, angle) in [
+        (Vector3::<World>::new(0.0, 0.0, 1.0), 0.25),
+        (Vector3::new(1.0, -2.0, 0.5), 1.9),
+        (Vector3::new(-0.3, 0.1, 4.0), 3.0),
+    ] {
+        let q = UnitQuat::from_axis_angle(axis, Quantity::new(angle)).unwrap();
+        let (axis2, angle2) = q.to_axis_angle();
+
+        let unit = axis.try_normalize().unwrap();
+        assert!(approx_eq(angle2.get(), angle, 1e-12));
+        assert!(approx_eq(axis2.x, unit.x, 1e-12));
+        assert!(approx_eq(axis2.y, unit.y, 1e-12));
+        assert!(approx_eq(axis2.z, unit.z, 1e-12));
+    }
+}
+
+#[test]
+fn axis_angle_of_more_than_half_turn_is_the_shorter_rotation() {
+    let (axis, angle) = about_z(1.5 * core::f64::consts::PI).to_axis_angle();
+
+    assert!(approx_eq(angle.get(), 0.5 * core::f64::consts::PI, 1e-12));
+    assert!(approx_eq(axis.z, -1.0, 1e-12));
+}
+
+#[test]
+fn axis_angle_of_identity_is_zero_about_x() {
+    let (axis, angle) = UnitQuat::<World>::identity().to_axis_angle();
+
+    assert_eq!(angle.get(), 0.0);
+    assert_eq!(axis, Vector3::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn angle_to_identity_is_zero() {
+    let q: UnitQuat<World> = UnitQuat::identity();
+
+    assert_eq!(q.angle_to(&q).get(), 0.0);
+}
+
+#[test]
+fn angle_to_quarter_turn_is_half_pi() {
+    let a = UnitQuat::<World>::identity();
+    let b = UnitQuat::<World>::from_axis_angle(
+        Vector3::new(0.0, 0.0, 1.0),
+        Quantity::new(core::f64::consts::FRAC_PI_2),
+    )
+    .unwrap();
+
+    let angle = a.angle_to(&b).get();
+    assert!(approx_eq(angle, core::f64::consts::FRAC_PI_2, 1e-12));
+    assert!(approx_eq(b.angle_to(&a).get(), angle, 1e-15));
+}
+
+#[test]
+fn angle_to_ignores_quaternion_sign() {
+    let q = UnitQuat::<World>::from_axis_angle(Vector3::new(1.0, 2.0, -0.5), Quantity::new(0.8))
+        .unwrap();
+    let neg = UnitQuat::<World>::try_from_components(-q.x, -q.y, -q.z, -q.w).unwrap();
+
+    let angle = q.angle_to(&neg).get();
+    assert!(!angle.is_nan());
+    assert!(approx_eq(angle, 0.0, 1e-6));
+}
@@ -7,7 +7,7 @@
 //! - This reinforces your invariant that `UnitQuat::try_from_components` always returns a unit quaternion when it succeeds.
 //!
 use proptest::prelude::*;
-use spatial_typestate::{spatial_frames, Frame, SpatialError, UnitQuat};
+use spatial_typestate::{spatial_frames, SpatialError, UnitQuat};
 
 spatial_frames! {
     World,
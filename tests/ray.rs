@@ -0,0 +1,107 @@
+//! Tests for frame-tagged rays.
+
+use spatial_typestate::units::{Quantity, Radians};
+use spatial_typestate::{spatial_frames, Point3, Ray, SpatialError, Transform, Vector3};
+
+spatial_frames! {
+    World,
+    Lidar,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[test]
+fn direction_is_normalized() {
+    let ray = Ray::try_new(
+        Point3::<World>::new(1.0, 1.0, 1.0),
+        Vector3::new(3.0, 0.0, 4.0),
+    )
+    .unwrap();
+
+    let d = ray.direction();
+    assert!(approx_eq(d.norm(), 1.0, 1e-12));
+    assert!(approx_eq(d.x, 0.6, 1e-12));
+    assert!(approx_eq(d.z, 0.8, 1e-12));
+
+    let p = ray.at(5.0);
+    assert!(approx_eq(p.x, 4.0, 1e-12));
+    assert!(approx_eq(p.y, 1.0, 1e-12));
+    assert!(approx_eq(p.z, 5.0, 1e-12));
+    assert_eq!(ray.at(0.0), ray.origin());
+}
+
+#[test]
+fn zero_or_non_finite_input_is_rejected() {
+    assert_eq!(
+        Ray::try_new(
+            Point3::<World>::new(0.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 0.0)
+        ),
+        Err(SpatialError::ZeroNormVector)
+    );
+    assert_eq!(
+        Ray::try_new(
+            Point3::<World>::new(f64::NAN, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0)
+        ),
+        Err(SpatialError::NonFinite)
+    );
+}
+
+#[test]
+fn transformed_ray_reaches_the_same_points() {
+    let lidar_to_world: Transform<Lidar, World> =
+        Transform::<Lidar, Lidar>::rotation_z(Quantity::<Radians>::new(0.7))
+            .compose(&Transform::from_translation(2.0, -1.0, 1.5));
+    let beam = Ray::try_new(
+        Point3::<Lidar>::new(0.1, 0.2, 0.0),
+        Vector3::new(1.0, 1.0, -0.2),
+    )
+    .unwrap();
+    let beam_world = lidar_to_world.apply_ray(beam);
+
+    assert!(approx_eq(beam_world.direction().norm(), 1.0, 1e-12));
+    for t in [0.0, 0.5, 3.0, 40.0] {
+        let expected = lidar_to_world.apply_point(beam.at(t));
+        let actual = beam_world.at(t);
+        assert!(approx_eq(actual.x, expected.x, 1e-9));
+        assert!(approx_eq(actual.y, expected.y, 1e-9));
+        assert!(approx_eq(actual.z, expected.z, 1e-9));
+    }
+}
+
+#[test]
+fn try_apply_ray_rejects_a_collapsed_direction() {
+    // Flattens everything onto the x-y plane, so a vertical beam has no
+    // direction left.
+    let flatten = Transform::<Lidar, World>::from_matrix([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    let vertical = Ray::try_new(
+        Point3::<Lidar>::new(1.0, 2.0, 3.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    )
+    .unwrap();
+    assert_eq!(
+        flatten.try_apply_ray(vertical),
+        Err(SpatialError::ZeroNormVector)
+    );
+
+    let slanted = Ray::try_new(
+        Point3::<Lidar>::new(1.0, 2.0, 3.0),
+        Vector3::new(1.0, 0.0, 1.0),
+    )
+    .unwrap();
+    let mapped = flatten.try_apply_ray(slanted).unwrap();
+    assert_eq!(mapped.direction(), Vector3::new(1.0, 0.0, 0.0));
+
+    assert_eq!(
+        Transform::<Lidar, World>::from_translation(f64::NAN, 0.0, 0.0).try_apply_ray(slanted),
+        Err(SpatialError::NonFinite)
+    );
+}
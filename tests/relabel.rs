@@ -0,0 +1,23 @@
+//! Tests for explicit frame relabeling through `RelabelSafe`.
+
+use spatial_typestate::{spatial_frames, Point3, RelabelSafe, Vector3};
+
+spatial_frames! {
+    LegacyWorld,
+    World,
+}
+
+// Both names refer to the same physical world frame.
+impl RelabelSafe<World> for LegacyWorld {}
+
+#[test]
+fn opted_in_pair_relabels_without_changing_data() {
+    let old_p: Point3<LegacyWorld> = Point3::new(1.0, -2.0, 3.5);
+    let old_v: Vector3<LegacyWorld> = Vector3::new(0.0, 1.0, 0.0);
+
+    let p: Point3<World> = old_p.relabel();
+    let v: Vector3<World> = old_v.relabel();
+
+    assert_eq!((p.x, p.y, p.z), (1.0, -2.0, 3.5));
+    assert_eq!((v.x, v.y, v.z), (0.0, 1.0, 0.0));
+}
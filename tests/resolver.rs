@@ -0,0 +1,75 @@
+//! Tests for typed lookups through a runtime frame tree.
+
+use core::any::TypeId;
+use core::f64::consts::FRAC_PI_2;
+
+use spatial_typestate::resolver::{transform_between, FrameResolver};
+use spatial_typestate::{spatial_frames, Point3, Quantity, SpatialError, Transform};
+
+spatial_frames! {
+    World,
+    Body,
+    Camera,
+    Detached,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+/// `Camera` → `Body` → `World`; `Detached` is a root of its own.
+struct Rig;
+
+impl FrameResolver for Rig {
+    fn parent(&self, child: TypeId) -> Option<(TypeId, [[f64; 4]; 4])> {
+        if child == TypeId::of::<Camera>() {
+            let camera_to_body: Transform<Camera, Body> =
+                Transform::from_translation(0.0, 0.0, 1.0);
+            Some((TypeId::of::<Body>(), camera_to_body.matrix))
+        } else if child == TypeId::of::<Body>() {
+            // A quarter turn about z, then 10 m along world x.
+            let turn: Transform<Body, Body> = Transform::rotation_z(Quantity::new(FRAC_PI_2));
+            let body_to_world =
+                turn.compose(&Transform::<Body, World>::from_translation(10.0, 0.0, 0.0));
+            Some((TypeId::of::<World>(), body_to_world.matrix))
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn two_hop_path_is_composed_up_the_tree() {
+    let camera_to_world = transform_between::<Camera, World, _>(&Rig).unwrap();
+
+    // Camera origin sits 1 m up the body z axis, which the turn leaves alone.
+    let p = camera_to_world.apply_point(Point3::new(0.0, 0.0, 0.0));
+    assert!(approx_eq(p.x, 10.0, 1e-12));
+    assert!(approx_eq(p.y, 0.0, 1e-12));
+    assert!(approx_eq(p.z, 1.0, 1e-12));
+
+    // The camera x axis has been turned onto world y.
+    let q = camera_to_world.apply_point(Point3::new(1.0, 0.0, 0.0));
+    assert!(approx_eq(q.x, 10.0, 1e-12));
+    assert!(approx_eq(q.y, 1.0, 1e-12));
+}
+
+#[test]
+fn walking_down_the_tree_inverts_edges() {
+    let world_to_camera = transform_between::<World, Camera, _>(&Rig).unwrap();
+    let camera_to_world = transform_between::<Camera, World, _>(&Rig).unwrap();
+
+    let p = Point3::<Camera>::new(0.5, -2.0, 3.0);
+    let back = world_to_camera.apply_point(camera_to_world.apply_point(p));
+    assert!(approx_eq(back.x, p.x, 1e-12));
+    assert!(approx_eq(back.y, p.y, 1e-12));
+    assert!(approx_eq(back.z, p.z, 1e-12));
+}
+
+#[test]
+fn frames_in_different_trees_have_no_path() {
+    assert_eq!(
+        transform_between::<Camera, Detached, _>(&Rig),
+        Err(SpatialError::NoTransformPath)
+    );
+}
@@ -0,0 +1,102 @@
+//! Single-precision points, vectors, quaternions, quantities and transforms.
+
+use core::f32::consts::FRAC_PI_2;
+
+use spatial_typestate::units::MetersPerSecond;
+use spatial_typestate::{
+    spatial_frames, Meters, Point3, Quantity, Radians, Seconds, SpatialError, Transform, UnitQuat,
+    Vector3,
+};
+
+spatial_frames! {
+    World,
+    Body,
+    Sensor,
+}
+
+fn approx_eq(a: f32, b: f32, eps: f32) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[test]
+fn f32_point_round_trips_through_composed_transforms() {
+    let sensor_to_body: Transform<Sensor, Body, f32> = Transform::from_translation(0.0, 0.5, 0.0);
+    let body_to_world: Transform<Body, World, f32> = Transform::from_translation(1.0, 0.0, -2.0);
+    let sensor_to_world = sensor_to_body * body_to_world;
+
+    let p: Point3<Sensor, f32> = Point3::new(1.0, 2.0, 3.0);
+    let q = sensor_to_world.apply_point(p);
+
+    assert!(approx_eq(q.x, 2.0, 1e-6));
+    assert!(approx_eq(q.y, 2.5, 1e-6));
+    assert!(approx_eq(q.z, 1.0, 1e-6));
+    assert_eq!(
+        sensor_to_world.apply_point(p),
+        sensor_to_body.compose(&body_to_world).apply_point(p)
+    );
+}
+
+#[test]
+fn f32_identity_and_vectors_ignore_translation() {
+    let id: Transform<World, World, f32> = Transform::identity();
+    let p: Point3<World, f32> = Point3::new(-1.5, 0.25, 4.0);
+    assert_eq!(id.apply_point(p), p);
+
+    let t: Transform<Body, World, f32> = Transform::from_translation(5.0, 5.0, 5.0);
+    let v: Vector3<Body, f32> = Vector3::new(3.0, 4.0, 0.0);
+    let w = t.apply_vector(v);
+
+    assert_eq!(w, Vector3::new(3.0, 4.0, 0.0));
+    assert!(approx_eq(w.norm(), 5.0, 1e-6));
+    assert_eq!(
+        v.cross(Vector3::new(0.0, 0.0, 1.0)),
+        Vector3::new(4.0, -3.0, 0.0)
+    );
+}
+
+#[test]
+fn f32_quaternion_builds_a_rotation_transform() {
+    let angle: Quantity<Radians, f32> = Quantity::new(FRAC_PI_2);
+    let q = UnitQuat::<Body, f32>::from_axis_angle(Vector3::new(0.0, 0.0, 2.0), angle).unwrap();
+    let body_to_world: Transform<Body, World, f32> =
+        Transform::from_quaternion_translation(q, 1.0, 0.0, 0.0);
+
+    let p = body_to_world.apply_point(Point3::new(1.0, 0.0, 0.0));
+    assert!(approx_eq(p.x, 1.0, 1e-6));
+    assert!(approx_eq(p.y, 1.0, 1e-6));
+    assert!(approx_eq(p.z, 0.0, 1e-6));
+
+    let v = q.rotate_vector(Vector3::new(0.0, 1.0, 0.0));
+    assert!(approx_eq(v.x, -1.0, 1e-6));
+    assert!(approx_eq(v.y, 0.0, 1e-6));
+
+    let rotated: Transform<Body, Body, f32> = (q * q).to_transform();
+    let back = rotated.apply_point(Point3::new(1.0, 0.0, 0.0));
+    assert!(approx_eq(back.x, -1.0, 1e-6));
+}
+
+#[test]
+fn f32_quaternion_constructors_check_their_input() {
+    let q = UnitQuat::<World, f32>::try_from_components(0.0, 0.0, 0.0, 2.0).unwrap();
+    assert_eq!(q, UnitQuat::identity());
+
+    assert_eq!(
+        UnitQuat::<World, f32>::try_from_components(0.0, 0.0, 0.0, 0.0),
+        Err(SpatialError::ZeroNormQuaternion)
+    );
+    assert_eq!(
+        UnitQuat::<World, f32>::from_axis_angle(Vector3::new(0.0, 0.0, 0.0), Quantity::new(1.0)),
+        Err(SpatialError::ZeroNormVector)
+    );
+}
+
+#[test]
+fn f32_quantities_keep_their_units() {
+    let distance: Quantity<Meters, f32> = Quantity::new(6.0);
+    let time: Quantity<Seconds, f32> = Quantity::new(4.0);
+    let speed: Quantity<MetersPerSecond, f32> = distance / time;
+
+    assert_eq!(speed.get(), 1.5_f32);
+    assert!(distance + distance > distance);
+    assert_eq!((distance - distance).get(), 0.0_f32);
+}
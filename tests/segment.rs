@@ -0,0 +1,62 @@
+//! Tests for frame-tagged line segments.
+
+use spatial_typestate::{spatial_frames, Point3, Segment, Transform};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+fn path() -> Segment<World> {
+    Segment::new(Point3::new(1.0, 1.0, 0.0), Point3::new(5.0, 1.0, 0.0))
+}
+
+#[test]
+fn point_beyond_an_endpoint_resolves_to_that_endpoint() {
+    let seg = path();
+
+    let c = seg.closest_point(&Point3::new(8.0, 2.0, 0.0));
+    assert_eq!((c.x, c.y, c.z), (5.0, 1.0, 0.0));
+
+    let c = seg.closest_point(&Point3::new(-3.0, 1.0, 0.0));
+    assert_eq!((c.x, c.y, c.z), (1.0, 1.0, 0.0));
+    assert!(approx_eq(
+        seg.distance_to(&Point3::new(-3.0, 1.0, 0.0)),
+        4.0,
+        1e-12
+    ));
+}
+
+#[test]
+fn point_beside_the_middle_resolves_to_the_perpendicular_foot() {
+    let seg = path();
+    let p = Point3::new(3.0, 4.0, 4.0);
+
+    let c = seg.closest_point(&p);
+    assert!(approx_eq(c.x, 3.0, 1e-12));
+    assert!(approx_eq(c.y, 1.0, 1e-12));
+    assert!(approx_eq(c.z, 0.0, 1e-12));
+    assert!(approx_eq(seg.distance_to(&p), 5.0, 1e-12));
+}
+
+#[test]
+fn degenerate_segment_behaves_like_a_point() {
+    let p = Point3::<World>::new(2.0, 2.0, 2.0);
+    let seg = Segment::new(p, p);
+
+    assert_eq!(seg.closest_point(&Point3::new(9.0, 0.0, 0.0)), seg.start());
+}
+
+#[test]
+fn transforming_a_segment_maps_both_endpoints() {
+    let t: Transform<World, Body> = Transform::from_translation(0.0, 0.0, 10.0);
+
+    let moved = t.apply_segment(path());
+
+    assert_eq!((moved.start().x, moved.start().z), (1.0, 10.0));
+    assert_eq!((moved.end().x, moved.end().z), (5.0, 10.0));
+}
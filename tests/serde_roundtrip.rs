@@ -0,0 +1,67 @@
+//! Round-trip tests for the `serde` implementations of the core types.
+
+#![cfg(feature = "serde")]
+
+use spatial_typestate::{spatial_frames, Meters, Point3, Quantity, Transform, UnitQuat, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+#[test]
+fn point_serializes_without_frame_marker() {
+    let p = Point3::<World>::new(1.0, -2.5, 3.0);
+
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":-2.5,"z":3.0}"#);
+
+    let back: Point3<World> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, p);
+}
+
+#[test]
+fn vector_quantity_and_transform_round_trip() {
+    let v = Vector3::<Body>::new(0.5, 0.0, -4.0);
+    let back: Vector3<Body> = serde_json::from_str(&serde_json::to_string(&v).unwrap()).unwrap();
+    assert_eq!(back, v);
+
+    let q = Quantity::<Meters>::new(42.0);
+    let json = serde_json::to_string(&q).unwrap();
+    assert_eq!(json, r#"{"value":42.0}"#);
+    let back: Quantity<Meters> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, q);
+
+    let t: Transform<Body, World> = Transform::from_translation(1.0, 2.0, 3.0);
+    let back: Transform<Body, World> =
+        serde_json::from_str(&serde_json::to_string(&t).unwrap()).unwrap();
+    assert_eq!(back, t);
+}
+
+#[test]
+fn quaternion_is_normalized_on_load() {
+    let q = UnitQuat::<World>::try_from_components(0.0, 0.0, 0.6, 0.8).unwrap();
+    let back: UnitQuat<World> = serde_json::from_str(&serde_json::to_string(&q).unwrap()).unwrap();
+    assert_eq!(back, q);
+
+    let scaled: UnitQuat<World> =
+        serde_json::from_str(r#"{"x":0.0,"y":0.0,"z":3.0,"w":4.0}"#).unwrap();
+    assert!((scaled.z - 0.6).abs() < 1e-12);
+    assert!((scaled.w - 0.8).abs() < 1e-12);
+
+    assert!(serde_json::from_str::<UnitQuat<World>>(r#"{"x":0,"y":0,"z":0,"w":0}"#).is_err());
+}
+
+#[test]
+fn frame_identity_is_not_checked() {
+    // Documented behaviour: the target type is trusted.
+    let json = serde_json::to_string(&Point3::<Body>::new(1.0, 2.0, 3.0)).unwrap();
+    let p: Point3<World> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!((p.x, p.y, p.z), (1.0, 2.0, 3.0));
+}
+
+#[test]
+fn missing_field_is_rejected() {
+    assert!(serde_json::from_str::<Point3<World>>(r#"{"x":1.0,"y":2.0}"#).is_err());
+}
@@ -0,0 +1,41 @@
+//! Tests for serializing quantities together with their unit symbol.
+
+#![cfg(feature = "serde")]
+
+use spatial_typestate::serde::unit_symbol;
+use spatial_typestate::{Meters, Quantity, Seconds};
+
+fn to_json<U: spatial_typestate::UnitSymbol>(q: &Quantity<U>) -> String {
+    let mut out = Vec::new();
+    unit_symbol::serialize(q, &mut serde_json::Serializer::new(&mut out)).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn from_json<U: spatial_typestate::UnitSymbol>(
+    json: &str,
+) -> Result<Quantity<U>, serde_json::Error> {
+    unit_symbol::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+#[test]
+fn quantity_round_trips_with_its_unit_symbol() {
+    let altitude = Quantity::<Meters>::new(42.0);
+
+    let json = to_json(&altitude);
+    assert_eq!(json, r#"{"value":42.0,"unit":"m"}"#);
+
+    let back: Quantity<Meters> = from_json(&json).unwrap();
+    assert_eq!(back, altitude);
+}
+
+#[test]
+fn deserializing_into_the_wrong_unit_fails() {
+    let err = from_json::<Seconds>(r#"{"value":42.0,"unit":"m"}"#).unwrap_err();
+
+    assert!(err.to_string().contains("\"s\""), "{err}");
+}
+
+#[test]
+fn missing_unit_is_rejected() {
+    assert!(from_json::<Meters>(r#"{"value":42.0}"#).is_err());
+}
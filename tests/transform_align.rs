@@ -0,0 +1,63 @@
+//! Tests for `Transform::align`, the point + forward-direction alignment
+//! constructor.
+
+use spatial_typestate::{spatial_frames, Point3, SpatialError, Transform, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+fn assert_point_near(p: Point3<World>, x: f64, y: f64, z: f64) {
+    assert!(approx_eq(p.x, x, 1e-12), "x: {} != {}", p.x, x);
+    assert!(approx_eq(p.y, y, 1e-12), "y: {} != {}", p.y, y);
+    assert!(approx_eq(p.z, z, 1e-12), "z: {} != {}", p.z, z);
+}
+
+#[test]
+fn align_maps_origin_and_forward() {
+    let src_origin = Point3::<Body>::new(1.0, 0.0, 0.0);
+    let src_forward = Vector3::<Body>::new(2.0, 0.0, 0.0);
+    let dst_origin = Point3::<World>::new(0.0, 5.0, 1.0);
+    let dst_forward = Vector3::<World>::new(0.0, 3.0, 0.0);
+
+    let t = Transform::align(src_origin, src_forward, dst_origin, dst_forward).unwrap();
+
+    assert_point_near(t.apply_point(src_origin), 0.0, 5.0, 1.0);
+
+    // One unit along the source forward lands one unit along the target
+    // forward.
+    assert_point_near(t.apply_point(Point3::new(2.0, 0.0, 0.0)), 0.0, 6.0, 1.0);
+
+    // Shortest arc from +x to +y is a yaw about z, so "up" stays up.
+    assert_point_near(t.apply_point(Point3::new(1.0, 0.0, 1.0)), 0.0, 5.0, 2.0);
+}
+
+#[test]
+fn align_handles_opposite_directions() {
+    let t: Transform<Body, World> = Transform::align(
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(-1.0, 0.0, 0.0),
+    )
+    .unwrap();
+
+    assert_point_near(t.apply_point(Point3::new(1.0, 0.0, 0.0)), -1.0, 0.0, 0.0);
+}
+
+#[test]
+fn align_rejects_zero_forward() {
+    let result = Transform::<Body, World>::align(
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+    );
+
+    assert_eq!(result, Err(SpatialError::DegenerateInput));
+}
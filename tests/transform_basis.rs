@@ -0,0 +1,81 @@
+//! Tests for assembling transforms from basis vectors.
+
+use spatial_typestate::{spatial_frames, Point3, SpatialError, Transform, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+fn assert_vector_near(v: Vector3<World>, expected: Vector3<World>) {
+    assert!(
+        approx_eq(v.x, expected.x, 1e-12),
+        "x: {} != {}",
+        v.x,
+        expected.x
+    );
+    assert!(
+        approx_eq(v.y, expected.y, 1e-12),
+        "y: {} != {}",
+        v.y,
+        expected.y
+    );
+    assert!(
+        approx_eq(v.z, expected.z, 1e-12),
+        "z: {} != {}",
+        v.z,
+        expected.z
+    );
+}
+
+#[test]
+fn from_basis_round_trips_through_axis_accessors() {
+    let s = core::f64::consts::FRAC_1_SQRT_2;
+    let x_axis = Vector3::<World>::new(s, s, 0.0);
+    let y_axis = Vector3::<World>::new(-s, s, 0.0);
+    let z_axis = Vector3::<World>::new(0.0, 0.0, 1.0);
+    let origin = Point3::<World>::new(1.0, 2.0, 3.0);
+
+    let t: Transform<Body, World> = Transform::from_basis(x_axis, y_axis, z_axis, origin).unwrap();
+
+    assert_vector_near(t.x_axis(), x_axis);
+    assert_vector_near(t.y_axis(), y_axis);
+    assert_vector_near(t.z_axis(), z_axis);
+    assert_eq!(t.origin(), origin);
+
+    // The body's unit x point lands one step along x_axis from the origin.
+    let p = t.apply_point(Point3::new(1.0, 0.0, 0.0));
+    assert!(approx_eq(p.x, 1.0 + s, 1e-12));
+    assert!(approx_eq(p.y, 2.0 + s, 1e-12));
+    assert!(approx_eq(p.z, 3.0, 1e-12));
+}
+
+#[test]
+fn from_basis_rejects_non_orthonormal_and_left_handed_axes() {
+    let x = Vector3::<World>::new(1.0, 0.0, 0.0);
+    let y = Vector3::<World>::new(0.0, 1.0, 0.0);
+    let z = Vector3::<World>::new(0.0, 0.0, 1.0);
+    let origin = Point3::<World>::new(0.0, 0.0, 0.0);
+
+    let skewed = Vector3::<World>::new(0.1, 1.0, 0.0);
+    assert_eq!(
+        Transform::<Body, World>::from_basis(x, skewed, z, origin),
+        Err(SpatialError::NotRigid)
+    );
+
+    let flipped = Vector3::<World>::new(0.0, 0.0, -1.0);
+    assert_eq!(
+        Transform::<Body, World>::from_basis(x, y, flipped, origin),
+        Err(SpatialError::NotRigid)
+    );
+
+    let nan = Vector3::<World>::new(f64::NAN, 0.0, 0.0);
+    assert_eq!(
+        Transform::<Body, World>::from_basis(nan, y, z, origin),
+        Err(SpatialError::NonFinite)
+    );
+}
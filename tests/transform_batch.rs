@@ -0,0 +1,53 @@
+//! Batch application of transforms to point buffers.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use spatial_typestate::{
+    spatial_frames, Point3, Quantity, SpatialError, Transform, UnitQuat, Vector3,
+};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn sample_transform() -> Transform<Body, World> {
+    let axis = Vector3::<Body>::new(0.3, -1.0, 0.7);
+    let q = UnitQuat::from_axis_angle(axis, Quantity::new(2.1)).unwrap();
+    Transform::from_quaternion_translation(q, -4.0, 0.5, 12.0)
+}
+
+#[test]
+fn batch_matches_scalar_apply_point_for_random_points() {
+    let t = sample_transform();
+    let mut rng = StdRng::seed_from_u64(0x5EED);
+    let src: Vec<Point3<Body>> = (0..1000)
+        .map(|_| {
+            Point3::new(
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+            )
+        })
+        .collect();
+    let mut dst = vec![Point3::<World>::new(0.0, 0.0, 0.0); src.len()];
+
+    t.apply_points(&src, &mut dst).unwrap();
+
+    for (p, q) in src.iter().zip(&dst) {
+        assert_eq!(*q, t.apply_point(*p));
+    }
+}
+
+#[test]
+fn mismatched_lengths_are_rejected_without_writing() {
+    let t = sample_transform();
+    let src = [Point3::<Body>::new(1.0, 2.0, 3.0); 3];
+    let mut dst = [Point3::<World>::new(0.0, 0.0, 0.0); 2];
+
+    assert_eq!(
+        t.apply_points(&src, &mut dst),
+        Err(SpatialError::LengthMismatch)
+    );
+    assert_eq!(dst, [Point3::new(0.0, 0.0, 0.0); 2]);
+}
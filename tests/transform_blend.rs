@@ -0,0 +1,131 @@
+//! Tests for `Transform::blend_many` and `Transform::lerp_se3`.
+
+use core::f64::consts::FRAC_PI_2;
+
+use spatial_typestate::{spatial_frames, Point3, SpatialError, Transform};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+fn yaw(angle: f64, tx: f64, ty: f64, tz: f64) -> Transform<Body, World> {
+    let (s, c) = angle.sin_cos();
+    Transform::from_matrix([
+        [c, -s, 0.0, tx],
+        [s, c, 0.0, ty],
+        [0.0, 0.0, 1.0, tz],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+fn assert_matrix_near(a: &Transform<Body, World>, b: &Transform<Body, World>) {
+    for (got, want) in a.matrix.iter().flatten().zip(b.matrix.iter().flatten()) {
+        assert!(approx_eq(*got, *want, 1e-12), "{got} != {want}");
+    }
+}
+
+#[test]
+fn blending_identical_poses_returns_that_pose() {
+    let pose = yaw(0.8, 1.0, -2.0, 3.0);
+
+    let blended = Transform::blend_many(&[(pose, 1.0), (pose, 2.0), (pose, 0.5)]).unwrap();
+
+    assert_matrix_near(&blended, &pose);
+}
+
+#[test]
+fn even_blend_of_two_poses_is_halfway() {
+    let a = yaw(0.0, 0.0, 0.0, 0.0);
+    let b = yaw(core::f64::consts::FRAC_PI_2, 2.0, 0.0, 4.0);
+
+    let blended = Transform::blend_many(&[(a, 0.5), (b, 0.5)]).unwrap();
+
+    assert_matrix_near(&blended, &yaw(core::f64::consts::FRAC_PI_4, 1.0, 0.0, 2.0));
+}
+
+#[test]
+fn empty_or_zero_weight_input_is_rejected() {
+    let pose = yaw(0.3, 0.0, 0.0, 0.0);
+
+    assert_eq!(
+        Transform::<Body, World>::blend_many(&[]),
+        Err(SpatialError::DegenerateInput)
+    );
+    assert_eq!(
+        Transform::blend_many(&[(pose, 0.0), (pose, 0.0)]),
+        Err(SpatialError::DegenerateInput)
+    );
+}
+
+#[test]
+fn lerp_se3_reproduces_endpoints() {
+    let a = yaw(0.3, 1.0, -2.0, 0.5);
+    let b = yaw(1.2, 4.0, 0.0, -1.0);
+
+    for (t, expected) in [(0.0, a), (1.0, b)] {
+        let m = a.lerp_se3(&b, t).matrix;
+        for (row, expected_row) in m.iter().zip(expected.matrix.iter()) {
+            for (v, e) in row.iter().zip(expected_row.iter()) {
+                assert!(approx_eq(*v, *e, 1e-12), "t = {t}: {v} != {e}");
+            }
+        }
+    }
+}
+
+#[test]
+fn lerp_se3_reproduces_endpoints_for_small_relative_rotations() {
+    // Small angles are where the closed-form SE(3) coefficients cancel.
+    for angle in [1e-3, 1e-4, 1e-5, 2e-6, 1.1e-6, 1e-6] {
+        let identity = Transform::<Body, World>::identity();
+        let other = yaw(angle, 10.0, 3.0, 2.0);
+
+        let reached = identity.lerp_se3(&other, 1.0);
+
+        for (got, want) in reached
+            .matrix
+            .iter()
+            .flatten()
+            .zip(other.matrix.iter().flatten())
+        {
+            assert!(
+                approx_eq(*got, *want, 1e-12),
+                "angle = {angle}: {got} != {want}"
+            );
+        }
+    }
+}
+
+#[test]
+fn lerp_se3_follows_a_helix_about_the_screw_axis() {
+    // A quarter turn about the vertical axis through (1, 0, 0), climbing 2 m:
+    // p ↦ R (p - c) + c + (0, 0, 2) with c = (1, 0, 0).
+    let start: Transform<Body, World> = Transform::identity();
+    let end = yaw(FRAC_PI_2, 1.0, -1.0, 2.0);
+
+    for step in 0..=4 {
+        let t = f64::from(step) / 4.0;
+        let origin = start
+            .lerp_se3(&end, t)
+            .apply_point(Point3::new(0.0, 0.0, 0.0));
+
+        // The body origin stays 1 m from the screw axis while rising linearly.
+        let angle = t * FRAC_PI_2;
+        assert!(approx_eq(origin.x, 1.0 - angle.cos(), 1e-12));
+        assert!(approx_eq(origin.y, -angle.sin(), 1e-12));
+        assert!(approx_eq(origin.z, 2.0 * t, 1e-12));
+    }
+
+    // Blending rotation and translation separately cuts the corner instead.
+    let naive = Transform::blend_many(&[(start, 0.5), (end, 0.5)]).unwrap();
+    let chord = naive.apply_point(Point3::new(0.0, 0.0, 0.0));
+    let helix = start
+        .lerp_se3(&end, 0.5)
+        .apply_point(Point3::new(0.0, 0.0, 0.0));
+    assert!(approx_eq(chord.x, 0.5, 1e-12));
+    assert!((helix.x - chord.x).abs() > 0.1);
+}
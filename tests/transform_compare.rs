@@ -0,0 +1,73 @@
+//! Tests for tolerant comparison of transforms.
+
+use spatial_typestate::units::{Quantity, Radians};
+use spatial_typestate::{spatial_frames, Transform};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+#[test]
+fn slightly_perturbed_identity_is_still_identity() {
+    let mut m = Transform::<Body, World>::identity().matrix;
+    m[0][0] += 1e-15;
+    m[1][3] -= 1e-15;
+    m[3][2] += 1e-15;
+    let t = Transform::<Body, World>::from_matrix(m);
+
+    assert_ne!(t, Transform::identity());
+    assert!(t.is_identity(1e-9));
+    assert!(!t.is_identity(0.0));
+}
+
+#[test]
+fn round_trip_through_inverse_is_identity() {
+    let t: Transform<Body, World> =
+        Transform::<Body, Body>::rotation_z(Quantity::<Radians>::new(0.4))
+            .compose(&Transform::from_translation(1.0, -2.0, 0.5));
+
+    assert!(t.compose(&t.inverse()).is_identity(1e-12));
+    assert!(!t.is_identity(1e-3));
+}
+
+#[test]
+fn abs_diff_eq_compares_every_entry() {
+    let a = Transform::<Body, World>::from_translation(1.0, 2.0, 3.0);
+    let b = Transform::<Body, World>::from_translation(1.0, 2.0, 3.0 + 1e-10);
+
+    assert!(a.abs_diff_eq(&b, 1e-9));
+    assert!(b.abs_diff_eq(&a, 1e-9));
+    assert!(!a.abs_diff_eq(&b, 1e-11));
+
+    let mut m = a.matrix;
+    m[2][1] = f64::NAN;
+    let nan = Transform::<Body, World>::from_matrix(m);
+    assert!(!nan.abs_diff_eq(&nan, f64::INFINITY));
+}
+
+fn snapshot(yaw: f64, x: f64, y: f64) -> Transform<Body, World> {
+    Transform::<Body, Body>::rotation_z(Quantity::<Radians>::new(yaw))
+        .compose(&Transform::from_translation(x, y, 0.0))
+}
+
+#[test]
+fn relative_transform_of_a_pose_with_itself_is_identity() {
+    let t = snapshot(0.8, 3.0, -1.0);
+
+    assert!(t.relative_to(&t).is_identity(1e-12));
+}
+
+#[test]
+fn relative_transform_is_the_motion_in_the_start_frame() {
+    let start = snapshot(core::f64::consts::FRAC_PI_2, 1.0, 2.0);
+    let end = snapshot(core::f64::consts::FRAC_PI_2, 1.0, 4.0);
+
+    // The body moved 2 m along world +y, which is its own +x after the
+    // 90° yaw.
+    let delta: Transform<Body, Body> = end.relative_to(&start);
+    assert!(delta.abs_diff_eq(&Transform::from_translation(2.0, 0.0, 0.0), 1e-12));
+
+    // Composing the motion onto the start recovers the end.
+    assert!(delta.compose(&start).abs_diff_eq(&end, 1e-12));
+}
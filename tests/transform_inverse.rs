@@ -0,0 +1,71 @@
+//! Tests for inverting transforms, rigid and general.
+
+use spatial_typestate::{spatial_frames, Point3, Quantity, SpatialError, Transform};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[test]
+fn inverse_then_forward_is_identity() {
+    let t: Transform<Body, World> = Transform::<Body, Body>::rotation_z(Quantity::new(0.7))
+        .compose(&Transform::from_translation(3.0, -1.0, 2.0));
+
+    let round_trip: Transform<World, World> = t.inverse().compose(&t);
+    let identity = Transform::<World, World>::identity();
+    for (row, expected) in round_trip.matrix.iter().zip(&identity.matrix) {
+        for (v, e) in row.iter().zip(expected) {
+            assert!(approx_eq(*v, *e, 1e-12));
+        }
+    }
+
+    // The general path agrees with the closed form on rigid input.
+    assert_eq!(t.try_inverse(), Ok(t.inverse()));
+}
+
+#[test]
+fn try_inverse_round_trips_scaled_and_translated_transform() {
+    let t: Transform<Body, World> = Transform::from_matrix([
+        [2.0, 0.0, 0.0, 1.0],
+        [0.0, 0.5, 0.0, -3.0],
+        [0.0, 0.0, 4.0, 2.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    let inv = t.try_inverse().unwrap();
+
+    let p = Point3::<Body>::new(0.3, -1.2, 5.0);
+    let back = inv.apply_point(t.apply_point(p));
+    assert!((back.x - p.x).abs() < 1e-12);
+    assert!((back.y - p.y).abs() < 1e-12);
+    assert!((back.z - p.z).abs() < 1e-12);
+}
+
+#[test]
+fn try_inverse_rejects_singular_matrix() {
+    // Flattens everything onto the xy-plane.
+    let t: Transform<Body, World> = Transform::from_matrix([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    assert_eq!(t.try_inverse(), Err(SpatialError::NotInvertible));
+}
+
+#[test]
+fn try_inverse_rejects_non_finite_entries() {
+    let mut m = Transform::<Body, World>::identity().matrix;
+    m[1][3] = f64::NAN;
+
+    assert_eq!(
+        Transform::<Body, World>::from_matrix(m).try_inverse(),
+        Err(SpatialError::NonFinite)
+    );
+}
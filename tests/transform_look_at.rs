@@ -0,0 +1,48 @@
+//! Tests for the `Transform::look_at` camera constructor.
+
+use spatial_typestate::{spatial_frames, Point3, SpatialError, Transform, Vector3};
+
+spatial_frames! {
+    World,
+    Camera,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[test]
+fn target_lies_on_the_view_axis() {
+    let eye = Point3::<World>::new(1.0, 2.0, 3.0);
+    let target = Point3::new(4.0, 6.0, 3.0);
+    let up = Vector3::new(0.0, 0.0, 1.0);
+
+    let camera_to_world: Transform<Camera, World> = Transform::look_at(eye, target, up).unwrap();
+    let view = camera_to_world.inverse();
+
+    // 5 m straight ahead along the camera +z axis.
+    let p = view.apply_point(target);
+    assert!(approx_eq(p.x, 0.0, 1e-12));
+    assert!(approx_eq(p.y, 0.0, 1e-12));
+    assert!(approx_eq(p.z, 5.0, 1e-12));
+
+    // A point above the target shows up in the upper half of the image (-y).
+    let above = view.apply_point(Point3::new(4.0, 6.0, 4.0));
+    assert!(above.y < 0.0);
+    assert!(approx_eq(above.x, 0.0, 1e-12));
+}
+
+#[test]
+fn up_parallel_to_view_direction_is_rejected() {
+    let eye = Point3::<World>::new(0.0, 0.0, 10.0);
+    let target = Point3::new(0.0, 0.0, 0.0);
+
+    assert_eq!(
+        Transform::<Camera, World>::look_at(eye, target, Vector3::new(0.0, 0.0, 1.0)),
+        Err(SpatialError::DegenerateInput)
+    );
+    assert_eq!(
+        Transform::<Camera, World>::look_at(eye, eye, Vector3::new(0.0, 0.0, 1.0)),
+        Err(SpatialError::DegenerateInput)
+    );
+}
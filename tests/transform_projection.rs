@@ -0,0 +1,85 @@
+//! Tests for the projection constructors and the homogeneous point mapping.
+
+use core::f64::consts::FRAC_PI_2;
+
+use spatial_typestate::{spatial_frames, Point3, Quantity, SpatialError, Transform};
+
+spatial_frames! {
+    Camera,
+    Clip,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+fn assert_point_near(p: Point3<Clip>, expected: (f64, f64, f64)) {
+    assert!(approx_eq(p.x, expected.0, 1e-12), "{p:?} vs {expected:?}");
+    assert!(approx_eq(p.y, expected.1, 1e-12), "{p:?} vs {expected:?}");
+    assert!(approx_eq(p.z, expected.2, 1e-12), "{p:?} vs {expected:?}");
+}
+
+#[test]
+fn perspective_maps_near_and_far_planes_to_unit_depth_range() {
+    let proj =
+        Transform::<Camera, Clip>::perspective(Quantity::new(FRAC_PI_2), 2.0, 0.5, 100.0).unwrap();
+    assert!(!proj.is_affine(1e-12));
+
+    let near = proj
+        .apply_point_homogeneous(Point3::new(0.0, 0.0, 0.5))
+        .unwrap();
+    assert_point_near(near, (0.0, 0.0, 0.0));
+
+    let far = proj
+        .apply_point_homogeneous(Point3::new(0.0, 0.0, 100.0))
+        .unwrap();
+    assert_point_near(far, (0.0, 0.0, 1.0));
+
+    // With a 90° vertical field of view the frustum edge is at |y| = z, and
+    // the horizontal edge at |x| = aspect · z.
+    let corner = proj
+        .apply_point_homogeneous(Point3::new(8.0, 4.0, 4.0))
+        .unwrap();
+    assert!(approx_eq(corner.x, 1.0, 1e-12));
+    assert!(approx_eq(corner.y, 1.0, 1e-12));
+}
+
+#[test]
+fn orthographic_maps_box_onto_clip_volume() {
+    let proj = Transform::<Camera, Clip>::orthographic(-4.0, 4.0, -3.0, 3.0, 1.0, 11.0).unwrap();
+    assert!(proj.is_affine(0.0));
+
+    let top_left_near = proj
+        .apply_point_homogeneous(Point3::new(-4.0, -3.0, 1.0))
+        .unwrap();
+    assert_point_near(top_left_near, (-1.0, -1.0, 0.0));
+
+    let centre = proj.apply_point(Point3::new(0.0, 0.0, 6.0));
+    assert_point_near(centre, (0.0, 0.0, 0.5));
+}
+
+#[test]
+fn homogeneous_point_at_infinity_is_rejected() {
+    let proj = Transform::<Camera, Clip>::perspective(Quantity::new(1.0), 1.0, 0.1, 10.0).unwrap();
+
+    assert_eq!(
+        proj.apply_point_homogeneous(Point3::new(1.0, 1.0, 0.0)),
+        Err(SpatialError::DegenerateInput)
+    );
+}
+
+#[test]
+fn invalid_projection_parameters_are_rejected() {
+    assert_eq!(
+        Transform::<Camera, Clip>::perspective(Quantity::new(1.0), 1.0, 5.0, 5.0),
+        Err(SpatialError::DegenerateInput)
+    );
+    assert_eq!(
+        Transform::<Camera, Clip>::perspective(Quantity::new(f64::NAN), 1.0, 0.1, 5.0),
+        Err(SpatialError::NonFinite)
+    );
+    assert_eq!(
+        Transform::<Camera, Clip>::orthographic(1.0, 1.0, 0.0, 1.0, 0.0, 1.0),
+        Err(SpatialError::DegenerateInput)
+    );
+}
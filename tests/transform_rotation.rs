@@ -0,0 +1,137 @@
+//! Tests for rotation constructors on `Transform`.
+
+use core::f64::consts::FRAC_PI_2;
+
+use spatial_typestate::euler::EulerOrder;
+use spatial_typestate::{
+    spatial_frames, Point3, Quantity, SpatialError, Transform, UnitQuat, Vector3,
+};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+fn assert_point_near(p: Point3<World>, x: f64, y: f64, z: f64) {
+    assert!(approx_eq(p.x, x, 1e-12), "x: {} != {}", p.x, x);
+    assert!(approx_eq(p.y, y, 1e-12), "y: {} != {}", p.y, y);
+    assert!(approx_eq(p.z, z, 1e-12), "z: {} != {}", p.z, z);
+}
+
+#[test]
+fn principal_axis_rotations_follow_right_hand_rule() {
+    let quarter = Quantity::new(FRAC_PI_2);
+
+    let rz: Transform<Body, World> = Transform::rotation_z(quarter);
+    assert_point_near(rz.apply_point(Point3::new(1.0, 0.0, 0.0)), 0.0, 1.0, 0.0);
+
+    let rx: Transform<Body, World> = Transform::rotation_x(quarter);
+    assert_point_near(rx.apply_point(Point3::new(0.0, 1.0, 0.0)), 0.0, 0.0, 1.0);
+
+    let ry: Transform<Body, World> = Transform::rotation_y(quarter);
+    assert_point_near(ry.apply_point(Point3::new(0.0, 0.0, 1.0)), 1.0, 0.0, 0.0);
+}
+
+#[test]
+fn principal_axis_rotation_leaves_its_axis_fixed() {
+    let rz: Transform<Body, World> = Transform::rotation_z(Quantity::new(0.7));
+    assert_point_near(rz.apply_point(Point3::new(0.0, 0.0, 2.0)), 0.0, 0.0, 2.0);
+}
+
+#[test]
+fn principal_axis_rotations_match_single_axis_euler_angles() {
+    let (angle, zero) = (Quantity::new(-1.3), Quantity::new(0.0));
+    let cases: [(Transform<Body, World>, Transform<Body, World>); 3] = [
+        (
+            Transform::rotation_x(angle),
+            Transform::from_euler(EulerOrder::XYZ, angle, zero, zero),
+        ),
+        (
+            Transform::rotation_y(angle),
+            Transform::from_euler(EulerOrder::YZX, angle, zero, zero),
+        ),
+        (
+            Transform::rotation_z(angle),
+            Transform::from_euler(EulerOrder::ZYX, angle, zero, zero),
+        ),
+    ];
+
+    for (axis, euler) in cases {
+        let p = Point3::<Body>::new(0.3, -1.7, 2.2);
+        let q = euler.apply_point(p);
+        assert_point_near(axis.apply_point(p), q.x, q.y, q.z);
+    }
+}
+
+#[test]
+fn quaternion_translation_matches_rotating_then_translating() {
+    let q = UnitQuat::<Body>::from_axis_angle(Vector3::new(1.0, 2.0, -0.5), Quantity::new(0.8))
+        .unwrap();
+    let t: Transform<Body, World> = Transform::from_quaternion_translation(q, 3.0, -1.0, 0.25);
+
+    let p = Point3::<Body>::new(0.4, -2.0, 1.5);
+    let rotated = q.rotate_point(p);
+    assert_point_near(
+        t.apply_point(p),
+        rotated.x + 3.0,
+        rotated.y - 1.0,
+        rotated.z + 0.25,
+    );
+}
+
+#[test]
+fn quaternion_translation_round_trips_through_decomposition() {
+    let q = UnitQuat::<Body>::from_axis_angle(Vector3::new(-0.2, 0.9, 0.4), Quantity::new(2.2))
+        .unwrap();
+    let t: Transform<Body, World> = Transform::from_quaternion_translation(q, -7.0, 0.5, 12.0);
+
+    let (q2, [tx, ty, tz]) = t.to_quaternion_translation().unwrap();
+    assert_eq!((tx, ty, tz), (-7.0, 0.5, 12.0));
+
+    let rebuilt: Transform<Body, World> = Transform::from_quaternion_translation(q2, tx, ty, tz);
+    for (row, expected) in rebuilt.matrix.iter().zip(&t.matrix) {
+        for (v, e) in row.iter().zip(expected) {
+            assert!(approx_eq(*v, *e, 1e-9));
+        }
+    }
+}
+
+#[test]
+fn decomposing_a_scaled_transform_is_rejected() {
+    let t: Transform<Body, World> = Transform::from_matrix([
+        [2.0, 0.0, 0.0, 0.0],
+        [0.0, 2.0, 0.0, 0.0],
+        [0.0, 0.0, 2.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    assert_eq!(
+        t.to_quaternion_translation().map(|(_, tr)| tr),
+        Err(SpatialError::NotRigid)
+    );
+}
+
+#[test]
+fn quaternion_to_transform_is_a_pure_rotation() {
+    let q = UnitQuat::<Body>::from_axis_angle(Vector3::new(2.0, -1.0, 0.5), Quantity::new(1.3))
+        .unwrap();
+    let t: Transform<Body, World> = q.to_transform();
+
+    let m = &t.matrix;
+    for i in 0..3 {
+        assert_eq!(m[i][3], 0.0);
+        for j in 0..3 {
+            let dot: f64 = (0..3).map(|k| m[k][i] * m[k][j]).sum();
+            assert!(approx_eq(dot, if i == j { 1.0 } else { 0.0 }, 1e-12));
+        }
+    }
+    assert_eq!(m[3], [0.0, 0.0, 0.0, 1.0]);
+
+    let p = Point3::<Body>::new(0.7, -3.0, 2.0);
+    let v = q.rotate_vector(Vector3::new(p.x, p.y, p.z));
+    assert_point_near(t.apply_point(p), v.x, v.y, v.z);
+}
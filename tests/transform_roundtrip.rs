@@ -3,11 +3,12 @@
 //! Focus here is on simple but meaningful properties rather than exhaustive
 //! property-based tests (those can live in separate files).
 
-use spatial_typestate::{spatial_frames, Frame, Point3, Transform};
+use spatial_typestate::{spatial_frames, Aabb, Point3, Quantity, Transform, Vector3};
 
 spatial_frames! {
     World,
     Body,
+    Sensor,
 }
 
 fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
@@ -37,3 +38,213 @@ fn simple_translation_on_point() {
     assert!(approx_eq(q.y, 2.0, 1e-12));
     assert!(approx_eq(q.z, 3.0, 1e-12));
 }
+
+/// 90° yaw about z with a translation of (1, 2, 3).
+fn yaw_and_offset() -> Transform<Body, World> {
+    Transform::from_matrix([
+        [0.0, -1.0, 0.0, 1.0],
+        [1.0, 0.0, 0.0, 2.0],
+        [0.0, 0.0, 1.0, 3.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+#[test]
+fn negated_translation_keeps_rotation_and_differs_from_inverse() {
+    let t = yaw_and_offset();
+    let n = t.negated_translation();
+
+    // Rotation block untouched, translation flipped.
+    for row in 0..3 {
+        for col in 0..3 {
+            assert_eq!(n.matrix[row][col], t.matrix[row][col]);
+        }
+    }
+    assert_eq!(n.matrix[0][3], -1.0);
+    assert_eq!(n.matrix[1][3], -2.0);
+    assert_eq!(n.matrix[2][3], -3.0);
+
+    // The inverse rotates the translation too: -Rᵀ t = (-2, 1, -3).
+    let inv = t.inverse();
+    assert!(approx_eq(inv.matrix[0][3], -2.0, 1e-12));
+    assert!(approx_eq(inv.matrix[1][3], 1.0, 1e-12));
+    assert!(approx_eq(inv.matrix[2][3], -3.0, 1e-12));
+    assert!(n.matrix[0][3] != inv.matrix[0][3]);
+}
+
+#[test]
+fn neg_reflects_output_through_origin() {
+    let t = yaw_and_offset();
+    let p: Point3<Body> = Point3::new(1.0, 0.0, 0.0);
+
+    let q = t.apply_point(p);
+    let r = (-t).apply_point(p);
+
+    assert!(approx_eq(r.x, -q.x, 1e-12));
+    assert!(approx_eq(r.y, -q.y, 1e-12));
+    assert!(approx_eq(r.z, -q.z, 1e-12));
+}
+
+#[test]
+fn pose_components_round_trip() {
+    // 90° about z is (0, 0, sin 45°, cos 45°).
+    let h = core::f64::consts::FRAC_1_SQRT_2;
+    let t = Transform::<Body, World>::from_pose_components(1.0, 2.0, 3.0, 0.0, 0.0, h, h).unwrap();
+
+    // Same matrix as the hand-built yaw + offset transform.
+    for (got, want) in t
+        .matrix
+        .iter()
+        .flatten()
+        .zip(yaw_and_offset().matrix.iter().flatten())
+    {
+        assert!(approx_eq(*got, *want, 1e-12));
+    }
+
+    let (px, py, pz, qx, qy, qz, qw) = t.to_pose_components();
+    assert_eq!((px, py, pz), (1.0, 2.0, 3.0));
+    assert!(approx_eq(qx, 0.0, 1e-12));
+    assert!(approx_eq(qy, 0.0, 1e-12));
+    assert!(approx_eq(qz, h, 1e-12));
+    assert!(approx_eq(qw, h, 1e-12));
+
+    let back = Transform::<Body, World>::from_pose_components(px, py, pz, qx, qy, qz, qw).unwrap();
+    for (got, want) in back.matrix.iter().flatten().zip(t.matrix.iter().flatten()) {
+        assert!(approx_eq(*got, *want, 1e-12));
+    }
+}
+
+#[test]
+fn borrowed_matrix_view_reads_through() {
+    let buffer = yaw_and_offset().matrix;
+
+    let view: &Transform<Body, World> = Transform::from_matrix_ref(&buffer);
+
+    // Same storage, not a copy.
+    assert!(core::ptr::eq(&view.matrix, &buffer));
+
+    let p = view.apply_point(Point3::new(1.0, 0.0, 0.0));
+    assert!(approx_eq(p.x, 1.0, 1e-12));
+    assert!(approx_eq(p.y, 3.0, 1e-12));
+    assert!(approx_eq(p.z, 3.0, 1e-12));
+}
+
+#[test]
+fn apply_point_clamped_keeps_result_inside_bounds() {
+    let t: Transform<Body, World> = Transform::from_translation(10.0, 0.0, 0.0);
+    let arena = Aabb::new(
+        Point3::<World>::new(-5.0, -5.0, 0.0),
+        Point3::new(5.0, 5.0, 5.0),
+    );
+
+    // Lands at (11, 2, 1): outside the +x face.
+    let p = t.apply_point_clamped(Point3::new(1.0, 2.0, 1.0), &arena);
+    assert_eq!(p, Point3::new(5.0, 2.0, 1.0));
+    assert!(arena.contains(p));
+
+    // Already inside after the transform: unchanged.
+    let q = t.apply_point_clamped(Point3::new(-12.0, 0.0, 3.0), &arena);
+    assert_eq!(q, Point3::new(-2.0, 0.0, 3.0));
+}
+
+#[test]
+fn apply_point_clamped_does_not_panic_on_nan_bounds() {
+    let t: Transform<Body, World> = Transform::from_translation(10.0, 0.0, 0.0);
+    // Both corners are NaN on y, so the box has NaN bounds on that axis.
+    let arena = Aabb::new(
+        Point3::<World>::new(-5.0, f64::NAN, 0.0),
+        Point3::new(5.0, f64::NAN, 5.0),
+    );
+
+    let p = t.apply_point_clamped(Point3::new(1.0, 2.0, 9.0), &arena);
+    assert_eq!((p.x, p.y, p.z), (5.0, 2.0, 5.0));
+
+    let q = arena.clamp(Point3::new(f64::NAN, 1.0, 1.0));
+    assert_eq!((q.x, q.y, q.z), (-5.0, 1.0, 1.0));
+}
+
+fn assert_matrices_near(a: &[[f64; 4]; 4], b: &[[f64; 4]; 4], eps: f64) {
+    for (row_a, row_b) in a.iter().zip(b) {
+        for (x, y) in row_a.iter().zip(row_b) {
+            assert!(approx_eq(*x, *y, eps), "{a:?} != {b:?}");
+        }
+    }
+}
+
+#[test]
+fn mul_with_identity_keeps_translation() {
+    let t: Transform<Body, World> = Transform::from_translation(1.0, -2.0, 3.0);
+
+    let left = Transform::<Body, Body>::identity() * t;
+    let right = t * Transform::<World, World>::identity();
+
+    assert_matrices_near(&left.matrix, &t.matrix, 1e-12);
+    assert_matrices_near(&right.matrix, &t.matrix, 1e-12);
+}
+
+#[test]
+fn mul_is_associative_and_matches_compose() {
+    let sensor_to_body: Transform<Sensor, Body> =
+        Transform::<Sensor, Sensor>::rotation_x(Quantity::new(0.3))
+            .compose(&Transform::from_translation(0.1, 0.2, 0.3));
+    let body_to_world = yaw_and_offset();
+    let world_to_world: Transform<World, World> =
+        Transform::<World, World>::rotation_y(Quantity::new(-1.1))
+            .compose(&Transform::from_translation(-4.0, 0.0, 2.0));
+
+    let grouped_left = (sensor_to_body * body_to_world) * world_to_world;
+    let grouped_right = sensor_to_body * (body_to_world * world_to_world);
+    assert_matrices_near(&grouped_left.matrix, &grouped_right.matrix, 1e-12);
+
+    let p = Point3::new(0.5, -1.5, 2.0);
+    let via_mul = (sensor_to_body * body_to_world).apply_point(p);
+    let step_by_step = body_to_world.apply_point(sensor_to_body.apply_point(p));
+    assert!(approx_eq(via_mul.x, step_by_step.x, 1e-12));
+    assert!(approx_eq(via_mul.y, step_by_step.y, 1e-12));
+    assert!(approx_eq(via_mul.z, step_by_step.z, 1e-12));
+}
+
+#[test]
+fn apply_vector_ignores_translation_that_apply_point_uses() {
+    let t = yaw_and_offset();
+
+    let p = t.apply_point(Point3::new(1.0, 0.0, 0.0));
+    assert!(approx_eq(p.x, 1.0, 1e-12));
+    assert!(approx_eq(p.y, 3.0, 1e-12));
+    assert!(approx_eq(p.z, 3.0, 1e-12));
+
+    // Same coordinates as a direction: rotated by the yaw, never offset.
+    let v = t.apply_vector(Vector3::new(1.0, 0.0, 0.0));
+    assert!(approx_eq(v.x, 0.0, 1e-12));
+    assert!(approx_eq(v.y, 1.0, 1e-12));
+    assert!(approx_eq(v.z, 0.0, 1e-12));
+
+    // A difference of points transforms like a vector.
+    let a = Point3::<Body>::new(2.0, -1.0, 0.5);
+    let b = Point3::<Body>::new(-1.0, 4.0, 2.0);
+    let (ta, tb) = (t.apply_point(a), t.apply_point(b));
+    let d = t.apply_vector(Vector3::new(b.x - a.x, b.y - a.y, b.z - a.z));
+    assert!(approx_eq(d.x, tb.x - ta.x, 1e-12));
+    assert!(approx_eq(d.y, tb.y - ta.y, 1e-12));
+    assert!(approx_eq(d.z, tb.z - ta.z, 1e-12));
+}
+
+#[test]
+fn apply_point_ref_matches_by_value_form() {
+    let t = yaw_and_offset();
+    let input = [
+        Point3::<Body>::new(0.0, 0.0, 0.0),
+        Point3::new(1.5, -2.25, 3.0),
+        Point3::new(-1e6, 7e-3, 42.0),
+    ];
+
+    let mut out = [Point3::<World>::new(0.0, 0.0, 0.0); 3];
+    for (p, o) in input.iter().zip(out.iter_mut()) {
+        t.apply_point_ref(p, o);
+    }
+
+    for (p, o) in input.iter().zip(&out) {
+        let expected = t.apply_point(*p);
+        assert_eq!((o.x, o.y, o.z), (expected.x, expected.y, expected.z));
+    }
+}
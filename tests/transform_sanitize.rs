@@ -0,0 +1,143 @@
+//! Tests for `Transform::sanitize` and `Transform::try_from_matrix`, the
+//! "trust but verify" paths for transforms loaded from external storage.
+
+use spatial_typestate::{spatial_frames, SpatialError, Transform};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+const YAW_90: [[f64; 4]; 4] = [
+    [0.0, -1.0, 0.0, 1.0],
+    [1.0, 0.0, 0.0, 2.0],
+    [0.0, 0.0, 1.0, 3.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+#[test]
+fn clean_matrix_is_accepted_unchanged() {
+    let t = Transform::<Body, World>::sanitize(YAW_90, 1e-9).unwrap();
+
+    for (got, want) in t.matrix.iter().flatten().zip(YAW_90.iter().flatten()) {
+        assert!(approx_eq(*got, *want, 1e-15));
+    }
+}
+
+#[test]
+fn slightly_noisy_matrix_is_repaired() {
+    let mut noisy = YAW_90;
+    noisy[0][0] += 1e-7;
+    noisy[1][1] -= 2e-7;
+    noisy[2][2] += 1e-7;
+    noisy[3][0] = 1e-8;
+
+    let t = Transform::<Body, World>::sanitize(noisy, 1e-5).unwrap();
+    let m = t.matrix;
+
+    // Columns of the rotation block are orthonormal again.
+    for a in 0..3 {
+        for b in 0..3 {
+            let dot: f64 = (0..3).map(|r| m[r][a] * m[r][b]).sum();
+            let expected = if a == b { 1.0 } else { 0.0 };
+            assert!(approx_eq(dot, expected, 1e-12));
+        }
+    }
+
+    // Bottom row forced back, translation untouched.
+    assert_eq!(m[3], [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!([m[0][3], m[1][3], m[2][3]], [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn far_from_rigid_matrix_is_rejected() {
+    // Uniform scale of 2 is not something re-orthonormalization should hide.
+    let mut scaled = YAW_90;
+    for row in scaled.iter_mut().take(3) {
+        for v in row.iter_mut().take(3) {
+            *v *= 2.0;
+        }
+    }
+    assert_eq!(
+        Transform::<Body, World>::sanitize(scaled, 1e-3),
+        Err(SpatialError::NotRigid)
+    );
+
+    // A reflection (det = -1) cannot be repaired into a rotation either.
+    let mut mirrored = YAW_90;
+    mirrored[2][2] = -1.0;
+    assert_eq!(
+        Transform::<Body, World>::sanitize(mirrored, 1e-3),
+        Err(SpatialError::NotRigid)
+    );
+}
+
+#[test]
+fn non_finite_matrix_is_rejected() {
+    let mut bad = YAW_90;
+    bad[1][3] = f64::NAN;
+
+    assert_eq!(
+        Transform::<Body, World>::sanitize(bad, 1.0),
+        Err(SpatialError::NonFinite)
+    );
+}
+
+#[test]
+fn projective_matrix_is_detected_and_truncated() {
+    let mut projective = YAW_90;
+    projective[3] = [0.0, 0.0, -1.0, 0.0];
+
+    let t = Transform::<Body, World>::from_matrix(projective);
+    assert!(!t.is_affine(1e-9));
+
+    let affine = t.affine_truncated();
+    assert!(affine.is_affine(0.0));
+    assert_eq!(affine.matrix, YAW_90);
+
+    // Tiny drift in the bottom row is within tolerance.
+    let mut drifted = YAW_90;
+    drifted[3][3] = 1.0 + 1e-14;
+    assert!(Transform::<Body, World>::from_matrix(drifted).is_affine(1e-12));
+}
+
+#[test]
+fn try_from_matrix_accepts_a_rigid_transform_as_is() {
+    let t = Transform::<Body, World>::try_from_matrix(YAW_90).unwrap();
+    assert_eq!(t.matrix, YAW_90);
+}
+
+#[test]
+fn try_from_matrix_rejects_reflections_and_projective_rows() {
+    let mut mirrored = YAW_90;
+    mirrored[2][2] = -1.0;
+    assert_eq!(
+        Transform::<Body, World>::try_from_matrix(mirrored).unwrap_err(),
+        SpatialError::NotRigid
+    );
+
+    let mut projective = YAW_90;
+    projective[3][2] = 0.5;
+    assert_eq!(
+        Transform::<Body, World>::try_from_matrix(projective).unwrap_err(),
+        SpatialError::NotRigid
+    );
+
+    let mut scaled = YAW_90;
+    scaled[0][1] = -2.0;
+    assert_eq!(
+        Transform::<Body, World>::try_from_matrix(scaled).unwrap_err(),
+        SpatialError::NotRigid
+    );
+
+    let mut broken = YAW_90;
+    broken[1][3] = f64::NAN;
+    assert_eq!(
+        Transform::<Body, World>::try_from_matrix(broken).unwrap_err(),
+        SpatialError::NonFinite
+    );
+}
@@ -8,3 +8,92 @@ fn frame_mismatch_does_not_compile() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/frame_mismatch.rs");
 }
+
+#[test]
+fn non_zero_sized_frame_does_not_compile() {
+    // The zero-size check is a post-monomorphization error, which only fires
+    // during a full build. trybuild performs a full build (rather than
+    // `cargo check`) when at least one passing case is registered.
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/zst_frame.rs");
+    t.compile_fail("tests/ui/non_zst_frame.rs");
+}
+
+#[test]
+fn out_of_order_frame_chain_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/frame_chain_out_of_order.rs");
+}
+
+#[test]
+fn relabel_without_opt_in_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/relabel_not_opted_in.rs");
+}
+
+#[test]
+fn discarding_pure_results_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/discarded_must_use.rs");
+}
+
+#[test]
+fn cross_frame_relative_rotation_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/relative_rotation_frame_mismatch.rs");
+}
+
+#[test]
+fn mislabeled_cross_product_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/cross_product_mislabel.rs");
+}
+
+#[test]
+fn mismatched_transform_product_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/compose_frame_mismatch.rs");
+}
+
+#[test]
+fn adding_two_points_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/point_plus_point.rs");
+}
+
+#[test]
+fn cross_product_of_mixed_frames_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/cross_product_mixed_frames.rs");
+}
+
+#[test]
+fn frame_macro_forwards_docs_and_visibility() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/documented_frames.rs");
+    t.compile_fail("tests/ui/private_frame.rs");
+}
+
+#[test]
+fn adding_vectors_from_different_frames_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/vector_plus_other_frame.rs");
+}
+
+#[test]
+fn comparing_quantities_of_different_units_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/compare_mixed_units.rs");
+}
+
+#[test]
+fn dimensional_mismatches_do_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/area_as_length.rs");
+}
+
+#[test]
+fn trig_on_degrees_does_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/degrees_into_sin.rs");
+}
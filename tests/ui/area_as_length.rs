@@ -0,0 +1,15 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{Meters, Quantity, Seconds};
+
+fn main() {
+    let side = Quantity::<Meters>::new(2.0);
+    let time = Quantity::<Seconds>::new(1.0);
+
+    // ❌ Meters times meters is an area, not a length.
+    let _length: Quantity<Meters> = side * side;
+
+    // ❌ There is no unit for meters times seconds.
+    let _nonsense = side * time;
+}
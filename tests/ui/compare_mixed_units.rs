@@ -0,0 +1,12 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{Meters, Quantity, Radians};
+
+fn main() {
+    let length = Quantity::<Meters>::new(1.0);
+    let angle = Quantity::<Radians>::new(1.0);
+
+    // ❌ Meters and radians cannot be compared.
+    let _longer = length > angle;
+}
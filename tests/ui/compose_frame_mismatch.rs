@@ -0,0 +1,20 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{spatial_frames, Transform};
+
+spatial_frames! {
+    Sensor,
+    Body,
+    Camera,
+    World,
+}
+
+fn main() {
+    let sensor_to_body: Transform<Sensor, Body> = Transform::identity();
+    let camera_to_world: Transform<Camera, World> = Transform::identity();
+
+    // ❌ Intentional frame mismatch:
+    // the left operand ends in `Body` but the right one starts in `Camera`.
+    let _bad = sensor_to_body * camera_to_world;
+}
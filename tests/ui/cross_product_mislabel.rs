@@ -0,0 +1,18 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{spatial_frames, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn main() {
+    let x_body: Vector3<Body> = Vector3::new(1.0, 0.0, 0.0);
+    let y_body: Vector3<Body> = Vector3::new(0.0, 1.0, 0.0);
+
+    // ❌ The cross product of body vectors is a body vector; use
+    // `Transform::basis_cross` to express it in another frame.
+    let _z_world: Vector3<World> = x_body.cross(y_body);
+}
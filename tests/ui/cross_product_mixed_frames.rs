@@ -0,0 +1,17 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{spatial_frames, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn main() {
+    let x_body: Vector3<Body> = Vector3::new(1.0, 0.0, 0.0);
+    let y_world: Vector3<World> = Vector3::new(0.0, 1.0, 0.0);
+
+    // ❌ Operands must be expressed in the same frame.
+    let _z = x_body.cross(y_world);
+}
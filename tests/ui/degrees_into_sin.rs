@@ -0,0 +1,11 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{Degrees, Quantity};
+
+fn main() {
+    let tilt = Quantity::<Degrees>::new(30.0);
+
+    // ❌ Trigonometry takes radians; call `to_radians()` first.
+    let _s = tilt.sin();
+}
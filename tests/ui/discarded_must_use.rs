@@ -0,0 +1,25 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+#![deny(unused_must_use)]
+
+use spatial_typestate::{spatial_frames, Meters, Point3, Quantity, Transform, UnitQuat, Vector3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn main() {
+    let t: Transform<Body, World> = Transform::identity();
+    let q: UnitQuat<World> = UnitQuat::identity();
+    let v = Vector3::<World>::new(1.0, 0.0, 0.0);
+
+    // ❌ Each of these computes a new value and throws it away.
+    t.inverse();
+    t.compose(&Transform::<World, World>::identity());
+    q.integrate(v, 0.1);
+    v.cross(v);
+    Point3::<Body>::new(0.0, 0.0, 0.0);
+    Quantity::<Meters>::new(1.0).get();
+}
@@ -0,0 +1,47 @@
+// This file should compile: `spatial_frames!` forwards doc comments and
+// visibility, so the generated frames satisfy `missing_docs`.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+#![deny(missing_docs)]
+//! Frames declared with docs and restricted visibility.
+
+use spatial_typestate::{spatial_frames, Frame, FrameSet, Handedness, Point3};
+
+spatial_frames! {
+    /// The fixed world frame.
+    World,
+    /// The vehicle body, only used inside this crate.
+    pub(crate) Body,
+    /// A left-handed scene imported from a game engine.
+    #[frame(handedness = Left)]
+    pub Scene,
+}
+
+spatial_frames! {
+    /// Every sensor on the vehicle.
+    pub(crate) type Sensors = (
+        /// Roof lidar.
+        Lidar,
+        /// Front camera.
+        pub(crate) Camera,
+    );
+}
+
+mod private {
+    use spatial_typestate::spatial_frames;
+
+    spatial_frames! {
+        /// Visible to the parent module only.
+        pub(super) Scratch,
+    }
+}
+
+fn main() {
+    let _p: Point3<Body> = Point3::new(0.0, 0.0, 0.0);
+    let _q: Point3<private::Scratch> = Point3::new(0.0, 0.0, 0.0);
+
+    assert_eq!(World::NAME, "World");
+    assert_eq!(Scene::HANDEDNESS, Handedness::Left);
+    assert_eq!(Sensors::NAMES, ["Lidar", "Camera"]);
+    let _ = (Lidar, Camera);
+}
@@ -0,0 +1,19 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{spatial_frames, FrameChain, Transform};
+
+spatial_frames! {
+    Sensor,
+    Body,
+    World,
+}
+
+fn main() {
+    let sensor_to_body: Transform<Sensor, Body> = Transform::identity();
+    let body_to_world: Transform<Body, World> = Transform::identity();
+
+    // ❌ Hops out of order: the first hop ends in `World`, but the second one
+    // starts from `Sensor`.
+    let _t = <FrameChain![Sensor, Body, World]>::compose((body_to_world, sensor_to_body));
+}
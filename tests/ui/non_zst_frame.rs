@@ -0,0 +1,16 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{Frame, Point3};
+
+// ❌ A frame marker that carries data is not zero-sized.
+struct Fat {
+    _id: u32,
+}
+
+impl Frame for Fat {}
+
+fn main() {
+    // Building any value tagged with `Fat` trips the zero-size assertion.
+    let _p: Point3<Fat> = Point3::new(1.0, 0.0, 0.0);
+}
@@ -0,0 +1,17 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{spatial_frames, Point3};
+
+spatial_frames! {
+    World,
+}
+
+fn main() {
+    let a: Point3<World> = Point3::new(1.0, 0.0, 0.0);
+    let b: Point3<World> = Point3::new(0.0, 1.0, 0.0);
+
+    // ❌ Points are positions; only their difference (a `Vector3`) can be
+    // added to a point.
+    let _c = a + b;
+}
@@ -0,0 +1,16 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+mod sensors {
+    use spatial_typestate::spatial_frames;
+
+    spatial_frames! {
+        /// Calibration target, private to this module.
+        pub(self) Target,
+    }
+}
+
+fn main() {
+    // ❌ `Target` was declared `pub(self)`, so it is not visible here.
+    let _target = sensors::Target;
+}
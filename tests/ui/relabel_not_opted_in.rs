@@ -0,0 +1,16 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{spatial_frames, Point3};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn main() {
+    let p_body: Point3<Body> = Point3::new(1.0, 0.0, 0.0);
+
+    // ❌ `Body` was never declared `RelabelSafe<World>`; a transform is required.
+    let _p_world: Point3<World> = p_body.relabel();
+}
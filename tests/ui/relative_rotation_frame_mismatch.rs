@@ -0,0 +1,17 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{spatial_frames, UnitQuat};
+
+spatial_frames! {
+    World,
+    Body,
+}
+
+fn main() {
+    let q_world: UnitQuat<World> = UnitQuat::identity();
+    let q_body: UnitQuat<Body> = UnitQuat::identity();
+
+    // ❌ Orientations in different frames cannot be differenced.
+    let _delta = q_world.relative_to(&q_body);
+}
@@ -0,0 +1,17 @@
+// This file is intentionally incorrect and should FAIL to compile.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{spatial_frames, Vector3};
+
+spatial_frames! {
+    Body,
+    World,
+}
+
+fn main() {
+    let a: Vector3<Body> = Vector3::new(1.0, 0.0, 0.0);
+    let b: Vector3<World> = Vector3::new(0.0, 1.0, 0.0);
+
+    // ❌ The vectors live in different frames; transform one first.
+    let _sum = a + b;
+}
@@ -0,0 +1,12 @@
+// This file should compile: a unit-struct frame marker is zero-sized.
+// It is used by trybuild from `tests/type_safety_compile_fail.rs`.
+
+use spatial_typestate::{Frame, Point3};
+
+struct Thin;
+
+impl Frame for Thin {}
+
+fn main() {
+    let _p: Point3<Thin> = Point3::new(1.0, 0.0, 0.0);
+}
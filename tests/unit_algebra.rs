@@ -0,0 +1,63 @@
+//! Tests for composite units produced by multiplying and dividing quantities.
+
+use spatial_typestate::units::{CubicMeters, MetersPerSecond, RadiansPerSecond, SquareMeters};
+use spatial_typestate::{Dimensionless, Meters, Point3, Quantity, Radians, Seconds};
+
+spatial_typestate::spatial_frames! {
+    World,
+}
+
+#[test]
+fn meters_times_meters_is_an_area() {
+    let width = Quantity::<Meters>::new(2.5);
+    let depth = Quantity::<Meters>::new(4.0);
+
+    let area: Quantity<SquareMeters> = width * depth;
+    assert_eq!(area.get(), 10.0);
+
+    // The named unit is the same type the rest of the API produces.
+    let a = Point3::<World>::new(0.0, 0.0, 0.0);
+    let b = Point3::<World>::new(3.0, 4.0, 0.0);
+    let d2: Quantity<SquareMeters> = a.distance_squared(b);
+    assert_eq!(d2.get(), 25.0);
+}
+
+#[test]
+fn area_and_volume_divide_back_to_length() {
+    let side = Quantity::<Meters>::new(3.0);
+
+    let volume: Quantity<CubicMeters> = side * side * side;
+    assert_eq!(volume.get(), 27.0);
+    assert_eq!(side * (side * side), volume);
+
+    let face: Quantity<SquareMeters> = volume / side;
+    assert_eq!(face.get(), 9.0);
+    let edge: Quantity<Meters> = volume / face;
+    assert_eq!(edge, side);
+
+    let ratio: Quantity<Dimensionless> = face / face;
+    assert_eq!(ratio.get(), 1.0);
+}
+
+#[test]
+fn distance_over_time_is_a_speed() {
+    let distance = Quantity::<Meters>::new(100.0);
+    let time = Quantity::<Seconds>::new(8.0);
+
+    let speed: Quantity<MetersPerSecond> = distance / time;
+    assert_eq!(speed.get(), 12.5);
+
+    let back: Quantity<Meters> = speed * time;
+    assert_eq!(back, distance);
+    assert_eq!(time * speed, distance);
+}
+
+#[test]
+fn angle_over_time_is_a_rate() {
+    let turned = Quantity::<Radians>::new(3.0);
+    let rate: Quantity<RadiansPerSecond> = turned / Quantity::<Seconds>::new(2.0);
+    assert_eq!(rate.get(), 1.5);
+
+    let angle: Quantity<Radians> = rate * Quantity::<Seconds>::new(4.0);
+    assert_eq!(angle.get(), 6.0);
+}
@@ -0,0 +1,347 @@
+//! Tests for frame-tagged vector operations.
+
+use spatial_typestate::{spatial_frames, Meters, Quantity, SpatialError, Transform, Vector3};
+
+spatial_frames! {
+    Body,
+    World,
+}
+
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[test]
+fn try_cross_of_orthogonal_vectors_succeeds() {
+    let x = Vector3::<Body>::new(1.0, 0.0, 0.0);
+    let y = Vector3::<Body>::new(0.0, 1.0, 0.0);
+
+    let z = x.try_cross(y, 1e-9).unwrap();
+
+    assert!(approx_eq(z.x, 0.0, 1e-12));
+    assert!(approx_eq(z.y, 0.0, 1e-12));
+    assert!(approx_eq(z.z, 1.0, 1e-12));
+}
+
+#[test]
+fn try_cross_rejects_nearly_parallel_vectors() {
+    let a = Vector3::<Body>::new(1.0, 0.0, 0.0);
+    let b = Vector3::<Body>::new(1.0, 1e-12, 0.0);
+
+    // The plain cross product happily returns a near-zero vector...
+    let c = a.cross(b);
+    assert!(c.z.abs() < 1e-9);
+
+    // ...while the checked variant flags it.
+    assert_eq!(a.try_cross(b, 1e-9), Err(SpatialError::DegenerateInput));
+    assert_eq!(a.try_cross(a, 1e-9), Err(SpatialError::DegenerateInput));
+}
+
+#[test]
+fn try_normalize_yields_unit_length() {
+    let v = Vector3::<Body>::new(3.0, 0.0, 4.0);
+    assert_eq!(v.norm(), 5.0);
+    assert_eq!(v.norm_squared(), 25.0);
+
+    let u = v.try_normalize().unwrap();
+    assert!(approx_eq(u.norm(), 1.0, 1e-15));
+    assert!(approx_eq(u.x, 0.6, 1e-15));
+    assert!(approx_eq(u.z, 0.8, 1e-15));
+}
+
+#[test]
+fn try_normalize_rejects_near_zero_vector() {
+    let tiny = Vector3::<Body>::new(1e-17, -1e-17, 0.0);
+    assert_eq!(tiny.try_normalize(), Err(SpatialError::ZeroNormVector));
+}
+
+#[test]
+fn try_normalize_rejects_zero_vector() {
+    let v = Vector3::<Body>::new(0.0, 0.0, 0.0);
+    assert_eq!(v.try_normalize(), Err(SpatialError::ZeroNormVector));
+
+    let nan = Vector3::<Body>::new(f64::NAN, 1.0, 0.0);
+    assert_eq!(nan.try_normalize(), Err(SpatialError::NonFinite));
+}
+
+#[test]
+fn try_project_onto_rejects_zero_target() {
+    let v = Vector3::<Body>::new(1.0, 1.0, 0.0);
+    let zero = Vector3::<Body>::new(0.0, 0.0, 0.0);
+    assert_eq!(v.try_project_onto(zero), Err(SpatialError::ZeroNormVector));
+
+    // Projecting the zero vector itself is fine: it has no component anywhere.
+    let p = zero.try_project_onto(v).unwrap();
+    assert_eq!((p.x, p.y, p.z), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn try_angle_to_rejects_zero_vector() {
+    let v = Vector3::<Body>::new(1.0, 0.0, 0.0);
+    let zero = Vector3::<Body>::new(0.0, 0.0, 0.0);
+    assert_eq!(v.try_angle_to(zero), Err(SpatialError::ZeroNormVector));
+    assert_eq!(zero.try_angle_to(v), Err(SpatialError::ZeroNormVector));
+
+    let right = v.try_angle_to(Vector3::new(0.0, 2.0, 0.0)).unwrap();
+    assert!(approx_eq(right.get(), core::f64::consts::FRAC_PI_2, 1e-12));
+}
+
+#[test]
+fn reflect_downward_velocity_off_floor() {
+    let falling = Vector3::<Body>::new(1.0, 0.5, -3.0);
+    let up = Vector3::<Body>::new(0.0, 0.0, 1.0);
+
+    let bounced = falling.reflect(up);
+
+    assert_eq!((bounced.x, bounced.y, bounced.z), (1.0, 0.5, 3.0));
+}
+
+#[test]
+fn norm_m_is_a_length_in_meters() {
+    let displacement = Vector3::<Body>::new(3.0, 4.0, 0.0);
+
+    let distance: Quantity<Meters> = displacement.norm_m();
+
+    assert_eq!(distance.get(), 5.0);
+}
+
+#[test]
+fn scalar_triple_of_unit_basis_is_one() {
+    let x = Vector3::<Body>::new(1.0, 0.0, 0.0);
+    let y = Vector3::<Body>::new(0.0, 1.0, 0.0);
+    let z = Vector3::<Body>::new(0.0, 0.0, 1.0);
+
+    assert!(approx_eq(x.scalar_triple(y, z), 1.0, 1e-12));
+    // Swapping two arguments flips handedness.
+    assert!(approx_eq(y.scalar_triple(x, z), -1.0, 1e-12));
+}
+
+#[test]
+fn vector_triple_matches_bac_cab_identity() {
+    let a = Vector3::<Body>::new(1.0, -2.0, 0.5);
+    let b = Vector3::<Body>::new(0.3, 4.0, -1.0);
+    let c = Vector3::<Body>::new(-2.0, 0.7, 3.0);
+
+    let lhs = a.vector_triple(b, c);
+    let ac = a.dot(c);
+    let ab = a.dot(b);
+
+    assert!(approx_eq(lhs.x, b.x * ac - c.x * ab, 1e-12));
+    assert!(approx_eq(lhs.y, b.y * ac - c.y * ab, 1e-12));
+    assert!(approx_eq(lhs.z, b.z * ac - c.z * ab, 1e-12));
+}
+
+#[test]
+fn basis_cross_rotates_the_product_into_the_target_frame() {
+    // Body x/y/z map onto world y/z/x; the translation must not matter.
+    let body_to_world: Transform<Body, World> = Transform::from_matrix([
+        [0.0, 0.0, 1.0, 5.0],
+        [1.0, 0.0, 0.0, -3.0],
+        [0.0, 1.0, 0.0, 7.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    let x = Vector3::<Body>::new(1.0, 0.0, 0.0);
+    let y = Vector3::<Body>::new(0.0, 1.0, 0.0);
+
+    // Body z (= x × y) is world x.
+    let axis: Vector3<World> = body_to_world.basis_cross(x, y);
+    assert!(approx_eq(axis.x, 1.0, 1e-12));
+    assert!(approx_eq(axis.y, 0.0, 1e-12));
+    assert!(approx_eq(axis.z, 0.0, 1e-12));
+}
+
+#[test]
+fn orthonormal_basis_completes_any_direction() {
+    for n in [
+        Vector3::<Body>::new(0.0, 0.0, 1.0),
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(3.0, -4.0, 12.0),
+        Vector3::new(-1.0, 2.0, -1e-9),
+    ] {
+        let (t, b) = n.orthonormal_basis().unwrap();
+        let n = n.try_normalize().unwrap();
+
+        for v in [t, b] {
+            assert!(approx_eq(v.norm(), 1.0, 1e-12));
+            assert!(approx_eq(v.dot(n), 0.0, 1e-12));
+        }
+        assert!(approx_eq(t.dot(b), 0.0, 1e-12));
+
+        // Right-handed: t × b = n.
+        let c = t.cross(b);
+        assert!(approx_eq(c.x, n.x, 1e-12));
+        assert!(approx_eq(c.y, n.y, 1e-12));
+        assert!(approx_eq(c.z, n.z, 1e-12));
+    }
+
+    assert_eq!(
+        Vector3::<Body>::new(0.0, 0.0, 0.0).orthonormal_basis(),
+        Err(SpatialError::ZeroNormVector)
+    );
+}
+
+#[test]
+fn cross_of_unit_axes_is_right_handed() {
+    let x = Vector3::<World>::new(1.0, 0.0, 0.0);
+    let y = Vector3::<World>::new(0.0, 1.0, 0.0);
+    let z = Vector3::<World>::new(0.0, 0.0, 1.0);
+
+    assert_eq!(x.cross(y), z);
+    assert_eq!(y.cross(z), x);
+    assert_eq!(z.cross(x), y);
+}
+
+#[test]
+fn cross_is_anti_commutative_and_perpendicular_to_operands() {
+    let a = Vector3::<Body>::new(1.5, -2.0, 0.25);
+    let b = Vector3::<Body>::new(-0.5, 3.0, 4.0);
+
+    let ab = a.cross(b);
+    let ba = b.cross(a);
+    assert!(approx_eq(ab.x, -ba.x, 1e-12));
+    assert!(approx_eq(ab.y, -ba.y, 1e-12));
+    assert!(approx_eq(ab.z, -ba.z, 1e-12));
+
+    assert!(approx_eq(ab.dot(a), 0.0, 1e-12));
+    assert!(approx_eq(ab.dot(b), 0.0, 1e-12));
+}
+
+#[test]
+fn dot_of_perpendicular_unit_vectors_is_zero() {
+    let s = core::f64::consts::FRAC_1_SQRT_2;
+    let u = Vector3::<World>::new(s, s, 0.0);
+    let v = Vector3::<World>::new(-s, s, 0.0);
+
+    assert!(approx_eq(u.dot(v), 0.0, 1e-15));
+    assert!(approx_eq(u.dot(u), 1.0, 1e-15));
+}
+
+#[test]
+fn addition_is_commutative() {
+    let a = Vector3::<Body>::new(1.5, -2.0, 0.25);
+    let b = Vector3::<Body>::new(-0.5, 4.0, 3.0);
+
+    assert_eq!(a + b, b + a);
+    assert_eq!(a + b, Vector3::new(1.0, 2.0, 3.25));
+    assert_eq!((a + b) - b, a);
+}
+
+#[test]
+fn negation_cancels_under_addition() {
+    let v = Vector3::<Body>::new(0.3, -7.0, 1e6);
+
+    assert_eq!(v + -v, Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(-(-v), v);
+    assert_eq!(v - v, v + -v);
+}
+
+#[test]
+fn scaling_multiplies_each_component() {
+    let v = Vector3::<Body>::new(1.0, -2.0, 4.0);
+
+    assert_eq!(v * 2.0, Vector3::new(2.0, -4.0, 8.0));
+    assert_eq!(v / 4.0, Vector3::new(0.25, -0.5, 1.0));
+    assert!(approx_eq((v * 3.0).norm(), 3.0 * v.norm(), 1e-12));
+
+    let w = Vector3::<Body, f32>::new(1.0, 2.0, 3.0) * 0.5;
+    assert_eq!(w, Vector3::new(0.5, 1.0, 1.5));
+}
+
+#[test]
+fn angle_between_orthogonal_vectors_is_a_right_angle() {
+    let x = Vector3::<Body>::new(2.0, 0.0, 0.0);
+    let y = Vector3::<Body>::new(0.0, 0.5, 0.0);
+
+    let angle = x.try_angle_to(y).unwrap();
+    assert!(approx_eq(angle.get(), core::f64::consts::FRAC_PI_2, 1e-15));
+    assert_eq!(y.try_angle_to(x).unwrap(), angle);
+}
+
+#[test]
+fn angle_between_nearly_parallel_vectors_stays_accurate() {
+    let a = Vector3::<Body>::new(1.0, 0.0, 0.0);
+    let b = Vector3::<Body>::new(1.0, 1e-9, 0.0);
+
+    // acos of the normalized dot product would round this to exactly 0.
+    let angle = a.try_angle_to(b).unwrap().get();
+    assert!(!angle.is_nan());
+    assert!(angle > 0.0);
+    assert!(approx_eq(angle, 1e-9, 1e-20));
+
+    // Likewise near π.
+    let back = a.try_angle_to(-b).unwrap().get();
+    assert!(approx_eq(back, core::f64::consts::PI - 1e-9, 1e-15));
+}
+
+#[test]
+fn angle_to_a_zero_vector_is_an_error() {
+    let a = Vector3::<Body>::new(1.0, 0.0, 0.0);
+    let zero = Vector3::<Body>::new(0.0, 0.0, 0.0);
+
+    assert_eq!(a.try_angle_to(zero), Err(SpatialError::ZeroNormVector));
+    assert_eq!(
+        a.try_angle_to(Vector3::new(f64::NAN, 0.0, 0.0)),
+        Err(SpatialError::NonFinite)
+    );
+}
+
+#[test]
+fn angle_between_matches_try_angle_to_and_handles_degenerate_inputs() {
+    let a = Vector3::<Body>::new(1.0, 0.0, 0.0);
+    let b = Vector3::<Body>::new(1.0, 1e-9, 0.0);
+
+    assert_eq!(a.angle_between(b), a.try_angle_to(b).unwrap());
+    assert_eq!(a.angle_between(-b), a.try_angle_to(-b).unwrap());
+
+    let zero = Vector3::<Body>::new(0.0, 0.0, 0.0);
+    assert_eq!(a.angle_between(zero).get(), 0.0);
+    assert_eq!(zero.angle_between(zero).get(), 0.0);
+    assert!(a
+        .angle_between(Vector3::new(f64::NAN, 0.0, 0.0))
+        .get()
+        .is_nan());
+}
+
+#[test]
+fn projection_and_rejection_split_a_vector() {
+    let v = Vector3::<Body>::new(1.0, 1.0, 0.0);
+    let x = Vector3::<Body>::new(1.0, 0.0, 0.0);
+
+    assert_eq!(v.try_project_onto(x).unwrap(), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(v.try_reject_from(x).unwrap(), Vector3::new(0.0, 1.0, 0.0));
+
+    // The length of `onto` does not matter, and the parts add back up.
+    let w = Vector3::<Body>::new(0.3, -2.0, 5.0);
+    let d = Vector3::<Body>::new(0.0, 4.0, 3.0);
+    let along = w.try_project_onto(d).unwrap();
+    let across = w.try_reject_from(d).unwrap();
+    assert!(approx_eq(across.dot(d), 0.0, 1e-12));
+    assert!(approx_eq((along + across - w).norm(), 0.0, 1e-12));
+}
+
+#[test]
+fn projecting_onto_a_zero_vector_is_an_error() {
+    let v = Vector3::<Body>::new(1.0, 1.0, 0.0);
+    let zero = Vector3::<Body>::new(0.0, 0.0, 0.0);
+
+    assert_eq!(v.try_project_onto(zero), Err(SpatialError::ZeroNormVector));
+    assert_eq!(v.try_reject_from(zero), Err(SpatialError::ZeroNormVector));
+}
+
+#[test]
+fn reflecting_off_a_floor_flips_only_the_vertical_component() {
+    let falling = Vector3::<World>::new(3.0, -1.5, -2.0);
+    let up = Vector3::<World>::new(0.0, 0.0, 1.0);
+
+    assert_eq!(falling.reflect(up), Vector3::new(3.0, -1.5, 2.0));
+
+    // Reflecting twice restores the original, and the length is preserved
+    // for any unit normal.
+    let n = Vector3::<World>::new(1.0, 2.0, -2.0)
+        .try_normalize()
+        .unwrap();
+    let bounced = falling.reflect(n);
+    assert!(approx_eq(bounced.norm(), falling.norm(), 1e-12));
+    assert!(approx_eq((bounced.reflect(n) - falling).norm(), 0.0, 1e-12));
+}